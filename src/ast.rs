@@ -5,51 +5,68 @@
 //! actual query building is in the [visitor](../visitor/index.html) module.
 //!
 //! For prelude, all important imports are in `quaint::ast::*`.
+mod case;
 mod column;
 mod compare;
 mod conditions;
 mod conjunctive;
+mod create_table;
 mod cte;
 mod delete;
+mod drop;
 mod expression;
 mod function;
 mod grouping;
+mod identifier;
 mod index;
 mod insert;
 mod join;
+mod lock;
 mod merge;
 mod ops;
 mod ordering;
 mod over;
 mod query;
+mod replace;
 mod row;
 mod select;
 mod table;
+mod table_schema;
 mod union;
 mod update;
 mod values;
 
+pub use case::Case;
 pub use column::{Column, DefaultValue, TypeFamily};
-pub use compare::{Comparable, Compare};
+pub use compare::{Comparable, Compare, Quantifier, DEFAULT_IN_ARRAY_CHUNK_SIZE};
 pub use conditions::ConditionTree;
 pub use conjunctive::Conjunctive;
+pub use create_table::{
+    ColumnConstraint, ColumnDefinition, ColumnType, CreateIndex, CreateTable, CreateTableAs, ForeignKey,
+    ReferentialAction,
+};
 pub use cte::{CommonTableExpression, IntoCommonTableExpression};
 pub use delete::Delete;
+pub use drop::{DropIndex, DropTable};
 pub use expression::*;
 pub use function::*;
 pub use grouping::*;
+pub use identifier::validate_identifier;
 pub use index::*;
 pub use insert::*;
 pub use join::{Join, JoinData, Joinable};
-pub(crate) use merge::*;
+pub use lock::{Lock, LockType, LockWait};
+pub use merge::{IntoUsing, Merge, Using};
 pub use ops::*;
-pub use ordering::{IntoOrderDefinition, Order, OrderDefinition, Orderable, Ordering};
+pub use ordering::{ordinal, IntoOrderDefinition, Order, OrderDefinition, Orderable, Ordering};
 pub use over::*;
 pub use query::{Query, SelectQuery};
+pub use replace::Replace;
 pub use row::Row;
 pub use select::Select;
 pub use table::*;
+pub use table_schema::TableSchema;
 pub use union::Union;
 pub use update::*;
 pub(crate) use values::Params;
-pub use values::{IntoRaw, Raw, Value, Values};
+pub use values::{IntoRaw, Raw, Value, ValueKind, Values};
@@ -0,0 +1,89 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// A `CASE` expression, choosing the result of the first matching branch.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let case = Case::on(Column::from("id")).when(1, "first").when(2, "second").otherwise("other");
+/// let query = Select::from_table("users").value(case.alias("position"));
+/// let (sql, params) = Postgres::build(query)?;
+///
+/// assert_eq!(
+///     "SELECT CASE \"id\" WHEN $1 THEN $2 WHEN $3 THEN $4 ELSE $5 END AS \"position\" FROM \"users\"",
+///     sql
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         Value::from(1),
+///         Value::from("first"),
+///         Value::from(2),
+///         Value::from("second"),
+///         Value::from("other"),
+///     ],
+///     params
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case<'a> {
+    pub(crate) operand: Box<Expression<'a>>,
+    pub(crate) branches: Vec<(Expression<'a>, Expression<'a>)>,
+    pub(crate) otherwise: Option<Box<Expression<'a>>>,
+}
+
+impl<'a> Case<'a> {
+    /// A `CASE` comparing the operand to the value of every branch.
+    pub fn on<E>(operand: E) -> Self
+    where
+        E: Into<Expression<'a>>,
+    {
+        Self {
+            operand: Box::new(operand.into()),
+            branches: Vec::new(),
+            otherwise: None,
+        }
+    }
+
+    /// Adds a `WHEN value THEN result` branch.
+    pub fn when<W, T>(mut self, value: W, result: T) -> Self
+    where
+        W: Into<Expression<'a>>,
+        T: Into<Expression<'a>>,
+    {
+        self.branches.push((value.into(), result.into()));
+        self
+    }
+
+    /// The result if no branch matches. Without it the result is `NULL`.
+    pub fn otherwise<T>(mut self, result: T) -> Self
+    where
+        T: Into<Expression<'a>>,
+    {
+        self.otherwise = Some(Box::new(result.into()));
+        self
+    }
+}
+
+impl<'a> From<Case<'a>> for Expression<'a> {
+    fn from(case: Case<'a>) -> Self {
+        Expression {
+            kind: ExpressionKind::Case(Box::new(case)),
+            alias: None,
+        }
+    }
+}
+
+impl<'a> Aliasable<'a> for Case<'a> {
+    type Target = Expression<'a>;
+
+    fn alias<T>(self, alias: T) -> Self::Target
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Expression::from(self).alias(alias)
+    }
+}
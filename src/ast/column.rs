@@ -1,6 +1,6 @@
 use super::Aliasable;
 use crate::{
-    ast::{Expression, ExpressionKind, Table},
+    ast::{Comparable, Expression, ExpressionKind, JoinData, Joinable, Row, Table, Values},
     Value,
 };
 use std::borrow::Cow;
@@ -114,6 +114,25 @@ impl<'a> Column<'a> {
         }
     }
 
+    /// Create a column definition, rejecting names with control characters
+    /// or identifier quotes with an `InvalidIdentifier` error. Use with
+    /// names built from untrusted input.
+    ///
+    /// ```rust
+    /// # use quaint::ast::*;
+    /// assert!(Column::checked("name").is_ok());
+    /// assert!(Column::checked("name` = 1 OR `1").is_err());
+    /// ```
+    pub fn checked<S>(name: S) -> crate::Result<Self>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let name = name.into();
+        super::validate_identifier(&name)?;
+
+        Ok(Self::new(name))
+    }
+
     /// Include the table name in the column expression.
     pub fn table<T>(mut self, table: T) -> Self
     where
@@ -134,6 +153,53 @@ impl<'a> Column<'a> {
 
         self
     }
+
+    /// Compares or orders the column with the given collation. See
+    /// [`Expression::collate`](struct.Expression.html#method.collate).
+    pub fn collate<C>(self, collation: C) -> Expression<'a>
+    where
+        C: Into<Cow<'a, str>>,
+    {
+        Expression::from(self).collate(collation)
+    }
+
+    /// Filters the column against a list of values by joining an inline
+    /// `VALUES` table, instead of an `IN` list with a parameter per value.
+    /// The planner can use the join with an index scan when the list is
+    /// long, where a large `IN` list often ends up as a sequential scan.
+    ///
+    /// The values table is named after the column with a `_values` suffix.
+    /// Supported on PostgreSQL and SQLite.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let join = Column::from(("users", "id")).in_values_join(vec![1, 2, 3]);
+    /// let query = Select::from_table("users").inner_join(join);
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT \"users\".* FROM \"users\" \
+    ///      INNER JOIN (VALUES ($1::int8),($2),($3)) AS \"id_values\" \
+    ///      ON \"users\".\"id\" = \"id_values\".\"column1\"",
+    ///     sql
+    /// );
+    ///
+    /// assert_eq!(vec![Value::from(1), Value::from(2), Value::from(3)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn in_values_join<I, V>(self, values: I) -> JoinData<'a>
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Expression<'a>>,
+    {
+        let alias = format!("{}_values", self.name);
+        let values = Values::from(values.into_iter().map(|value| Row::from(vec![value])));
+        let value_column = Column::from((alias.clone(), "column1"));
+
+        Table::from(values).alias(alias).on(self.equals(value_column))
+    }
 }
 
 impl<'a> Aliasable<'a> for Column<'a> {
@@ -1,5 +1,5 @@
 use super::ExpressionKind;
-use crate::ast::{Column, ConditionTree, Expression};
+use crate::ast::{Column, ConditionTree, Expression, Value};
 use std::borrow::Cow;
 
 /// For modeling comparison expressions.
@@ -21,6 +21,9 @@ pub enum Compare<'a> {
     In(Box<Expression<'a>>, Box<Expression<'a>>),
     /// `left NOT IN (..)`
     NotIn(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left = ANY(array)` on PostgreSQL, otherwise `left IN (..)` split into
+    /// chunks of the given size.
+    InArray(Box<Expression<'a>>, Vec<Value<'a>>, usize),
     /// `left LIKE %..%`
     Like(Box<Expression<'a>>, Cow<'a, str>),
     /// `left NOT LIKE %..%`
@@ -44,9 +47,50 @@ pub enum Compare<'a> {
     /// Raw comparator, allows to use an operator `left <raw> right` as is,
     /// without visitor transformation in between.
     Raw(Box<Expression<'a>>, Cow<'a, str>, Box<Expression<'a>>),
+    /// `left <comparator> ANY (..)` or `left <comparator> ALL (..)`
+    Quantified(Box<Expression<'a>>, &'static str, Quantifier, Box<Expression<'a>>),
 }
 
+/// The quantifier of a comparison against the rows of a subquery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+    /// True if the comparison is true for at least one row.
+    Any,
+    /// True if the comparison is true for every row.
+    All,
+}
+
+/// The default number of values in one `IN` list, when an array comparison
+/// must be split for databases without array support.
+pub const DEFAULT_IN_ARRAY_CHUNK_SIZE: usize = 1000;
+
 impl<'a> Compare<'a> {
+    /// Sets the maximum number of values in one `IN` list for an array
+    /// comparison on databases without array support. Does nothing for other
+    /// comparison types. A zero size means the default,
+    /// [`DEFAULT_IN_ARRAY_CHUNK_SIZE`].
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").so_that("id".in_array(vec![1, 2, 3]).chunk_size(2));
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE (`id` IN (?,?) OR `id` IN (?))", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DEFAULT_IN_ARRAY_CHUNK_SIZE`]: constant.DEFAULT_IN_ARRAY_CHUNK_SIZE.html
+    pub fn chunk_size(self, size: usize) -> Self {
+        let size = if size == 0 { DEFAULT_IN_ARRAY_CHUNK_SIZE } else { size };
+
+        match self {
+            Self::InArray(left, values, _) => Self::InArray(left, values, size),
+            _ => self,
+        }
+    }
+
     /// Finds a possible `(a,y) IN (SELECT x,z FROM B)`, takes the select out and
     /// converts the comparison into `a IN (SELECT x FROM cte_n where z = y)`.
     ///
@@ -212,7 +256,8 @@ impl<'a> From<Compare<'a>> for Expression<'a> {
 
 /// An item that can be compared against other values in the database.
 pub trait Comparable<'a> {
-    /// Tests if both sides are the same value.
+    /// Tests if both sides are the same value. Comparing to a `NULL` value
+    /// is written as `IS NULL`, as `= NULL` is never true.
     ///
     /// ```rust
     /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
@@ -235,7 +280,8 @@ pub trait Comparable<'a> {
     where
         T: Into<Expression<'a>>;
 
-    /// Tests if both sides are not the same value.
+    /// Tests if both sides are not the same value. Comparing to a `NULL`
+    /// value is written as `IS NOT NULL`.
     ///
     /// ```rust
     /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
@@ -391,6 +437,33 @@ pub trait Comparable<'a> {
     where
         T: Into<Expression<'a>>;
 
+    /// Tests if the left side is included in the given list of values, binding
+    /// the list as one array parameter on PostgreSQL. Databases without array
+    /// support get `IN` lists of at most [`DEFAULT_IN_ARRAY_CHUNK_SIZE`]
+    /// values, see [`Compare::chunk_size`] to change the size.
+    ///
+    /// Useful with large lists, which would otherwise need a parameter for
+    /// every value.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").so_that("id".in_array(vec![1, 2, 3]));
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"id\" = ANY($1)", sql);
+    /// assert_eq!(vec![Value::array(vec![1, 2, 3])], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DEFAULT_IN_ARRAY_CHUNK_SIZE`]: constant.DEFAULT_IN_ARRAY_CHUNK_SIZE.html
+    /// [`Compare::chunk_size`]: enum.Compare.html#method.chunk_size
+    fn in_array<T, V>(self, values: T) -> Compare<'a>
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<Value<'a>>;
+
     /// Tests if the left side includes the right side string.
     ///
     /// ```rust
@@ -621,6 +694,90 @@ pub trait Comparable<'a> {
     where
         T: Into<Cow<'a, str>>,
         V: Into<Expression<'a>>;
+
+    /// Compares the left side to every row of a subquery with the given
+    /// operator, such as `=`, and quantifier. Supported on PostgreSQL, MySQL
+    /// and SQL Server. See [`eq_any`](#method.eq_any) and the other shorthands.
+    fn compare_quantified<T>(self, comparator: &'static str, quantifier: Quantifier, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if the left side equals any row of the subquery.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let sub = Select::from_table("admins").column("user_id").so_that("active".equals(true));
+    /// let query = Select::from_table("users").so_that("id".eq_any(sub));
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT \"users\".* FROM \"users\" WHERE \"id\" = ANY (SELECT \"user_id\" FROM \"admins\" WHERE \"active\" = $1)",
+    ///     sql
+    /// );
+    ///
+    /// assert_eq!(vec![Value::from(true)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn eq_any<T>(self, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        Self: Sized,
+    {
+        self.compare_quantified("=", Quantifier::Any, selection)
+    }
+
+    /// Tests if the left side is greater than any row of the subquery.
+    fn gt_any<T>(self, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        Self: Sized,
+    {
+        self.compare_quantified(">", Quantifier::Any, selection)
+    }
+
+    /// Tests if the left side is greater than every row of the subquery.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let sub = Select::from_table("products").column("price").so_that("category".equals("toys"));
+    /// let query = Select::from_table("products").so_that("price".gt_all(sub));
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT `products`.* FROM `products` WHERE `price` > ALL (SELECT `price` FROM `products` WHERE `category` = ?)",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn gt_all<T>(self, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        Self: Sized,
+    {
+        self.compare_quantified(">", Quantifier::All, selection)
+    }
+
+    /// Tests if the left side is less than any row of the subquery.
+    fn lt_any<T>(self, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        Self: Sized,
+    {
+        self.compare_quantified("<", Quantifier::Any, selection)
+    }
+
+    /// Tests if the left side is less than every row of the subquery.
+    fn lt_all<T>(self, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        Self: Sized,
+    {
+        self.compare_quantified("<", Quantifier::All, selection)
+    }
 }
 
 impl<'a, U> Comparable<'a> for U
@@ -700,6 +857,16 @@ where
         val.not_in_selection(selection)
     }
 
+    fn in_array<T, V>(self, values: T) -> Compare<'a>
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<Value<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.in_array(values)
+    }
+
     fn like<T>(self, pattern: T) -> Compare<'a>
     where
         T: Into<Cow<'a, str>>,
@@ -797,4 +964,13 @@ where
 
         left.compare_raw(raw_comparator.into(), right)
     }
+
+    fn compare_quantified<T>(self, comparator: &'static str, quantifier: Quantifier, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+        val.compare_quantified(comparator, quantifier, selection)
+    }
 }
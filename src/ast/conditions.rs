@@ -63,35 +63,85 @@ impl<'a> ConditionTree<'a> {
     }
 
     /// An `AND` statement, is true when both sides are true.
-    pub fn and<E>(mut self, other: E) -> ConditionTree<'a>
+    ///
+    /// A `NoCondition` on either side is dropped, and a `NegativeCondition`
+    /// on either side makes the whole statement a `NegativeCondition`.
+    pub fn and<E>(self, other: E) -> ConditionTree<'a>
     where
         E: Into<Expression<'a>>,
     {
-        match self {
-            Self::And(ref mut conditions) => {
-                conditions.push(other.into());
-                self
+        match (self, other.into()) {
+            (Self::NegativeCondition, _) => Self::NegativeCondition,
+            (_, other) if is_leaf(&other, &Self::NegativeCondition) => Self::NegativeCondition,
+            (Self::NoCondition, other) => into_tree(other),
+            (tree, other) if is_leaf(&other, &Self::NoCondition) => tree,
+            (Self::And(mut conditions), other) => {
+                conditions.push(other);
+                Self::And(conditions)
             }
-            Self::Single(expr) => Self::And(vec![*expr, other.into()]),
-            _ => Self::And(vec![Expression::from(self), other.into()]),
+            (Self::Single(expr), other) => Self::And(vec![*expr, other]),
+            (tree, other) => Self::And(vec![Expression::from(tree), other]),
         }
     }
 
     /// An `OR` statement, is true when one side is true.
-    pub fn or<E>(mut self, other: E) -> ConditionTree<'a>
+    ///
+    /// A `NegativeCondition` on either side is dropped, and a `NoCondition`
+    /// on either side makes the whole statement a `NoCondition`.
+    pub fn or<E>(self, other: E) -> ConditionTree<'a>
     where
         E: Into<Expression<'a>>,
     {
-        match self {
-            Self::Or(ref mut conditions) => {
-                conditions.push(other.into());
-                self
+        match (self, other.into()) {
+            (Self::NoCondition, _) => Self::NoCondition,
+            (_, other) if is_leaf(&other, &Self::NoCondition) => Self::NoCondition,
+            (Self::NegativeCondition, other) => into_tree(other),
+            (tree, other) if is_leaf(&other, &Self::NegativeCondition) => tree,
+            (Self::Or(mut conditions), other) => {
+                conditions.push(other);
+                Self::Or(conditions)
             }
-            Self::Single(expr) => Self::Or(vec![*expr, other.into()]),
-            _ => Self::Or(vec![Expression::from(self), other.into()]),
+            (Self::Single(expr), other) => Self::Or(vec![*expr, other]),
+            (tree, other) => Self::Or(vec![Expression::from(tree), other]),
         }
     }
 
+    /// Combines the conditions with `AND`. Without conditions the result is
+    /// a `NoCondition`, and a single condition is returned as is.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let filters = vec!["name".equals("Musti"), "age".greater_than(3)];
+    /// let query = Select::from_table("cats").so_that(ConditionTree::all_of(filters));
+    /// let (sql, _) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("SELECT `cats`.* FROM `cats` WHERE (`name` = ? AND `age` > ?)", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn all_of<I, E>(conditions: I) -> ConditionTree<'a>
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Expression<'a>>,
+    {
+        conditions
+            .into_iter()
+            .fold(Self::NoCondition, |tree, condition| tree.and(condition))
+    }
+
+    /// Combines the conditions with `OR`. Without conditions the result is
+    /// a `NegativeCondition`, and a single condition is returned as is.
+    pub fn any_of<I, E>(conditions: I) -> ConditionTree<'a>
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Expression<'a>>,
+    {
+        conditions
+            .into_iter()
+            .fold(Self::NegativeCondition, |tree, condition| tree.or(condition))
+    }
+
     /// A `NOT` statement, is true when the expression is false.
     pub fn not<E>(left: E) -> ConditionTree<'a>
     where
@@ -118,6 +168,23 @@ impl<'a> ConditionTree<'a> {
     }
 }
 
+fn is_leaf(expr: &Expression<'_>, leaf: &ConditionTree<'_>) -> bool {
+    match &expr.kind {
+        ExpressionKind::ConditionTree(tree) => tree == leaf,
+        _ => false,
+    }
+}
+
+fn into_tree(expr: Expression<'_>) -> ConditionTree<'_> {
+    match expr {
+        Expression {
+            kind: ExpressionKind::ConditionTree(tree),
+            alias: None,
+        } => tree,
+        expr => ConditionTree::single(expr),
+    }
+}
+
 impl<'a> Default for ConditionTree<'a> {
     fn default() -> Self {
         ConditionTree::NoCondition
@@ -133,6 +200,25 @@ impl<'a> From<ConditionTree<'a>> for Expression<'a> {
     }
 }
 
+/// A bare column as a condition, true when the boolean value of the column
+/// is true. On SQL Server the column is compared to `1`.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").so_that(Column::from("is_active"));
+/// let (sql, _) = Mysql::build(query)?;
+///
+/// assert_eq!("SELECT `users`.* FROM `users` WHERE `is_active`", sql);
+/// # Ok(())
+/// # }
+/// ```
+impl<'a> From<Column<'a>> for ConditionTree<'a> {
+    fn from(column: Column<'a>) -> Self {
+        ConditionTree::single(column)
+    }
+}
+
 impl<'a> From<Select<'a>> for ConditionTree<'a> {
     fn from(sel: Select<'a>) -> Self {
         let exp = Expression {
@@ -0,0 +1,308 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// The type of a column in a `CREATE TABLE` statement, rendered with the
+/// native type name of the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// A 32-bit integer.
+    Integer,
+    /// A 64-bit integer.
+    BigInt,
+    /// Text without a length limit.
+    Text,
+    /// Text of at most the given number of characters.
+    Varchar(u32),
+    /// A boolean.
+    Boolean,
+    /// A double-precision float.
+    Double,
+    /// A date and time.
+    DateTime,
+    /// Binary data.
+    Bytes,
+}
+
+/// A constraint of a column in a `CREATE TABLE` statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnConstraint {
+    /// `NOT NULL`
+    NotNull,
+    /// `PRIMARY KEY`
+    PrimaryKey,
+    /// The column gets its value from a sequence, such as `AUTO_INCREMENT` in
+    /// MySQL, `SERIAL` in PostgreSQL or `AUTOINCREMENT` in SQLite.
+    AutoIncrement,
+    /// `UNIQUE`
+    Unique,
+}
+
+/// A column definition in a `CREATE TABLE` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDefinition<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) typ: ColumnType,
+    pub(crate) constraints: Vec<ColumnConstraint>,
+}
+
+impl<'a> ColumnDefinition<'a> {
+    pub(crate) fn has(&self, constraint: ColumnConstraint) -> bool {
+        self.constraints.contains(&constraint)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A builder for a `CREATE TABLE` statement.
+pub struct CreateTable<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) columns: Vec<ColumnDefinition<'a>>,
+    pub(crate) foreign_keys: Vec<ForeignKey<'a>>,
+}
+
+impl<'a> From<CreateTable<'a>> for Query<'a> {
+    fn from(create: CreateTable<'a>) -> Self {
+        Query::CreateTable(Box::new(create))
+    }
+}
+
+impl<'a> CreateTable<'a> {
+    /// Creates a new `CREATE TABLE` statement for the given table.
+    pub fn new<T>(table: T) -> Self
+    where
+        T: Into<Table<'a>>,
+    {
+        Self {
+            table: table.into(),
+            columns: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    /// Adds a column to the table. The types and the auto-increment
+    /// constraint are written in the dialect of the visitor.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = CreateTable::new("users")
+    ///     .column("id", ColumnType::Integer, vec![ColumnConstraint::PrimaryKey, ColumnConstraint::AutoIncrement])
+    ///     .column("name", ColumnType::Varchar(255), vec![ColumnConstraint::NotNull]);
+    ///
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE \"users\" (\"id\" SERIAL PRIMARY KEY, \"name\" varchar(255) NOT NULL)",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn column<N>(mut self, name: N, typ: ColumnType, constraints: Vec<ColumnConstraint>) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        self.columns.push(ColumnDefinition {
+            name: name.into(),
+            typ,
+            constraints,
+        });
+
+        self
+    }
+
+    /// Adds a foreign key constraint to the table, written after the
+    /// columns.
+    pub fn foreign_key(mut self, foreign_key: ForeignKey<'a>) -> Self {
+        self.foreign_keys.push(foreign_key);
+        self
+    }
+}
+
+/// A `CREATE TABLE ... AS SELECT` statement, creating a table from the
+/// results of a query. Created with
+/// [`Select::into_table`](struct.Select.html#method.into_table).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTableAs<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) select: Select<'a>,
+}
+
+impl<'a> From<CreateTableAs<'a>> for Query<'a> {
+    fn from(create: CreateTableAs<'a>) -> Self {
+        Query::CreateTableAs(Box::new(create))
+    }
+}
+
+/// The action taken on the referencing rows when the referenced row of a
+/// foreign key is deleted or updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferentialAction {
+    /// Deletes or updates the referencing rows.
+    Cascade,
+    /// Sets the referencing columns to `NULL`.
+    SetNull,
+    /// Sets the referencing columns to their defaults. Not supported on
+    /// MySQL.
+    SetDefault,
+    /// Fails immediately. Written as `NO ACTION` on SQL Server.
+    Restrict,
+    /// Fails at the end of the statement.
+    NoAction,
+}
+
+/// A foreign key constraint in a `CREATE TABLE` statement.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let foreign_key = ForeignKey::new(vec!["owner_id"], "users", vec!["id"])
+///     .name("cats_owner_fkey")
+///     .on_delete(ReferentialAction::Cascade);
+///
+/// let query = CreateTable::new("cats")
+///     .column("owner_id", ColumnType::Integer, vec![])
+///     .foreign_key(foreign_key);
+///
+/// let (sql, _) = Postgres::build(query)?;
+///
+/// assert_eq!(
+///     "CREATE TABLE \"cats\" (\"owner_id\" int4, CONSTRAINT \"cats_owner_fkey\" \
+///      FOREIGN KEY (\"owner_id\") REFERENCES \"users\" (\"id\") ON DELETE CASCADE)",
+///     sql
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKey<'a> {
+    pub(crate) name: Option<Cow<'a, str>>,
+    pub(crate) columns: Vec<Cow<'a, str>>,
+    pub(crate) referenced_table: Table<'a>,
+    pub(crate) referenced_columns: Vec<Cow<'a, str>>,
+    pub(crate) on_delete: Option<ReferentialAction>,
+    pub(crate) on_update: Option<ReferentialAction>,
+}
+
+impl<'a> ForeignKey<'a> {
+    /// A foreign key from the given columns to the columns of the referenced
+    /// table.
+    pub fn new<C, T, R>(columns: Vec<C>, referenced_table: T, referenced_columns: Vec<R>) -> Self
+    where
+        C: Into<Cow<'a, str>>,
+        T: Into<Table<'a>>,
+        R: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: None,
+            columns: columns.into_iter().map(Into::into).collect(),
+            referenced_table: referenced_table.into(),
+            referenced_columns: referenced_columns.into_iter().map(Into::into).collect(),
+            on_delete: None,
+            on_update: None,
+        }
+    }
+
+    /// Names the constraint.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The action taken when the referenced row is deleted.
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        self.on_delete = Some(action);
+        self
+    }
+
+    /// The action taken when the referenced columns are updated.
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        self.on_update = Some(action);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A builder for a `CREATE INDEX` statement.
+pub struct CreateIndex<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) columns: Vec<Cow<'a, str>>,
+    pub(crate) name: Option<Cow<'a, str>>,
+    pub(crate) unique: bool,
+}
+
+impl<'a> From<CreateIndex<'a>> for Query<'a> {
+    fn from(create: CreateIndex<'a>) -> Self {
+        Query::CreateIndex(Box::new(create))
+    }
+}
+
+impl<'a> CreateIndex<'a> {
+    /// Creates a new `CREATE INDEX` statement for the given columns of a
+    /// table.
+    ///
+    /// Without a [`name`](#method.name), the index is named after the table
+    /// and the columns, such as `users_email_idx`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = CreateIndex::on("users", vec!["email"]).unique().name("idx_users_email");
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("CREATE UNIQUE INDEX `idx_users_email` ON `users` (`email`)", sql);
+    ///
+    /// let (sql, _) = Mysql::build(CreateIndex::on("users", vec!["name", "age"]))?;
+    ///
+    /// assert_eq!("CREATE INDEX `users_name_age_idx` ON `users` (`name`, `age`)", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on<T, C>(table: T, columns: Vec<C>) -> Self
+    where
+        T: Into<Table<'a>>,
+        C: Into<Cow<'a, str>>,
+    {
+        Self {
+            table: table.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            name: None,
+            unique: false,
+        }
+    }
+
+    /// Sets the name of the index.
+    pub fn name<N>(mut self, name: N) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Creates a `UNIQUE` index.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    pub(crate) fn index_name(&self) -> Cow<'a, str> {
+        match self.name {
+            Some(ref name) => name.clone(),
+            None => {
+                let mut parts: Vec<&str> = Vec::with_capacity(self.columns.len() + 2);
+
+                if let TableType::Table(ref table) = self.table.typ {
+                    parts.push(table);
+                }
+
+                parts.extend(self.columns.iter().map(|c| c.as_ref()));
+                parts.push("idx");
+
+                Cow::Owned(parts.join("_"))
+            }
+        }
+    }
+}
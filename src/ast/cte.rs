@@ -13,6 +13,7 @@ pub struct CommonTableExpression<'a> {
     pub(crate) identifier: Cow<'a, str>,
     pub(crate) columns: Vec<Cow<'a, str>>,
     pub(crate) selection: SelectQuery<'a>,
+    pub(crate) recursive: bool,
 }
 
 impl<'a> CommonTableExpression<'a> {
@@ -38,6 +39,7 @@ pub trait IntoCommonTableExpression<'a> {
             identifier: identifier.into(),
             columns: Vec::new(),
             selection: self.into(),
+            recursive: false,
         }
     }
 }
@@ -0,0 +1,103 @@
+use crate::ast::*;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A builder for a `DROP TABLE` statement.
+pub struct DropTable<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) if_exists: bool,
+}
+
+impl<'a> From<DropTable<'a>> for Query<'a> {
+    fn from(drop: DropTable<'a>) -> Self {
+        Query::DropTable(Box::new(drop))
+    }
+}
+
+impl<'a> DropTable<'a> {
+    /// Creates a new `DROP TABLE` statement for the given table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let (sql, _) = Mysql::build(DropTable::table("user"))?;
+    ///
+    /// assert_eq!("DROP TABLE `user`", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn table<T>(table: T) -> Self
+    where
+        T: Into<Table<'a>>,
+    {
+        Self {
+            table: table.into(),
+            if_exists: false,
+        }
+    }
+
+    /// Does nothing if the table does not exist.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let (sql, _) = Mysql::build(DropTable::table("user").if_exists())?;
+    ///
+    /// assert_eq!("DROP TABLE IF EXISTS `user`", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A builder for a `DROP INDEX` statement.
+pub struct DropIndex<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) table: Table<'a>,
+    pub(crate) if_exists: bool,
+}
+
+impl<'a> From<DropIndex<'a>> for Query<'a> {
+    fn from(drop: DropIndex<'a>) -> Self {
+        Query::DropIndex(Box::new(drop))
+    }
+}
+
+impl<'a> DropIndex<'a> {
+    /// Creates a new `DROP INDEX` statement for the named index of the given
+    /// table. MySQL and SQL Server name the table in the statement, on
+    /// PostgreSQL and SQLite the index is qualified with the database of the
+    /// table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = DropIndex::index("user_email_idx", ("public", "user"));
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("DROP INDEX \"public\".\"user_email_idx\"", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn index<N, T>(name: N, table: T) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        T: Into<Table<'a>>,
+    {
+        Self {
+            name: name.into(),
+            table: table.into(),
+            if_exists: false,
+        }
+    }
+
+    /// Does nothing if the index does not exist. Not supported on MySQL.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
@@ -21,6 +21,36 @@ impl<'a> Expression<'a> {
         self.alias.as_ref().map(|s| s.as_ref())
     }
 
+    /// Compares or orders the expression with the given collation. The name is
+    /// database-specific and written as given, failing the query if it has
+    /// characters not allowed in an identifier. PostgreSQL quotes the name,
+    /// so it is matched case-sensitively.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users")
+    ///     .order_by(Column::from("name").collate("utf8mb4_unicode_ci").ascend());
+    ///
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT `users`.* FROM `users` ORDER BY `name` COLLATE utf8mb4_unicode_ci ASC",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collate<C>(self, collation: C) -> Self
+    where
+        C: Into<Cow<'a, str>>,
+    {
+        Self {
+            kind: ExpressionKind::Collate(Box::new(self), collation.into()),
+            alias: None,
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn row(row: Row<'a>) -> Self {
         Self {
@@ -52,6 +82,15 @@ impl<'a> Expression<'a> {
         }
     }
 
+    pub(crate) fn is_null_value(&self) -> bool {
+        match &self.kind {
+            ExpressionKind::Parameterized(value) => value.is_null(),
+            ExpressionKind::Value(expr) => expr.is_null_value(),
+            ExpressionKind::Null => true,
+            _ => false,
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn is_xml_value(&self) -> bool {
         self.kind.is_xml_value()
@@ -101,6 +140,17 @@ impl<'a> Expression<'a> {
         matches!(self.kind, ExpressionKind::Column(_))
     }
 
+    /// True if the database chooses the name of the expression in the
+    /// result columns.
+    pub(crate) fn is_unnamed(&self) -> bool {
+        match self.kind {
+            _ if self.alias.is_some() => false,
+            ExpressionKind::Column(_) | ExpressionKind::Asterisk(_) => false,
+            ExpressionKind::Function(ref fun) => fun.alias.is_none(),
+            _ => true,
+        }
+    }
+
     /// Finds all comparisons between a tuple and a selection. If returning some
     /// CTEs, they should be handled in the calling layer.
     #[cfg(feature = "mssql")]
@@ -180,6 +230,16 @@ pub enum ExpressionKind<'a> {
     Value(Box<Expression<'a>>),
     /// DEFAULT keyword, e.g. for `INSERT INTO ... VALUES (..., DEFAULT, ...)`
     Default,
+    /// NULL keyword, e.g. for `SELECT NULL AS x`
+    Null,
+    /// An expression with a collation, e.g. `name COLLATE utf8mb4_unicode_ci`
+    Collate(Box<Expression<'a>>, Cow<'a, str>),
+    /// An SQL fragment written to the query as-is, e.g. `CURRENT_DATE`
+    RawSql(Cow<'a, str>),
+    /// A `CASE` expression
+    Case(Box<Case<'a>>),
+    /// A text parameter cast to the named type, e.g. `$1::text::hstore`
+    TypedText(Cow<'a, str>, Cow<'a, str>),
 }
 
 impl<'a> ExpressionKind<'a> {
@@ -201,6 +261,28 @@ pub fn asterisk() -> Expression<'static> {
 }
 
 /// A quick alias to create a default value expression.
+///
+/// Writes the `DEFAULT` keyword to an `INSERT`, making the database use the
+/// default of the column for that cell only:
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let insert = Insert::multi_into("cats", vec!["name", "age"])
+///     .values(vec![Expression::from("Musti"), default_value()])
+///     .values(vec![Expression::from("Naukio"), Expression::from(7)]);
+///
+/// let (sql, params) = Postgres::build(insert)?;
+///
+/// assert_eq!(
+///     "INSERT INTO \"cats\" (\"name\",\"age\") VALUES ($1,DEFAULT), ($2,$3)",
+///     sql
+/// );
+///
+/// assert_eq!(vec![Value::from("Musti"), Value::from("Naukio"), Value::from(7)], params);
+/// # Ok(())
+/// # }
+/// ```
 pub fn default_value() -> Expression<'static> {
     Expression {
         kind: ExpressionKind::Default,
@@ -208,6 +290,95 @@ pub fn default_value() -> Expression<'static> {
     }
 }
 
+/// A quick alias to create a `NULL` literal.
+///
+/// Writes the `NULL` keyword instead of binding a null parameter, which has
+/// no type in some databases. Useful for filling the columns a branch of a
+/// `UNION` does not have:
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let cats = Select::from_table("cats").column("name").column("lives");
+/// let dogs = Select::from_table("dogs").column("name").value(null_value().alias("lives"));
+///
+/// let (sql, _) = Postgres::build(Union::new(cats).all(dogs))?;
+///
+/// assert_eq!(
+///     "SELECT \"name\", \"lives\" FROM \"cats\" UNION ALL SELECT \"name\", NULL AS \"lives\" FROM \"dogs\"",
+///     sql
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn null_value() -> Expression<'static> {
+    Expression {
+        kind: ExpressionKind::Null,
+        alias: None,
+    }
+}
+
+/// An SQL fragment written to the query as-is, such as a function call the
+/// AST does not support. Unlike [`IntoRaw`](trait.IntoRaw.html), a text
+/// fragment is not quoted.
+///
+/// Must be used carefully to avoid SQL injections.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").so_that("created".equals(raw("CURRENT_DATE")));
+/// let (sql, params) = Mysql::build(query)?;
+///
+/// assert_eq!("SELECT `users`.* FROM `users` WHERE `created` = CURRENT_DATE", sql);
+/// assert!(params.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn raw<'a, T>(sql: T) -> Expression<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    Expression {
+        kind: ExpressionKind::RawSql(sql.into()),
+        alias: None,
+    }
+}
+
+/// A literal in the text format of a type the database has no native
+/// value for, such as `hstore` or `int4range` on PostgreSQL. The literal is
+/// bound as a text parameter and cast to the type.
+///
+/// On PostgreSQL the parameter is sent as text and cast twice, as in
+/// `$1::text::hstore`, for the database to parse it with the input function
+/// of the type. The other databases write `CAST(? AS type)`.
+///
+/// The type name is written to the query as-is and must never come from
+/// user input to avoid SQL injections. The literal is a parameter, but it is
+/// up to the caller to format and escape it as the type expects.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let insert = Insert::single_into("products").value("attributes", typed_text(r#""color"=>"red""#, "hstore"));
+/// let (sql, params) = Postgres::build(insert)?;
+///
+/// assert_eq!("INSERT INTO \"products\" (\"attributes\") VALUES ($1::text::hstore)", sql);
+/// assert_eq!(vec![Value::from(r#""color"=>"red""#)], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn typed_text<'a, L, T>(literal: L, type_name: T) -> Expression<'a>
+where
+    L: Into<Cow<'a, str>>,
+    T: Into<Cow<'a, str>>,
+{
+    Expression {
+        kind: ExpressionKind::TypedText(literal.into(), type_name.into()),
+        alias: None,
+    }
+}
+
 expression!(Row, Row);
 expression!(Function, Function);
 
@@ -283,14 +454,20 @@ impl<'a> Comparable<'a> for Expression<'a> {
     where
         T: Into<Expression<'a>>,
     {
-        Compare::Equals(Box::new(self), Box::new(comparison.into()))
+        match comparison.into() {
+            comparison if comparison.is_null_value() => Compare::Null(Box::new(self)),
+            comparison => Compare::Equals(Box::new(self), Box::new(comparison)),
+        }
     }
 
     fn not_equals<T>(self, comparison: T) -> Compare<'a>
     where
         T: Into<Expression<'a>>,
     {
-        Compare::NotEquals(Box::new(self), Box::new(comparison.into()))
+        match comparison.into() {
+            comparison if comparison.is_null_value() => Compare::NotNull(Box::new(self)),
+            comparison => Compare::NotEquals(Box::new(self), Box::new(comparison)),
+        }
     }
 
     fn less_than<T>(self, comparison: T) -> Compare<'a>
@@ -335,6 +512,15 @@ impl<'a> Comparable<'a> for Expression<'a> {
         Compare::NotIn(Box::new(self), Box::new(selection.into()))
     }
 
+    fn in_array<T, V>(self, values: T) -> Compare<'a>
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<Value<'a>>,
+    {
+        let values = values.into_iter().map(|v| v.into()).collect();
+        Compare::InArray(Box::new(self), values, DEFAULT_IN_ARRAY_CHUNK_SIZE)
+    }
+
     fn like<T>(self, pattern: T) -> Compare<'a>
     where
         T: Into<Cow<'a, str>>,
@@ -408,4 +594,11 @@ impl<'a> Comparable<'a> for Expression<'a> {
     {
         Compare::Raw(Box::new(self), raw_comparator.into(), Box::new(right.into()))
     }
+
+    fn compare_quantified<T>(self, comparator: &'static str, quantifier: Quantifier, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::Quantified(Box::new(self), comparator, quantifier, Box::new(selection.into()))
+    }
 }
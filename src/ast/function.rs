@@ -1,25 +1,37 @@
 mod aggregate_to_string;
 mod average;
 mod count;
+mod filter;
+mod greatest;
+mod least;
 mod lower;
 mod maximum;
 mod minimum;
+mod pad;
 mod row_number;
 mod row_to_json;
+mod substring;
 mod sum;
 mod upper;
+mod window;
 
 pub use aggregate_to_string::*;
 pub use average::*;
 pub use count::*;
+pub use filter::*;
+pub use greatest::*;
+pub use least::*;
 pub use lower::*;
 pub use maximum::*;
 pub use minimum::*;
+pub use pad::*;
 pub use row_number::*;
 #[cfg(all(feature = "json", feature = "postgresql"))]
 pub use row_to_json::*;
+pub use substring::*;
 pub use sum::*;
 pub use upper::*;
+pub use window::*;
 
 use super::{Aliasable, Expression};
 use std::borrow::Cow;
@@ -45,6 +57,12 @@ pub(crate) enum FunctionType<'a> {
     Upper(Upper<'a>),
     Minimum(Minimum<'a>),
     Maximum(Maximum<'a>),
+    Substring(Substring<'a>),
+    Pad(Pad<'a>),
+    Greatest(Greatest<'a>),
+    Least(Least<'a>),
+    AggregateFilter(AggregateFilter<'a>),
+    Window(Window<'a>),
 }
 
 impl<'a> Aliasable<'a> for Function<'a> {
@@ -71,5 +89,11 @@ function!(
     Lower,
     Upper,
     Minimum,
-    Maximum
+    Maximum,
+    Substring,
+    Pad,
+    Greatest,
+    Least,
+    AggregateFilter,
+    Window
 );
@@ -1,10 +1,11 @@
 use super::Function;
-use crate::ast::Expression;
+use crate::ast::{Expression, ExpressionKind};
 
 #[derive(Debug, Clone, PartialEq)]
 /// Returns the number of rows that matches a specified criteria.
 pub struct Count<'a> {
     pub(crate) exprs: Vec<Expression<'a>>,
+    pub(crate) distinct: bool,
 }
 
 /// Count of the underlying table where the given expression is not null.
@@ -24,7 +25,62 @@ where
 {
     let fun = Count {
         exprs: vec![expr.into()],
+        distinct: false,
     };
 
     fun.into()
 }
+
+/// Count of all rows of the underlying table, the same as
+/// `count(asterisk())`.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").value(count_star());
+/// let (sql, _) = Sqlite::build(query)?;
+/// assert_eq!("SELECT COUNT(*) FROM `users`", sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn count_star<'a>() -> Function<'a> {
+    let fun = Count {
+        exprs: Vec::new(),
+        distinct: false,
+    };
+    fun.into()
+}
+
+/// Count of the distinct values of the given expression. A row of columns
+/// counts the distinct combinations, which only MySQL supports.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").value(count_distinct(Column::from("name")));
+/// let (sql, _) = Mysql::build(query)?;
+/// assert_eq!("SELECT COUNT(DISTINCT `name`) FROM `users`", sql);
+///
+/// let columns = vec![Column::from("name"), Column::from("age")];
+/// let query = Select::from_table("users").value(count_distinct(columns));
+/// let (sql, _) = Mysql::build(query)?;
+/// assert_eq!("SELECT COUNT(DISTINCT `name`, `age`) FROM `users`", sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn count_distinct<'a, T>(expr: T) -> Function<'a>
+where
+    T: Into<Expression<'a>>,
+{
+    let exprs = match expr.into() {
+        Expression {
+            kind: ExpressionKind::Row(row),
+            alias: None,
+        } => row.values,
+        expr => vec![expr],
+    };
+
+    let fun = Count { exprs, distinct: true };
+
+    fun.into()
+}
@@ -0,0 +1,52 @@
+use super::Function;
+use crate::ast::ConditionTree;
+
+/// An aggregate function with a `FILTER (WHERE ...)` clause.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateFilter<'a> {
+    pub(crate) function: Box<Function<'a>>,
+    pub(crate) conditions: ConditionTree<'a>,
+}
+
+impl<'a> Function<'a> {
+    /// Aggregates only the rows matching the conditions. Rendered as
+    /// `FILTER (WHERE ...)` in PostgreSQL and SQLite.
+    ///
+    /// MySQL and SQL Server have no `FILTER` clause, so the `COUNT`, `SUM`,
+    /// `AVG`, `MIN` and `MAX` aggregates are rewritten to aggregate over a
+    /// `CASE` expression. Other functions return an error on these
+    /// databases.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let active = count_star().filter("status".equals("active")).alias("active");
+    /// let query = Select::from_table("users").value(active);
+    ///
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT COUNT(*) FILTER (WHERE \"status\" = $1) AS \"active\" FROM \"users\"",
+    ///     sql
+    /// );
+    /// assert_eq!(vec![Value::from("active")], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter<T>(self, conditions: T) -> Function<'a>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        let alias = self.alias.clone();
+
+        let fun = AggregateFilter {
+            function: Box::new(Function { alias: None, ..self }),
+            conditions: conditions.into(),
+        };
+
+        Function {
+            alias,
+            ..Function::from(fun)
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::Function;
+use crate::ast::Expression;
+
+/// A represention of the `LEAST` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Least<'a> {
+    pub(crate) exprs: Vec<Expression<'a>>,
+}
+
+/// Returns the smallest value of the given expressions. Rendered as `MIN` with
+/// multiple arguments in SQLite.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").value(least(vec![Column::from("a").into(), Expression::from(0)]));
+/// let (sql, params) = Postgres::build(query)?;
+///
+/// assert_eq!("SELECT LEAST(\"a\", $1) FROM \"users\"", sql);
+/// assert_eq!(vec![Value::from(0)], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn least<'a, T>(exprs: Vec<T>) -> Function<'a>
+where
+    T: Into<Expression<'a>>,
+{
+    let fun = Least {
+        exprs: exprs.into_iter().map(Into::into).collect(),
+    };
+
+    fun.into()
+}
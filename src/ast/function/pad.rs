@@ -0,0 +1,80 @@
+use super::Function;
+use crate::ast::Expression;
+
+/// A represention of the `LPAD` and `RPAD` functions in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pad<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) length: Box<Expression<'a>>,
+    pub(crate) pad: Box<Expression<'a>>,
+    pub(crate) side: PadSide,
+}
+
+/// The side of the string to pad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PadSide {
+    Left,
+    Right,
+}
+
+/// Pads the string from the left with `pad` up to `length` characters. Not
+/// supported in SQLite and SQL Server.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").value(lpad(Column::from("id"), 8, "0"));
+/// let (sql, params) = Mysql::build(query)?;
+///
+/// assert_eq!("SELECT LPAD(`id`, ?, ?) FROM `users`", sql);
+/// assert_eq!(vec![Value::from(8), Value::from("0")], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn lpad<'a, E, L, P>(expression: E, length: L, pad: P) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    L: Into<Expression<'a>>,
+    P: Into<Expression<'a>>,
+{
+    padding(expression, length, pad, PadSide::Left)
+}
+
+/// Pads the string from the right with `pad` up to `length` characters. Not
+/// supported in SQLite and SQL Server.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").value(rpad(Column::from("name"), 10, "."));
+/// let (sql, params) = Postgres::build(query)?;
+///
+/// assert_eq!("SELECT RPAD(\"name\", $1, $2) FROM \"users\"", sql);
+/// assert_eq!(vec![Value::from(10), Value::from(".")], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rpad<'a, E, L, P>(expression: E, length: L, pad: P) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    L: Into<Expression<'a>>,
+    P: Into<Expression<'a>>,
+{
+    padding(expression, length, pad, PadSide::Right)
+}
+
+fn padding<'a, E, L, P>(expression: E, length: L, pad: P, side: PadSide) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    L: Into<Expression<'a>>,
+    P: Into<Expression<'a>>,
+{
+    let fun = Pad {
+        expression: Box::new(expression.into()),
+        length: Box::new(length.into()),
+        pad: Box::new(pad.into()),
+        side,
+    };
+
+    fun.into()
+}
@@ -13,7 +13,7 @@ impl<'a> RowNumber<'a> {
     where
         T: IntoOrderDefinition<'a>,
     {
-        self.over.ordering = self.over.ordering.append(value.into_order_definition());
+        self.over = self.over.order_by(value);
         self
     }
 
@@ -22,7 +22,7 @@ impl<'a> RowNumber<'a> {
     where
         T: Into<Column<'a>>,
     {
-        self.over.partitioning.push(partition.into());
+        self.over = self.over.partition_by(partition);
         self
     }
 }
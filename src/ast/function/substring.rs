@@ -0,0 +1,39 @@
+use super::Function;
+use crate::ast::Expression;
+
+/// A represention of the `SUBSTRING` function in the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Substring<'a> {
+    pub(crate) expression: Box<Expression<'a>>,
+    pub(crate) start: Box<Expression<'a>>,
+    pub(crate) length: Box<Expression<'a>>,
+}
+
+/// Takes `length` characters of the string, starting from the one-based
+/// position `start`.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("users").value(substring(Column::from("name"), 2, 3));
+/// let (sql, params) = Sqlite::build(query)?;
+///
+/// assert_eq!("SELECT SUBSTR(`name`, ?, ?) FROM `users`", sql);
+/// assert_eq!(vec![Value::from(2), Value::from(3)], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn substring<'a, E, S, L>(expression: E, start: S, length: L) -> Function<'a>
+where
+    E: Into<Expression<'a>>,
+    S: Into<Expression<'a>>,
+    L: Into<Expression<'a>>,
+{
+    let fun = Substring {
+        expression: Box::new(expression.into()),
+        start: Box::new(start.into()),
+        length: Box::new(length.into()),
+    };
+
+    fun.into()
+}
@@ -0,0 +1,44 @@
+use super::Function;
+use crate::ast::Over;
+
+/// A function evaluated over a window of rows, such as a moving sum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Window<'a> {
+    pub(crate) function: Box<Function<'a>>,
+    pub(crate) over: Over<'a>,
+}
+
+impl<'a> Function<'a> {
+    /// Evaluates an aggregate over a window of rows, with an optional frame.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}, col};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let over = Over::default()
+    ///     .order_by("day")
+    ///     .rows_between(FrameBound::Preceding(6), FrameBound::CurrentRow);
+    ///
+    /// let query = Select::from_table("sales").value(sum(col!("amount")).over(over).alias("weekly"));
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT SUM(\"amount\") OVER (ORDER BY \"day\" ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) AS \"weekly\" FROM \"sales\"",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn over(self, over: Over<'a>) -> Function<'a> {
+        let alias = self.alias.clone();
+
+        let fun = Window {
+            function: Box::new(Function { alias: None, ..self }),
+            over,
+        };
+
+        Function {
+            alias,
+            ..Function::from(fun)
+        }
+    }
+}
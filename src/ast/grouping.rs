@@ -24,6 +24,21 @@ impl<'a> Grouping<'a> {
     }
 }
 
+/// A multi-level grouping, written after the other groupings of the
+/// `GROUP BY` statement.
+///
+/// PostgreSQL and SQL Server support all of the forms. MySQL supports only
+/// `ROLLUP` as `WITH ROLLUP`, and SQLite none of them.
+#[derive(Debug, PartialEq, Clone)]
+pub enum GroupingSet<'a> {
+    /// `ROLLUP (a, b)`, grouping by `(a, b)`, `(a)` and `()`.
+    Rollup(Vec<GroupByDefinition<'a>>),
+    /// `CUBE (a, b)`, grouping by every combination of the columns.
+    Cube(Vec<GroupByDefinition<'a>>),
+    /// `GROUPING SETS ((a, b), (a))`, grouping by each set.
+    Sets(Vec<Vec<GroupByDefinition<'a>>>),
+}
+
 /// An item that can be used in the `GROUP BY` statement
 pub trait Groupable<'a>
 where
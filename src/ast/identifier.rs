@@ -0,0 +1,63 @@
+use crate::error::{Error, ErrorKind};
+
+/// Checks the identifier has no control characters or identifier quotes of
+/// any supported database (`"`, `` ` ``, `[` and `]`).
+///
+/// The visitors escape the quotes of their own dialect, but an identifier
+/// built from untrusted input is safer rejected outright. Used by
+/// [`Column::checked`](struct.Column.html#method.checked) and
+/// [`Table::checked`](struct.Table.html#method.checked).
+///
+/// ```rust
+/// # use quaint::ast::validate_identifier;
+/// assert!(validate_identifier("users").is_ok());
+/// assert!(validate_identifier("users`; DROP TABLE users; --").is_err());
+/// ```
+pub fn validate_identifier(identifier: &str) -> crate::Result<()> {
+    let is_invalid = |c: char| c.is_control() || matches!(c, '"' | '`' | '[' | ']');
+
+    if identifier.is_empty() || identifier.contains(is_invalid) {
+        let kind = ErrorKind::InvalidIdentifier {
+            identifier: identifier.escape_debug().to_string(),
+        };
+
+        return Err(Error::builder(kind).build());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, error::ErrorKind};
+
+    #[test]
+    fn accepts_plain_identifiers() {
+        assert!(validate_identifier("users").is_ok());
+        assert!(validate_identifier("user name").is_ok());
+        assert!(validate_identifier("käyttäjät").is_ok());
+    }
+
+    #[test]
+    fn rejects_null_bytes() {
+        let err = Column::checked("name\0").unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::InvalidIdentifier { .. }));
+
+        let err = Table::checked("users\0").unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::InvalidIdentifier { .. }));
+    }
+
+    #[test]
+    fn rejects_identifier_quotes() {
+        for name in &["na`me", "na\"me", "[name]", "name\n"] {
+            let err = Column::checked(*name).unwrap_err();
+            assert!(matches!(err.kind(), ErrorKind::InvalidIdentifier { .. }));
+        }
+    }
+
+    #[test]
+    fn rejects_empty_identifiers() {
+        let err = Table::checked("").unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::InvalidIdentifier { .. }));
+    }
+}
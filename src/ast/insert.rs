@@ -1,4 +1,8 @@
-use crate::ast::*;
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+    visitor::ParameterCollector,
+};
 
 /// A builder for an `INSERT` statement.
 #[derive(Clone, Debug, PartialEq)]
@@ -7,6 +11,7 @@ pub struct Insert<'a> {
     pub(crate) columns: Vec<Column<'a>>,
     pub(crate) values: Expression<'a>,
     pub(crate) on_conflict: Option<OnConflict>,
+    pub(crate) conflict_target: Option<ConflictTarget<'a>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
@@ -86,6 +91,61 @@ pub enum OnConflict {
     DoNothing,
 }
 
+/// The conflict target of an `ON CONFLICT` clause, naming the columns of a
+/// unique index and, for a partial index, its predicate. Only written on
+/// PostgreSQL, the other databases ignore it.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let target = ConflictTarget::columns(vec!["email"]).so_that("deleted_at".is_null());
+///
+/// let query = Insert::from(Insert::single_into("users").value("email", "musti@example.com"))
+///     .on_conflict(OnConflict::DoNothing)
+///     .conflict_target(target);
+///
+/// let (sql, params) = Postgres::build(query)?;
+///
+/// assert_eq!(
+///     "INSERT INTO \"users\" (\"email\") VALUES ($1) \
+///      ON CONFLICT (\"email\") WHERE \"deleted_at\" IS NULL DO NOTHING",
+///     sql
+/// );
+///
+/// assert_eq!(vec![Value::from("musti@example.com")], params);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConflictTarget<'a> {
+    pub(crate) columns: Vec<Column<'a>>,
+    pub(crate) predicate: Option<ConditionTree<'a>>,
+}
+
+impl<'a> ConflictTarget<'a> {
+    /// A conflict target on the unique index of the given columns.
+    pub fn columns<I, K>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Column<'a>>,
+    {
+        Self {
+            columns: columns.into_iter().map(Into::into).collect(),
+            predicate: None,
+        }
+    }
+
+    /// The predicate of a partial unique index, written as the `WHERE`
+    /// clause of the conflict target.
+    pub fn so_that<T>(mut self, conditions: T) -> Self
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        self.predicate = Some(conditions.into());
+        self
+    }
+}
+
 impl<'a> From<Insert<'a>> for Query<'a> {
     fn from(insert: Insert<'a>) -> Self {
         Query::Insert(Box::new(insert))
@@ -105,6 +165,7 @@ impl<'a> From<SingleRowInsert<'a>> for Insert<'a> {
             columns: insert.columns,
             values,
             on_conflict: None,
+            conflict_target: None,
             returning: None,
         }
     }
@@ -119,6 +180,7 @@ impl<'a> From<MultiRowInsert<'a>> for Insert<'a> {
             columns: insert.columns,
             values,
             on_conflict: None,
+            conflict_target: None,
             returning: None,
         }
     }
@@ -182,6 +244,70 @@ impl<'a> Insert<'a> {
         }
     }
 
+    /// Creates a new multi row `INSERT` statement from column-oriented data,
+    /// where every column holds the values of all rows. The columns are
+    /// written in the order of iteration and the parameters row by row.
+    ///
+    /// Returns an error if the columns differ in length.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let columns = vec![("id", vec![1, 2]), ("age", vec![10, 20])];
+    /// let query = Insert::columnar_into("users", columns)?;
+    /// let (sql, params) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("INSERT INTO `users` (`id`, `age`) VALUES (?,?), (?,?)", sql);
+    ///
+    /// assert_eq!(
+    ///     vec![Value::from(1), Value::from(10), Value::from(2), Value::from(20)],
+    ///     params
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn columnar_into<T, K, V, I>(table: T, columns: I) -> crate::Result<MultiRowInsert<'a>>
+    where
+        T: Into<Table<'a>>,
+        K: Into<Column<'a>>,
+        V: Into<Expression<'a>>,
+        I: IntoIterator<Item = (K, Vec<V>)>,
+    {
+        let mut names = Vec::new();
+        let mut data = Vec::new();
+
+        for (name, values) in columns {
+            names.push(name.into());
+            data.push(values.into_iter());
+        }
+
+        let row_count = data.first().map(|values| values.len()).unwrap_or(0);
+
+        if let Some(position) = data.iter().position(|values| values.len() != row_count) {
+            let msg = format!(
+                "Column `{}` has {} values, expected {}.",
+                names[position].name,
+                data[position].len(),
+                row_count
+            );
+
+            return Err(Error::builder(ErrorKind::conversion(msg)).build());
+        }
+
+        let mut insert = Self::multi_into(table, names);
+
+        for _ in 0..row_count {
+            let row: Vec<Expression<'a>> = data
+                .iter_mut()
+                .filter_map(|values| values.next())
+                .map(Into::into)
+                .collect();
+            insert = insert.values(row);
+        }
+
+        Ok(insert)
+    }
+
     pub fn multi<K, I>(columns: I) -> MultiRowInsert<'a>
     where
         K: Into<Column<'a>>,
@@ -206,17 +332,43 @@ impl<'a> Insert<'a> {
             columns: columns.into_iter().map(|c| c.into()).collect(),
             values: expression.into(),
             on_conflict: None,
+            conflict_target: None,
             returning: None,
         }
     }
 
+    /// Checks the statement sets every required column of the schema,
+    /// returning a `MissingRequiredColumn` error for the first missing one.
+    pub fn validate_against(&self, schema: &TableSchema<'_>) -> crate::Result<()> {
+        for required in schema.required_columns() {
+            if !self.columns.iter().any(|c| c.name == required) {
+                let kind = ErrorKind::MissingRequiredColumn {
+                    column: required.to_string(),
+                };
+
+                return Err(Error::builder(kind).build());
+            }
+        }
+
+        Ok(())
+    }
+
     /// Sets the conflict resolution strategy.
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
         self.on_conflict = Some(on_conflict);
         self
     }
 
-    /// Sets the returned columns.
+    /// Sets the unique index the conflict resolution applies to. Only
+    /// supported on PostgreSQL with an `on_conflict` strategy, other
+    /// inserts with a conflict target fail to build.
+    pub fn conflict_target(mut self, target: ConflictTarget<'a>) -> Self {
+        self.conflict_target = Some(target);
+        self
+    }
+
+    /// Sets the returned columns. On PostgreSQL an aliased column is
+    /// returned with the alias as its name.
     ///
     /// ```rust
     /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
@@ -306,4 +458,74 @@ impl<'a> MultiRowInsert<'a> {
     pub fn build(self) -> Insert<'a> {
         Insert::from(self)
     }
+
+    /// Splits the rows into inserts with at most `max_params` parameters
+    /// each, for inserts too large for the parameter limit of the database,
+    /// such as
+    /// [`C_MAX_PARAMETERS`](../visitor/trait.Visitor.html#associatedconstant.C_MAX_PARAMETERS).
+    /// The rows keep their order. Fails with `TooManyParameters` if a single
+    /// row has more parameters than the limit.
+    ///
+    /// The inserts are separate statements: run them in a transaction if
+    /// all the rows must be inserted or none.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let insert = Insert::multi_into("cats", vec!["name", "age"])
+    ///     .values(vec![Value::from("Musti"), Value::from(5)])
+    ///     .values(vec![Value::from("Naukio"), Value::from(7)])
+    ///     .values(vec![Value::from("Belka"), Value::from(2)]);
+    ///
+    /// let batches = insert.into_batches(4)?;
+    /// assert_eq!(2, batches.len());
+    ///
+    /// let (sql, _) = Sqlite::build(batches[1].clone())?;
+    /// assert_eq!("INSERT INTO `cats` (`name`, `age`) VALUES (?,?)", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_batches(self, max_params: usize) -> crate::Result<Vec<Insert<'a>>> {
+        let mut batches = Vec::new();
+        let mut rows = Vec::new();
+        let mut params = 0;
+
+        for row in self.values {
+            let row_params = ParameterCollector::count_in_row(row.clone())?;
+
+            if row_params > max_params {
+                let kind = ErrorKind::TooManyParameters {
+                    limit: max_params,
+                    got: row_params,
+                };
+
+                return Err(Error::builder(kind).build());
+            }
+
+            if params + row_params > max_params {
+                batches.push(std::mem::take(&mut rows));
+                params = 0;
+            }
+
+            rows.push(row);
+            params += row_params;
+        }
+
+        if !rows.is_empty() {
+            batches.push(rows);
+        }
+
+        let inserts = batches
+            .into_iter()
+            .map(|values| {
+                Insert::from(MultiRowInsert {
+                    table: self.table.clone(),
+                    columns: self.columns.clone(),
+                    values,
+                })
+            })
+            .collect();
+
+        Ok(inserts)
+    }
 }
@@ -5,6 +5,7 @@ use crate::ast::{ConditionTree, Table};
 pub struct JoinData<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) conditions: ConditionTree<'a>,
+    pub(crate) lateral: bool,
 }
 
 impl<'a> JoinData<'a> {
@@ -13,8 +14,37 @@ impl<'a> JoinData<'a> {
         Self {
             table: table.into(),
             conditions: ConditionTree::NoCondition,
+            lateral: false,
         }
     }
+
+    /// Makes the join `LATERAL`, letting a subquery refer to the columns of
+    /// the tables before it. Supported on PostgreSQL and MySQL 8.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let top_posts = Select::from_table("posts")
+    ///     .column("title")
+    ///     .so_that(("posts", "user_id").equals(Column::from(("users", "id"))))
+    ///     .limit(3);
+    ///
+    /// let join = JoinData::all_from(Table::from(top_posts).alias("p")).lateral();
+    /// let query = Select::from_table("users").left_join(join);
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT \"users\".* FROM \"users\" LEFT JOIN LATERAL (SELECT \"title\" FROM \"posts\" \
+    ///      WHERE \"posts\".\"user_id\" = \"users\".\"id\" LIMIT $1) AS \"p\" ON 1=1",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lateral(mut self) -> Self {
+        self.lateral = true;
+        self
+    }
 }
 
 impl<'a, T> From<T> for JoinData<'a>
@@ -73,6 +103,7 @@ where
         JoinData {
             table: self.into(),
             conditions: conditions.into(),
+            lateral: false,
         }
     }
 }
@@ -90,6 +121,7 @@ impl<'a> Joinable<'a> for JoinData<'a> {
         JoinData {
             table: self.table,
             conditions,
+            lateral: self.lateral,
         }
     }
 }
@@ -0,0 +1,61 @@
+/// The strength of a row-level lock taken in a `SELECT` statement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockType {
+    /// `FOR UPDATE`, an exclusive lock.
+    Update,
+    /// `FOR SHARE`, a shared lock.
+    Share,
+}
+
+/// What to do when a row to be locked is already locked by another
+/// transaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockWait {
+    /// Wait until the lock is released (the default).
+    Wait,
+    /// `NOWAIT`, return an error immediately.
+    NoWait,
+    /// `SKIP LOCKED`, leave the locked rows out from the result.
+    SkipLocked,
+}
+
+/// A row-level locking clause for a `SELECT` statement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lock {
+    pub(crate) typ: LockType,
+    pub(crate) wait: LockWait,
+}
+
+impl Lock {
+    /// An exclusive `FOR UPDATE` lock.
+    pub fn update() -> Self {
+        Self {
+            typ: LockType::Update,
+            wait: LockWait::Wait,
+        }
+    }
+
+    /// A shared `FOR SHARE` lock.
+    pub fn share() -> Self {
+        Self {
+            typ: LockType::Share,
+            wait: LockWait::Wait,
+        }
+    }
+
+    /// Sets the behaviour when the rows are already locked.
+    pub fn wait(mut self, wait: LockWait) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    /// The strength of the lock.
+    pub fn typ(&self) -> LockType {
+        self.typ
+    }
+
+    /// The behaviour when the rows are already locked.
+    pub fn wait_policy(&self) -> LockWait {
+        self.wait
+    }
+}
@@ -2,20 +2,52 @@ use super::*;
 use crate::error::*;
 use std::convert::TryFrom;
 
-/// A builder for SQL `MERGE` queries.
+/// A builder for SQL `MERGE` queries, the idiomatic upsert of SQL Server.
+/// Only supported on SQL Server.
 ///
-/// Not complete and not meant for external use in this state. Made for
-/// compatibility purposes.
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mssql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let source = Select::default()
+///     .value(Expression::from(1).alias("id"))
+///     .value(Expression::from("Musti").alias("name"));
+///
+/// let using = source
+///     .into_using("src", vec![Column::from("id"), Column::from("name")])
+///     .on(Column::from(("cats", "id")).equals(Column::from(("src", "id"))));
+///
+/// let merge = Merge::new("cats", using)
+///     .when_matched_set("name", Column::from(("src", "name")))
+///     .when_not_matched(
+///         Insert::multi(vec!["id", "name"]).values(vec![Column::from(("src", "id")), Column::from(("src", "name"))]),
+///     );
+///
+/// let (sql, params) = Mssql::build(merge)?;
+///
+/// assert_eq!(
+///     "MERGE INTO [cats] USING (SELECT @P1 AS [id], @P2 AS [name]) AS [src] ([id],[name]) \
+///      ON [cats].[id] = [src].[id] WHEN MATCHED THEN UPDATE SET [name] = [src].[name] \
+///      WHEN NOT MATCHED THEN INSERT ([id],[name]) VALUES ([src].[id],[src].[name]);",
+///     sql
+/// );
+///
+/// assert_eq!(vec![Value::from(1), Value::from("Musti")], params);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct Merge<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) using: Using<'a>,
+    pub(crate) when_matched: Vec<(Column<'a>, Expression<'a>)>,
     pub(crate) when_not_matched: Option<Query<'a>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
 impl<'a> Merge<'a> {
-    pub(crate) fn new<T, U>(table: T, using: U) -> Self
+    /// Creates a new `MERGE` statement into the given table from a source
+    /// created with [`IntoUsing`](trait.IntoUsing.html).
+    pub fn new<T, U>(table: T, using: U) -> Self
     where
         T: Into<Table<'a>>,
         U: Into<Using<'a>>,
@@ -23,12 +55,26 @@ impl<'a> Merge<'a> {
         Self {
             table: table.into(),
             using: using.into(),
+            when_matched: Vec::new(),
             when_not_matched: None,
             returning: None,
         }
     }
 
-    pub(crate) fn when_not_matched<Q>(mut self, query: Q) -> Self
+    /// Updates the column to the given value in the `WHEN MATCHED` branch.
+    /// Can be called multiple times to update more columns.
+    pub fn when_matched_set<K, V>(mut self, column: K, value: V) -> Self
+    where
+        K: Into<Column<'a>>,
+        V: Into<Expression<'a>>,
+    {
+        self.when_matched.push((column.into(), value.into()));
+        self
+    }
+
+    /// The `WHEN NOT MATCHED` branch, typically an `INSERT` without a table
+    /// reading the columns of the source.
+    pub fn when_not_matched<Q>(mut self, query: Q) -> Self
     where
         Q: Into<Query<'a>>,
     {
@@ -52,6 +98,7 @@ impl<'a> From<Merge<'a>> for Query<'a> {
     }
 }
 
+/// The source of a `MERGE` statement.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Using<'a> {
     pub(crate) base_query: Query<'a>,
@@ -61,7 +108,8 @@ pub struct Using<'a> {
 }
 
 impl<'a> Using<'a> {
-    pub(crate) fn on<T>(mut self, conditions: T) -> Self
+    /// The conditions matching the source rows to the target rows.
+    pub fn on<T>(mut self, conditions: T) -> Self
     where
         T: Into<ConditionTree<'a>>,
     {
@@ -70,7 +118,9 @@ impl<'a> Using<'a> {
     }
 }
 
-pub(crate) trait IntoUsing<'a> {
+/// Converts a query into the source of a `MERGE` statement.
+pub trait IntoUsing<'a> {
+    /// Names the source rows as `alias`, with the given columns.
     fn into_using(self, alias: &'a str, columns: Vec<Column<'a>>) -> Using<'a>;
 }
 
@@ -1,4 +1,4 @@
-use crate::ast::{Column, Expression};
+use crate::ast::{Column, Expression, IntoRaw, Value};
 
 /// Defines ordering for an `ORDER BY` statement.
 pub type OrderDefinition<'a> = (Expression<'a>, Option<Order>);
@@ -23,6 +23,38 @@ impl<'a> Ordering<'a> {
     }
 }
 
+/// Orders by the position of a selected column, starting from one. Rendered
+/// as a plain number, such as `ORDER BY 2`.
+///
+/// To order by an alias of a selected expression, pass the alias as a bare
+/// column name: `order_by("total")`.
+///
+/// SQL Server does not accept positions in the `ORDER BY` of a window
+/// function, or when the query has a `UNION`, and the database returns an
+/// error in those cases.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Select::from_table("orders")
+///     .column("user_id")
+///     .value(sum(Column::from("price")).alias("total"))
+///     .group_by("user_id")
+///     .order_by(ordinal(2).descend());
+///
+/// let (sql, _) = Postgres::build(query)?;
+///
+/// assert_eq!(
+///     "SELECT \"user_id\", SUM(\"price\") AS \"total\" FROM \"orders\" GROUP BY \"user_id\" ORDER BY 2 DESC",
+///     sql
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn ordinal(position: usize) -> Expression<'static> {
+    Value::from(position).raw().into()
+}
+
 /// The ordering direction
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Order {
@@ -69,6 +101,12 @@ impl<'a> IntoOrderDefinition<'a> for Column<'a> {
     }
 }
 
+impl<'a> IntoOrderDefinition<'a> for Expression<'a> {
+    fn into_order_definition(self) -> OrderDefinition<'a> {
+        (self, None)
+    }
+}
+
 impl<'a> IntoOrderDefinition<'a> for OrderDefinition<'a> {
     fn into_order_definition(self) -> OrderDefinition<'a> {
         self
@@ -81,6 +119,12 @@ impl<'a> Orderable<'a> for Column<'a> {
     }
 }
 
+impl<'a> Orderable<'a> for Expression<'a> {
+    fn order(self, order: Option<Order>) -> OrderDefinition<'a> {
+        (self, order)
+    }
+}
+
 impl<'a> Orderable<'a> for &'a str {
     fn order(self, order: Option<Order>) -> OrderDefinition<'a> {
         let column: Column<'a> = self.into();
@@ -1,4 +1,4 @@
-use crate::ast::{Column, Ordering};
+use crate::ast::{Column, IntoOrderDefinition, Ordering};
 
 #[derive(Debug, Default, Clone, PartialEq)]
 /// Determines the partitioning and ordering of a rowset before the associated
@@ -6,10 +6,79 @@ use crate::ast::{Column, Ordering};
 pub struct Over<'a> {
     pub(crate) ordering: Ordering<'a>,
     pub(crate) partitioning: Vec<Column<'a>>,
+    pub(crate) frame: Option<WindowFrame>,
+}
+
+/// The unit of a window frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameUnits {
+    /// The frame is counted in rows.
+    Rows,
+    /// The frame is counted in the values of the ordering column.
+    Range,
+}
+
+/// The start or the end of a window frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBound {
+    /// `UNBOUNDED PRECEDING`, the first row of the partition.
+    UnboundedPreceding,
+    /// `n PRECEDING`
+    Preceding(u32),
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `n FOLLOWING`
+    Following(u32),
+    /// `UNBOUNDED FOLLOWING`, the last row of the partition.
+    UnboundedFollowing,
+}
+
+/// A window frame, such as `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowFrame {
+    pub(crate) units: FrameUnits,
+    pub(crate) start: FrameBound,
+    pub(crate) end: FrameBound,
 }
 
 impl<'a> Over<'a> {
     pub fn is_empty(&self) -> bool {
-        self.ordering.is_empty() && self.partitioning.is_empty()
+        self.ordering.is_empty() && self.partitioning.is_empty() && self.frame.is_none()
+    }
+
+    /// Defines the ordering of the rows in the window.
+    pub fn order_by<T>(mut self, value: T) -> Self
+    where
+        T: IntoOrderDefinition<'a>,
+    {
+        self.ordering = self.ordering.append(value.into_order_definition());
+        self
+    }
+
+    /// Defines the partitioning of the rows in the window.
+    pub fn partition_by<T>(mut self, partition: T) -> Self
+    where
+        T: Into<Column<'a>>,
+    {
+        self.partitioning.push(partition.into());
+        self
+    }
+
+    /// Limits the window to a frame of rows around the current row, such as
+    /// `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`.
+    pub fn rows_between(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(FrameUnits::Rows, start, end)
+    }
+
+    /// Limits the window to a frame of rows with ordering values within the
+    /// given distance of the current row, such as `RANGE BETWEEN 10
+    /// PRECEDING AND 10 FOLLOWING`.
+    pub fn range_between(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(FrameUnits::Range, start, end)
+    }
+
+    fn frame(mut self, units: FrameUnits, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(WindowFrame { units, start, end });
+        self
     }
 }
@@ -1,4 +1,10 @@
-use crate::ast::{Delete, Insert, Merge, Select, Union, Update};
+use crate::{
+    ast::{
+        CreateIndex, CreateTable, CreateTableAs, Delete, DropIndex, DropTable, Insert, Merge, Replace, Select, Union,
+        Update, Value,
+    },
+    visitor::{ParameterCollector, Visitor},
+};
 use std::borrow::Cow;
 
 use super::IntoCommonTableExpression;
@@ -12,6 +18,12 @@ pub enum Query<'a> {
     Delete(Box<Delete<'a>>),
     Union(Box<Union<'a>>),
     Merge(Box<Merge<'a>>),
+    Replace(Box<Replace<'a>>),
+    CreateTable(Box<CreateTable<'a>>),
+    CreateTableAs(Box<CreateTableAs<'a>>),
+    CreateIndex(Box<CreateIndex<'a>>),
+    DropTable(Box<DropTable<'a>>),
+    DropIndex(Box<DropIndex<'a>>),
     Raw(Cow<'a, str>),
 }
 
@@ -44,6 +56,34 @@ impl<'a> Query<'a> {
     pub fn is_union(&self) -> bool {
         matches!(self, Query::Union(_))
     }
+
+    /// The parameters the query binds, in the order they appear in the query
+    /// without generating the SQL.
+    ///
+    /// The order matches all the visitors, but the values might differ when
+    /// the visitor rewrites the query. For example, the PostgreSQL visitor
+    /// binds the values of `in_array` as one array, and the SQLite visitor
+    /// adds a limit of `-1` to a query having only an offset.
+    ///
+    /// ```rust
+    /// # use quaint::ast::*;
+    /// let query: Query = Select::from_table("cats")
+    ///     .so_that("name".equals("Musti").and("age".greater_than(3)))
+    ///     .limit(10)
+    ///     .into();
+    ///
+    /// assert_eq!(
+    ///     vec![Value::from("Musti"), Value::from(3), Value::from(10)],
+    ///     query.parameters(),
+    /// );
+    /// ```
+    pub fn parameters(&self) -> Vec<Value<'a>> {
+        // The collector overrides every visit that could fail.
+        let (_, parameters) =
+            ParameterCollector::build(self.clone()).expect("Collecting the parameters of a query cannot fail.");
+
+        parameters
+    }
 }
 
 /// A database query that only returns data without modifying anything.
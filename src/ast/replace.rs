@@ -0,0 +1,38 @@
+use crate::ast::*;
+
+/// A builder for a `REPLACE` statement, inserting the rows and deleting the
+/// existing rows with the same primary or unique key first. Supported on
+/// MySQL and SQLite.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Replace::new(Insert::single_into("users").value("id", 1).value("name", "Musti"));
+/// let (sql, params) = Mysql::build(query)?;
+///
+/// assert_eq!("REPLACE INTO `users` (`id`,`name`) VALUES (?,?)", sql);
+/// assert_eq!(vec![Value::from(1), Value::from("Musti")], params);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replace<'a> {
+    pub(crate) insert: Insert<'a>,
+}
+
+impl<'a> From<Replace<'a>> for Query<'a> {
+    fn from(replace: Replace<'a>) -> Self {
+        Query::Replace(Box::new(replace))
+    }
+}
+
+impl<'a> Replace<'a> {
+    /// A `REPLACE` of the table, columns and values of the given insert. The
+    /// conflict resolution and `RETURNING` of the insert are ignored.
+    pub fn new<I>(insert: I) -> Self
+    where
+        I: Into<Insert<'a>>,
+    {
+        Self { insert: insert.into() }
+    }
+}
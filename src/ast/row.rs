@@ -1,4 +1,4 @@
-use crate::ast::{Comparable, Compare, Expression};
+use crate::ast::{Comparable, Compare, Expression, Quantifier, Values};
 use std::borrow::Cow;
 
 /// A collection of values surrounded by parentheses.
@@ -37,6 +37,47 @@ impl<'a> Row<'a> {
         self.values.len()
     }
 
+    /// Tests if the row matches any of the tuples. The parameters are
+    /// written tuple by tuple.
+    ///
+    /// PostgreSQL and MySQL compare the row values natively, SQLite against
+    /// a `VALUES` list and SQL Server expands the comparison to `OR`
+    /// conditions. Row values need SQLite 3.15 or later.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let cond = Row::from((Column::from("a"), Column::from("b"))).in_tuples(vec![(1, 2), (3, 4)]);
+    /// let (sql, params) = Mysql::build(Select::from_table("foo").so_that(cond))?;
+    ///
+    /// assert_eq!("SELECT `foo`.* FROM `foo` WHERE (`a`,`b`) IN ((?,?),(?,?))", sql);
+    ///
+    /// assert_eq!(
+    ///     vec![Value::from(1), Value::from(2), Value::from(3), Value::from(4)],
+    ///     params
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn in_tuples<I, T>(self, tuples: I) -> Compare<'a>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Row<'a>>,
+    {
+        let values = Values::new(tuples.into_iter().map(Into::into).collect());
+        self.in_selection(values)
+    }
+
+    /// Tests if the row matches none of the tuples.
+    pub fn not_in_tuples<I, T>(self, tuples: I) -> Compare<'a>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Row<'a>>,
+    {
+        let values = Values::new(tuples.into_iter().map(Into::into).collect());
+        self.not_in_selection(values)
+    }
+
     #[cfg(feature = "mssql")]
     pub(crate) fn is_only_columns(&self) -> bool {
         self.values.iter().all(|v| v.is_column())
@@ -226,6 +267,15 @@ impl<'a> Comparable<'a> for Row<'a> {
         value.not_in_selection(selection)
     }
 
+    fn in_array<T, V>(self, values: T) -> Compare<'a>
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<Value<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.in_array(values)
+    }
+
     fn like<T>(self, pattern: T) -> Compare<'a>
     where
         T: Into<Cow<'a, str>>,
@@ -310,4 +360,12 @@ impl<'a> Comparable<'a> for Row<'a> {
         let value: Expression<'a> = self.into();
         value.compare_raw(raw_comparator, right)
     }
+
+    fn compare_quantified<T>(self, comparator: &'static str, quantifier: Quantifier, selection: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let value: Expression<'a> = self.into();
+        value.compare_quantified(comparator, quantifier, selection)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::ast::*;
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+};
+use std::borrow::Cow;
 
 /// A builder for a `SELECT` statement.
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -9,11 +13,16 @@ pub struct Select<'a> {
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) ordering: Ordering<'a>,
     pub(crate) grouping: Grouping<'a>,
+    pub(crate) grouping_set: Option<GroupingSet<'a>>,
     pub(crate) having: Option<ConditionTree<'a>>,
     pub(crate) limit: Option<Value<'a>>,
     pub(crate) offset: Option<Value<'a>>,
     pub(crate) joins: Vec<Join<'a>>,
     pub(crate) ctes: Vec<CommonTableExpression<'a>>,
+    pub(crate) lock: Option<Lock>,
+    pub(crate) optimizer_hints: Vec<Cow<'a, str>>,
+    pub(crate) fetch_first: bool,
+    pub(crate) positional_aliases: bool,
 }
 
 impl<'a> From<Select<'a>> for Expression<'a> {
@@ -221,6 +230,33 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// A bulk method to add multiple expressions to the selection, such as
+    /// columns, functions or arithmetic, after the ones already selected.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").column("name").values(vec![
+    ///     Expression::from(count(asterisk()).alias("total")),
+    ///     Expression::from(Column::from("age")) + Expression::from(1),
+    /// ]);
+    ///
+    /// let (sql, params) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("SELECT `name`, COUNT(*) AS `total`, (`age` + ?) FROM `users`", sql);
+    /// assert_eq!(vec![Value::from(1)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn values<T, E>(mut self, values: T) -> Self
+    where
+        T: IntoIterator<Item = E>,
+        E: Into<Expression<'a>>,
+    {
+        self.columns.extend(values.into_iter().map(Into::into));
+        self
+    }
+
     /// Adds `DISTINCT` to the select query.
     ///
     /// ```rust
@@ -299,6 +335,34 @@ impl<'a> Select<'a> {
         }
     }
 
+    /// Adds an equality condition for every column and value pair, combining
+    /// them and the possible previous condition with `AND`.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").where_equals(vec![("name", Value::from("Joe")), ("age", Value::from(27))]);
+    /// let (sql, params) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE (`name` = ? AND `age` = ?)", sql);
+    /// assert_eq!(vec![Value::from("Joe"), Value::from(27)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn where_equals<I, K, V>(self, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<Column<'a>>,
+        V: Into<Expression<'a>>,
+    {
+        let conditions = ConditionTree::all_of(pairs.into_iter().map(|(column, value)| column.equals(value)));
+
+        match conditions {
+            ConditionTree::NoCondition => self,
+            conditions => self.and_where(conditions),
+        }
+    }
+
     /// Adds an additional `WHERE` condition to the query combining the possible
     /// previous condition with `OR`. See
     /// [Comparable](trait.Comparable.html#required-methods) for more examples.
@@ -474,6 +538,41 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Checks that every `ORDER BY` expression of a `DISTINCT` query is in
+    /// the selected columns, as PostgreSQL requires, returning an
+    /// `InvalidQuery` error for the first one that is not. An expression
+    /// matches a selected column with the same name, or the alias of a
+    /// selected expression. Queries selecting all columns always pass.
+    ///
+    /// ```rust
+    /// # use quaint::ast::*;
+    /// let query = Select::from_table("users").column("name").distinct();
+    ///
+    /// assert!(query.clone().order_by("name").validate_distinct_ordering().is_ok());
+    /// assert!(query.order_by("age").validate_distinct_ordering().is_err());
+    /// ```
+    pub fn validate_distinct_ordering(&self) -> crate::Result<()> {
+        if !self.distinct || self.columns.is_empty() || self.columns.iter().any(|c| c.is_asterisk()) {
+            return Ok(());
+        }
+
+        for (expr, _) in self.ordering.0.iter() {
+            if !self.columns.iter().any(|selected| is_selected(selected, expr)) {
+                let msg = match expr.kind {
+                    ExpressionKind::Column(ref column) => format!(
+                        "The ORDER BY column `{}` must be in the select list of a SELECT DISTINCT query.",
+                        column.name
+                    ),
+                    _ => String::from("ORDER BY expressions must be in the select list of a SELECT DISTINCT query."),
+                };
+
+                return Err(Error::builder(ErrorKind::InvalidQuery(msg.into())).build());
+            }
+        }
+
+        Ok(())
+    }
+
     /// Adds a grouping to the `GROUP BY` section.
     ///
     /// This does not check if the grouping is actually valid in respect to aggregated columns.
@@ -498,6 +597,87 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Groups by the columns with `ROLLUP`, adding subtotal rows for every
+    /// prefix of the columns and a grand total row.
+    ///
+    /// On MySQL the query is written with `WITH ROLLUP`, which also rolls up
+    /// the columns added with [group_by](#method.group_by). Not supported on
+    /// SQLite.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("sales")
+    ///     .column("region")
+    ///     .column("city")
+    ///     .value(sum(Column::from("amount")))
+    ///     .group_by_rollup(vec!["region", "city"]);
+    ///
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT \"region\", \"city\", SUM(\"amount\") FROM \"sales\" GROUP BY ROLLUP (\"region\", \"city\")",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn group_by_rollup<I, T>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoGroupByDefinition<'a>,
+    {
+        let values = values.into_iter().map(|v| v.into_group_by_definition()).collect();
+        self.grouping_set = Some(GroupingSet::Rollup(values));
+        self
+    }
+
+    /// Groups by the columns with `CUBE`, adding subtotal rows for every
+    /// combination of the columns. Not supported on MySQL and SQLite.
+    pub fn group_by_cube<I, T>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoGroupByDefinition<'a>,
+    {
+        let values = values.into_iter().map(|v| v.into_group_by_definition()).collect();
+        self.grouping_set = Some(GroupingSet::Cube(values));
+        self
+    }
+
+    /// Groups by each of the sets with `GROUPING SETS`. An empty set is a
+    /// grand total. Not supported on MySQL and SQLite.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("sales")
+    ///     .value(sum(Column::from("amount")))
+    ///     .grouping_sets(vec![vec!["region", "city"], vec!["region"], vec![]]);
+    ///
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT SUM(\"amount\") FROM \"sales\" GROUP BY GROUPING SETS ((\"region\", \"city\"), (\"region\"), ())",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn grouping_sets<I, S, T>(mut self, sets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: IntoIterator<Item = T>,
+        T: IntoGroupByDefinition<'a>,
+    {
+        let sets = sets
+            .into_iter()
+            .map(|set| set.into_iter().map(|v| v.into_group_by_definition()).collect())
+            .collect();
+
+        self.grouping_set = Some(GroupingSet::Sets(sets));
+        self
+    }
+
     /// Adds group conditions to a query. Should be combined together with a
     /// [group_by](struct.Select.html#method.group_by) statement.
     ///
@@ -556,6 +736,223 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Writes the limit and offset in the SQL standard `OFFSET ... ROWS FETCH
+    /// NEXT ... ROWS ONLY` form instead of `LIMIT`. SQL Server always uses
+    /// the standard form, MySQL and SQLite don't support it.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").limit(10).offset(20).fetch_first();
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("SELECT \"users\".* FROM \"users\" OFFSET $1 ROWS FETCH NEXT $2 ROWS ONLY", sql);
+    /// assert_eq!(vec![Value::from(20), Value::from(10)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_first(mut self) -> Self {
+        self.fetch_first = true;
+        self
+    }
+
+    /// Names the selected expressions without an alias by their position in
+    /// the select list, `_col0`, `_col1` and so on, so the result columns
+    /// have the same names on every database. Columns keep their own names.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("cats")
+    ///     .column("name")
+    ///     .value(count(asterisk()))
+    ///     .group_by("name")
+    ///     .positional_aliases();
+    ///
+    /// let (sql, _) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("SELECT `name`, COUNT(*) AS `_col1` FROM `cats` GROUP BY `name`", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn positional_aliases(mut self) -> Self {
+        self.positional_aliases = true;
+        self
+    }
+
+    /// Locks the selected rows with an exclusive `FOR UPDATE` lock.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("jobs").limit(1).for_update();
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("SELECT \"jobs\".* FROM \"jobs\" LIMIT $1 FOR UPDATE", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_update(mut self) -> Self {
+        let wait = self.lock.map(|l| l.wait).unwrap_or(LockWait::Wait);
+        self.lock = Some(Lock::update().wait(wait));
+        self
+    }
+
+    /// Locks the selected rows with a shared `FOR SHARE` lock.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("jobs").for_share();
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("SELECT \"jobs\".* FROM \"jobs\" FOR SHARE", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_share(mut self) -> Self {
+        let wait = self.lock.map(|l| l.wait).unwrap_or(LockWait::Wait);
+        self.lock = Some(Lock::share().wait(wait));
+        self
+    }
+
+    /// Skips the rows already locked by other transactions. Implies
+    /// `FOR UPDATE`, if no lock was set before.
+    ///
+    /// Not supported on SQLite and SQL Server, and requires MySQL 8.0 or
+    /// later.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("jobs").limit(1).skip_locked();
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("SELECT \"jobs\".* FROM \"jobs\" LIMIT $1 FOR UPDATE SKIP LOCKED", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn skip_locked(mut self) -> Self {
+        self.lock = Some(self.lock.unwrap_or_else(Lock::update).wait(LockWait::SkipLocked));
+        self
+    }
+
+    /// Fails immediately, if any of the selected rows is already locked by
+    /// other transactions. Implies `FOR UPDATE`, if no lock was set before.
+    ///
+    /// Not supported on SQLite and SQL Server, and requires MySQL 8.0 or
+    /// later.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("jobs").for_share().nowait();
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!("SELECT \"jobs\".* FROM \"jobs\" FOR SHARE NOWAIT", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn nowait(mut self) -> Self {
+        self.lock = Some(self.lock.unwrap_or_else(Lock::update).wait(LockWait::NoWait));
+        self
+    }
+
+    /// Creates a new table from the results of the query with a `CREATE
+    /// TABLE ... AS SELECT` statement. Not supported on SQL Server.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").so_that("active".equals(true)).into_table("snapshot");
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "CREATE TABLE \"snapshot\" AS SELECT \"users\".* FROM \"users\" WHERE \"active\" = $1",
+    ///     sql
+    /// );
+    /// assert_eq!(vec![Value::from(true)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_table<T>(self, table: T) -> CreateTableAs<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        CreateTableAs {
+            table: table.into(),
+            select: self,
+        }
+    }
+
+    /// Turns the query into a `SELECT COUNT(*)` over the same rows, such as
+    /// the total count for a paginated query. The ordering, limit, offset and
+    /// lock are dropped, and the rest of the query is kept as a subquery with
+    /// its parameters.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users")
+    ///     .so_that("age".greater_than(18))
+    ///     .order_by("name")
+    ///     .limit(10);
+    ///
+    /// let (sql, params) = Sqlite::build(query.into_count())?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT COUNT(*) AS `count` FROM (SELECT `users`.* FROM `users` WHERE `age` > ?) AS `count_query`",
+    ///     sql
+    /// );
+    /// assert_eq!(vec![Value::from(18)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_count(mut self) -> Select<'a> {
+        let ctes = std::mem::take(&mut self.ctes);
+
+        let inner = Select {
+            ordering: Ordering::default(),
+            limit: None,
+            offset: None,
+            lock: None,
+            fetch_first: false,
+            ..self
+        };
+
+        let mut count_query = Select::from_table(Table::from(inner).alias("count_query"));
+        count_query.columns.push(count(asterisk()).alias("count").into());
+        count_query.ctes = ctes;
+
+        count_query
+    }
+
+    /// Adds a MySQL optimizer hint, written in a `/*+ ... */` comment after
+    /// the `SELECT` keyword. Other databases ignore the hints.
+    ///
+    /// Comment delimiters are removed from the hint, the same way as from the
+    /// comments of [`Visitor::build_with_comment`].
+    ///
+    /// [`Visitor::build_with_comment`]: ../visitor/trait.Visitor.html#method.build_with_comment
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users").optimizer_hint("MAX_EXECUTION_TIME(1000)");
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("SELECT /*+ MAX_EXECUTION_TIME(1000) */ `users`.* FROM `users`", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn optimizer_hint<H>(mut self, hint: H) -> Self
+    where
+        H: Into<Cow<'a, str>>,
+    {
+        self.optimizer_hints.push(hint.into());
+        self
+    }
+
     /// Adds a common table expression to the select.
     ///
     /// ```rust
@@ -589,6 +986,54 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Adds a recursive common table expression to the select, combining the
+    /// anchor and the recursive select with `UNION ALL`. The recursive select
+    /// refers to the rows found so far with the name of the expression, e.g.
+    /// for walking a tree. The parameters of the anchor come before the ones
+    /// of the recursive select.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let anchor = Select::from_table("categories").column("id").so_that("id".equals(1));
+    ///
+    /// let recursive = Select::from_table("categories")
+    ///     .column(("categories", "id"))
+    ///     .inner_join("tree".on(("categories", "parent_id").equals(Column::from(("tree", "id")))));
+    ///
+    /// let query = Select::from_table("tree")
+    ///     .column("id")
+    ///     .with_recursive("tree", vec!["id"], anchor, recursive);
+    ///
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "WITH RECURSIVE \"tree\" (\"id\") AS (SELECT \"id\" FROM \"categories\" WHERE \"id\" = $1 \
+    ///      UNION ALL SELECT \"categories\".\"id\" FROM \"categories\" \
+    ///      INNER JOIN \"tree\" ON \"categories\".\"parent_id\" = \"tree\".\"id\") \
+    ///      SELECT \"id\" FROM \"tree\"",
+    ///     sql
+    /// );
+    /// assert_eq!(vec![Value::from(1)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_recursive<N, I, C>(mut self, name: N, columns: I, anchor: Select<'a>, recursive: Select<'a>) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'a, str>>,
+    {
+        self.ctes.push(CommonTableExpression {
+            identifier: name.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            selection: Union::new(anchor).all(recursive).into(),
+            recursive: true,
+        });
+
+        self
+    }
+
     /// Traverse the condition tree, looking for a comparison where the left
     /// side is a tuple and the right side a nested `SELECT` in an `IN` or `NOT
     /// IN` operation; converting it to a common table expression.
@@ -675,3 +1120,63 @@ impl<'a> Select<'a> {
 }
 
 impl<'a> IntoCommonTableExpression<'a> for Select<'a> {}
+
+/// True if ordering by the expression is allowed when the column is selected
+/// in a `DISTINCT` query.
+fn is_selected(selected: &Expression<'_>, ordered: &Expression<'_>) -> bool {
+    match (&selected.kind, &ordered.kind) {
+        (ExpressionKind::Column(selected_col), ExpressionKind::Column(ordered_col)) => {
+            let alias = selected.alias.as_ref().or_else(|| selected_col.alias.as_ref());
+
+            let same_column = selected_col.name == ordered_col.name
+                && (selected_col.table.is_none()
+                    || ordered_col.table.is_none()
+                    || selected_col.table == ordered_col.table);
+
+            let by_alias = ordered_col.table.is_none() && alias == Some(&ordered_col.name);
+
+            same_column || by_alias
+        }
+        (_, ExpressionKind::Column(ordered_col)) if ordered_col.table.is_none() => {
+            selected.alias.as_ref() == Some(&ordered_col.name)
+        }
+        (selected_kind, ordered_kind) => selected_kind == ordered_kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinct_ordering_by_an_unselected_column_fails_validation() {
+        let query = Select::from_table("users")
+            .column("name")
+            .distinct()
+            .order_by("name")
+            .order_by("age".descend());
+
+        let err = query.validate_distinct_ordering().unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::InvalidQuery(_)));
+        assert!(err.to_string().contains("`age`"));
+    }
+
+    #[test]
+    fn distinct_ordering_by_selected_columns_passes_validation() {
+        let query = Select::from_table("users")
+            .column(("users", "name"))
+            .value(Column::from("age").alias("years"))
+            .distinct()
+            .order_by("name")
+            .order_by("years".descend());
+
+        assert!(query.validate_distinct_ordering().is_ok());
+    }
+
+    #[test]
+    fn ordering_without_distinct_is_not_validated() {
+        let query = Select::from_table("users").column("name").order_by("age");
+        assert!(query.validate_distinct_ordering().is_ok());
+    }
+}
@@ -31,6 +31,20 @@ pub struct Table<'a> {
     pub alias: Option<Cow<'a, str>>,
     pub database: Option<Cow<'a, str>>,
     pub(crate) index_definitions: Vec<IndexDefinition<'a>>,
+    pub(crate) index_hints: Vec<IndexHint<'a>>,
+    pub(crate) partitions: Vec<Cow<'a, str>>,
+}
+
+/// An index hint for the MySQL query planner, written after the table name.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IndexHint<'a> {
+    /// `USE INDEX (name)`, considering only the given index.
+    Use(Cow<'a, str>),
+    /// `FORCE INDEX (name)`, like `USE INDEX`, but a table scan is used only
+    /// if the index can't be used.
+    Force(Cow<'a, str>),
+    /// `IGNORE INDEX (name)`, never considering the given index.
+    Ignore(Cow<'a, str>),
 }
 
 impl<'a> PartialEq for Table<'a> {
@@ -40,6 +54,54 @@ impl<'a> PartialEq for Table<'a> {
 }
 
 impl<'a> Table<'a> {
+    /// A table with the given name, rejecting names with control characters
+    /// or identifier quotes with an `InvalidIdentifier` error. Use with
+    /// names built from untrusted input.
+    ///
+    /// ```rust
+    /// # use quaint::ast::*;
+    /// assert!(Table::checked("users").is_ok());
+    /// assert!(Table::checked("users\0").is_err());
+    /// ```
+    pub fn checked<S>(name: S) -> crate::Result<Self>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let name = name.into();
+        super::validate_identifier(&name)?;
+
+        Ok(Table {
+            typ: TableType::Table(name),
+            alias: None,
+            database: None,
+            index_definitions: Vec::new(),
+            index_hints: Vec::new(),
+            partitions: Vec::new(),
+        })
+    }
+
+    /// A table with a name chosen at runtime, such as a per-tenant table.
+    /// The same as [`checked`](#method.checked): the name is validated as
+    /// an identifier and quoted like any other table.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let tenant = "acme";
+    /// let table = Table::dynamic(format!("{}_orders", tenant))?;
+    /// let (sql, _) = Postgres::build(Select::from_table(table))?;
+    ///
+    /// assert_eq!("SELECT \"acme_orders\".* FROM \"acme_orders\"", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dynamic<S>(name: S) -> crate::Result<Self>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::checked(name)
+    }
+
     /// Define in which database the table is located
     pub fn database<T>(mut self, database: T) -> Self
     where
@@ -49,7 +111,28 @@ impl<'a> Table<'a> {
         self
     }
 
-    /// A qualified asterisk to this table
+    /// A qualified asterisk to this table, selecting all of its columns.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let join = "orders".on(("orders", "user_id").equals(Column::from(("users", "id"))));
+    ///
+    /// let query = Select::from_table("users")
+    ///     .value(Table::from("users").asterisk())
+    ///     .column(("orders", "total"))
+    ///     .inner_join(join);
+    ///
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "SELECT `users`.*, `orders`.`total` FROM `users` \
+    ///      INNER JOIN `orders` ON `orders`.`user_id` = `users`.`id`",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn asterisk(self) -> Expression<'a> {
         Expression {
             kind: ExpressionKind::Asterisk(Some(Box::new(self))),
@@ -57,6 +140,76 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Adds a `USE INDEX` hint for the MySQL query planner. Other databases
+    /// return an error when building the query.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table(Table::from("users").use_index("idx_name")).so_that("name".equals("Musti"));
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` USE INDEX (`idx_name`) WHERE `name` = ?", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn use_index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Cow<'a, str>>,
+    {
+        self.index_hints.push(IndexHint::Use(index.into()));
+        self
+    }
+
+    /// Adds a `FORCE INDEX` hint for the MySQL query planner. Other databases
+    /// return an error when building the query.
+    pub fn force_index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Cow<'a, str>>,
+    {
+        self.index_hints.push(IndexHint::Force(index.into()));
+        self
+    }
+
+    /// Adds an `IGNORE INDEX` hint for the MySQL query planner. Other
+    /// databases return an error when building the query.
+    pub fn ignore_index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Cow<'a, str>>,
+    {
+        self.index_hints.push(IndexHint::Ignore(index.into()));
+        self
+    }
+
+    /// Limits the query to the given partitions of the table with a MySQL
+    /// `PARTITION` clause. Other databases return an error when building the
+    /// query.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table(Table::from("events").partition(vec!["p2020", "p2021"]));
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("SELECT `events`.* FROM `events` PARTITION (`p2020`, `p2021`)", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partition<I, P>(mut self, partitions: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Cow<'a, str>>,
+    {
+        self.partitions.extend(partitions.into_iter().map(Into::into));
+        self
+    }
+
+    /// The table without its partitions, for qualifying the columns.
+    pub(crate) fn without_partitions(mut self) -> Self {
+        self.partitions.clear();
+        self
+    }
+
     /// Add unique index definition.
     pub fn add_unique_index(mut self, i: impl Into<IndexDefinition<'a>>) -> Self {
         let definition = i.into();
@@ -324,6 +477,8 @@ impl<'a> From<&'a str> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hints: Vec::new(),
+            partitions: Vec::new(),
         }
     }
 }
@@ -335,6 +490,8 @@ impl<'a> From<&'a String> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hints: Vec::new(),
+            partitions: Vec::new(),
         }
     }
 }
@@ -374,6 +531,8 @@ impl<'a> From<String> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hints: Vec::new(),
+            partitions: Vec::new(),
         }
     }
 }
@@ -391,6 +550,8 @@ impl<'a> From<Values<'a>> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hints: Vec::new(),
+            partitions: Vec::new(),
         }
     }
 }
@@ -409,6 +570,8 @@ impl<'a> From<Select<'a>> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hints: Vec::new(),
+            partitions: Vec::new(),
         }
     }
 }
@@ -0,0 +1,135 @@
+use crate::ast::{ColumnConstraint, CreateTable};
+use std::borrow::Cow;
+
+/// A description of the columns of a table, for validating queries before
+/// sending them to the database.
+///
+/// ```rust
+/// # use quaint::ast::*;
+/// let schema = TableSchema::new().required("name").optional("bio");
+/// let insert = Insert::from(Insert::single_into("users").value("bio", "Cat"));
+///
+/// assert!(insert.validate_against(&schema).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableSchema<'a> {
+    pub(crate) columns: Vec<ColumnSchema<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ColumnSchema<'a> {
+    pub(crate) name: Cow<'a, str>,
+    pub(crate) required: bool,
+}
+
+impl<'a> TableSchema<'a> {
+    /// An empty schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A `NOT NULL` column without a default value, which every `INSERT`
+    /// must set.
+    pub fn required<N>(mut self, name: N) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        self.columns.push(ColumnSchema {
+            name: name.into(),
+            required: true,
+        });
+
+        self
+    }
+
+    /// A nullable column or a column with a default value.
+    pub fn optional<N>(mut self, name: N) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        self.columns.push(ColumnSchema {
+            name: name.into(),
+            required: false,
+        });
+
+        self
+    }
+
+    pub(crate) fn required_columns(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().filter(|c| c.required).map(|c| c.name.as_ref())
+    }
+}
+
+/// The `NOT NULL` columns are required, unless they get their value from a
+/// sequence.
+impl<'a> From<&CreateTable<'a>> for TableSchema<'a> {
+    fn from(create: &CreateTable<'a>) -> Self {
+        let columns = create
+            .columns
+            .iter()
+            .map(|definition| {
+                let not_null =
+                    definition.has(ColumnConstraint::NotNull) || definition.has(ColumnConstraint::PrimaryKey);
+
+                ColumnSchema {
+                    name: definition.name.clone(),
+                    required: not_null && !definition.has(ColumnConstraint::AutoIncrement),
+                }
+            })
+            .collect();
+
+        Self { columns }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, error::ErrorKind};
+
+    #[test]
+    fn missing_required_column_fails_validation() {
+        let schema = TableSchema::new().required("name").optional("age");
+        let insert = Insert::from(Insert::single_into("users").value("age", 10));
+
+        let err = insert.validate_against(&schema).unwrap_err();
+
+        match err.kind() {
+            ErrorKind::MissingRequiredColumn { column } => assert_eq!("name", column),
+            e => panic!("Expected `MissingRequiredColumn`, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn required_columns_given_pass_validation() {
+        let schema = TableSchema::new().required("name").optional("age");
+
+        let insert = Insert::from(Insert::single_into("users").value("name", "Musti"));
+        assert!(insert.validate_against(&schema).is_ok());
+
+        let insert = Insert::from(
+            Insert::multi_into("users", vec!["age", "name"])
+                .values(vec![Expression::from(1), Expression::from("Naukio")]),
+        );
+        assert!(insert.validate_against(&schema).is_ok());
+    }
+
+    #[test]
+    fn schema_from_create_table() {
+        let create = CreateTable::new("users")
+            .column(
+                "id",
+                ColumnType::Integer,
+                vec![ColumnConstraint::PrimaryKey, ColumnConstraint::AutoIncrement],
+            )
+            .column("name", ColumnType::Text, vec![ColumnConstraint::NotNull])
+            .column("bio", ColumnType::Text, vec![]);
+
+        let schema = TableSchema::from(&create);
+
+        let insert = Insert::from(Insert::single_into("users").value("bio", "Cat"));
+        assert!(insert.validate_against(&schema).is_err());
+
+        let insert = Insert::from(Insert::single_into("users").value("name", "Musti"));
+        assert!(insert.validate_against(&schema).is_ok());
+    }
+}
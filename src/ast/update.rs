@@ -1,4 +1,5 @@
 use crate::ast::*;
+use std::{collections::HashMap, hash::BuildHasher};
 
 /// A builder for an `UPDATE` statement.
 #[derive(Debug, PartialEq, Clone)]
@@ -7,6 +8,25 @@ pub struct Update<'a> {
     pub(crate) columns: Vec<Column<'a>>,
     pub(crate) values: Vec<Expression<'a>>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
+    pub(crate) returning: Option<Vec<Column<'a>>>,
+    pub(crate) null_mode: UpdateNullMode,
+}
+
+/// Defines how [`Update::set_opt`](struct.Update.html#method.set_opt) handles
+/// `None` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateNullMode {
+    /// `None` sets the column to `NULL`. The default.
+    SetNull,
+    /// `None` leaves the column out of the update, useful for partial
+    /// `PATCH`-style updates.
+    SkipNone,
+}
+
+impl Default for UpdateNullMode {
+    fn default() -> Self {
+        Self::SetNull
+    }
 }
 
 impl<'a> From<Update<'a>> for Query<'a> {
@@ -26,6 +46,8 @@ impl<'a> Update<'a> {
             columns: Vec::new(),
             values: Vec::new(),
             conditions: None,
+            returning: None,
+            null_mode: UpdateNullMode::default(),
         }
     }
 
@@ -49,6 +71,22 @@ impl<'a> Update<'a> {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// The value can be any expression, such as an operation on the column
+    /// itself, incrementing it without reading it first.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let views = Expression::from(Column::from("views")) + Expression::from(1);
+    /// let query = Update::table("posts").set("views", views);
+    /// let (sql, params) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("UPDATE `posts` SET `views` = (`views` + ?)", sql);
+    /// assert_eq!(vec![Value::from(1)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn set<K, V>(mut self, column: K, value: V) -> Update<'a>
     where
         K: Into<Column<'a>>,
@@ -60,6 +98,151 @@ impl<'a> Update<'a> {
         self
     }
 
+    /// Sets how the following [`set_opt`](#method.set_opt) calls handle
+    /// `None` values.
+    pub fn null_mode(mut self, mode: UpdateNullMode) -> Self {
+        self.null_mode = mode;
+        self
+    }
+
+    /// Adds an assignment from an optional value. `None` either sets the
+    /// column to `NULL` or leaves the column out of the update, depending on
+    /// the [`null_mode`](#method.null_mode).
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Sqlite}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let name: Option<&str> = Some("Musti");
+    /// let age: Option<i64> = None;
+    ///
+    /// let query = Update::table("users").set_opt("name", name).set_opt("age", age);
+    /// let (sql, _) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("UPDATE `users` SET `name` = ?, `age` = ?", sql);
+    ///
+    /// let query = Update::table("users")
+    ///     .null_mode(UpdateNullMode::SkipNone)
+    ///     .set_opt("name", name)
+    ///     .set_opt("age", age);
+    ///
+    /// let (sql, _) = Sqlite::build(query)?;
+    ///
+    /// assert_eq!("UPDATE `users` SET `name` = ?", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_opt<K, V>(self, column: K, value: Option<V>) -> Update<'a>
+    where
+        K: Into<Column<'a>>,
+        Option<V>: Into<Value<'a>>,
+    {
+        match (value, self.null_mode) {
+            (None, UpdateNullMode::SkipNone) => self,
+            (value, _) => self.set(column, value.into()),
+        }
+    }
+
+    /// Sets every column of the map to its value, e.g. for applying a patch
+    /// of changed fields. The columns are written in the order of their
+    /// names, so the same map always gives the same query.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # use std::collections::HashMap;
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let mut changes = HashMap::new();
+    /// changes.insert("name", Value::from("Musti"));
+    /// changes.insert("age", Value::from(6));
+    ///
+    /// let query = Update::table("cats").set_all(changes).so_that("id".equals(1));
+    /// let (sql, params) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("UPDATE `cats` SET `age` = ?, `name` = ? WHERE `id` = ?", sql);
+    /// assert_eq!(vec![Value::from(6), Value::from("Musti"), Value::from(1)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_all<K, V, S>(self, values: HashMap<K, V, S>) -> Update<'a>
+    where
+        K: Into<Column<'a>> + Ord,
+        V: Into<Expression<'a>>,
+        S: BuildHasher,
+    {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        values
+            .into_iter()
+            .fold(self, |update, (column, value)| update.set(column, value))
+    }
+
+    /// Sets the column of every row to a different value in one statement,
+    /// choosing the value by the `key` column with a `CASE` expression. Only
+    /// the rows with the given keys are updated, with an `IN` condition
+    /// combined with `AND` to the possible previous conditions.
+    ///
+    /// The parameters follow the order of the given pairs. Without any pairs
+    /// the update is returned unchanged.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Update::table("users").bulk_set("name", "id", vec![(1, "Musti"), (2, "Naukio")]);
+    /// let (sql, params) = Mysql::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "UPDATE `users` SET `name` = CASE `id` WHEN ? THEN ? WHEN ? THEN ? END WHERE `id` IN (?,?)",
+    ///     sql
+    /// );
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         Value::from(1),
+    ///         Value::from("Musti"),
+    ///         Value::from(2),
+    ///         Value::from("Naukio"),
+    ///         Value::from(1),
+    ///         Value::from(2),
+    ///     ],
+    ///     params
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bulk_set<C, K, I, KV, V>(self, column: C, key: K, values: I) -> Update<'a>
+    where
+        C: Into<Column<'a>>,
+        K: Into<Column<'a>>,
+        I: IntoIterator<Item = (KV, V)>,
+        KV: Into<Value<'a>>,
+        V: Into<Expression<'a>>,
+    {
+        let key = key.into();
+        let mut case = Case::on(key.clone());
+        let mut keys: Vec<Value<'a>> = Vec::new();
+
+        for (k, v) in values {
+            let k = k.into();
+
+            keys.push(k.clone());
+            case = case.when(k, v);
+        }
+
+        if keys.is_empty() {
+            return self;
+        }
+
+        let in_keys = key.in_selection(keys);
+        let mut update = self.set(column, case);
+
+        update.conditions = Some(match update.conditions.take() {
+            Some(previous) => previous.and(in_keys),
+            None => in_keys.into(),
+        });
+
+        update
+    }
+
     /// Adds `WHERE` conditions to the query. See
     /// [Comparable](trait.Comparable.html#required-methods) for more examples.
     ///
@@ -113,4 +296,38 @@ impl<'a> Update<'a> {
         self.conditions = Some(conditions.into());
         self
     }
+
+    /// Sets the columns of the updated rows to be returned. Only supported on
+    /// PostgreSQL, other databases return an `UnsupportedFeature` error when
+    /// building the query.
+    ///
+    /// The rows are available when running the query with
+    /// [`Queryable::update_returning`] or [`Queryable::query`].
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Update::table("users").set("foo", 1).so_that("bar".equals(false)).returning(vec!["id", "foo"]);
+    /// let (sql, params) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "UPDATE \"users\" SET \"foo\" = $1 WHERE \"bar\" = $2 RETURNING \"id\", \"foo\"",
+    ///     sql
+    /// );
+    ///
+    /// assert_eq!(vec![Value::from(1), Value::from(false)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Queryable::update_returning`]: ../connector/trait.Queryable.html#method.update_returning
+    /// [`Queryable::query`]: ../connector/trait.Queryable.html#tymethod.query
+    pub fn returning<K, I>(mut self, columns: I) -> Self
+    where
+        K: Into<Column<'a>>,
+        I: IntoIterator<Item = K>,
+    {
+        self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
+        self
+    }
 }
@@ -1,3 +1,7 @@
+mod hash;
+mod literal;
+mod ord;
+
 use crate::ast::*;
 use crate::error::{Error, ErrorKind};
 
@@ -38,7 +42,11 @@ where
 /// A value we must parameterize for the prepared statement. Null values should be
 /// defined by their corresponding type variants with a `None` value for best
 /// compatibility.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Values implement `Eq` and `Hash`, so the parameters of a query can be used
+/// as a cache key. Floats are compared and hashed by their bit pattern: `NaN`
+/// equals itself, and `0.0` is not equal to `-0.0`.
+#[derive(Debug, Clone)]
 pub enum Value<'a> {
     /// 64-bit signed integer.
     Integer(Option<i64>),
@@ -86,6 +94,55 @@ pub enum Value<'a> {
     Time(Option<NaiveTime>),
 }
 
+/// The type of a `Value`, without the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// 64-bit signed integer.
+    Integer,
+    /// 32-bit floating point.
+    Float,
+    /// 64-bit floating point.
+    Double,
+    /// String value.
+    Text,
+    /// Database enum value.
+    Enum,
+    /// Bytes value.
+    Bytes,
+    /// Boolean value.
+    Boolean,
+    /// A single character.
+    Char,
+    /// An array value (PostgreSQL).
+    Array,
+    /// A numeric value.
+    #[cfg(feature = "bigdecimal")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "bigdecimal")))]
+    Numeric,
+    #[cfg(feature = "json")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "json")))]
+    /// A JSON value.
+    Json,
+    /// A XML value.
+    Xml,
+    #[cfg(feature = "uuid")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "uuid")))]
+    /// An UUID value.
+    Uuid,
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "chrono")))]
+    /// A datetime value.
+    DateTime,
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "chrono")))]
+    /// A date value.
+    Date,
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "chrono")))]
+    /// A time value.
+    Time,
+}
+
 pub(crate) struct Params<'a>(pub(crate) &'a [Value<'a>]);
 
 impl<'a> fmt::Display for Params<'a> {
@@ -346,6 +403,34 @@ impl<'a> Value<'a> {
         }
     }
 
+    /// The type of the value, also defined for null values.
+    pub const fn kind(&self) -> ValueKind {
+        match self {
+            Value::Integer(_) => ValueKind::Integer,
+            Value::Float(_) => ValueKind::Float,
+            Value::Double(_) => ValueKind::Double,
+            Value::Text(_) => ValueKind::Text,
+            Value::Enum(_) => ValueKind::Enum,
+            Value::Bytes(_) => ValueKind::Bytes,
+            Value::Boolean(_) => ValueKind::Boolean,
+            Value::Char(_) => ValueKind::Char,
+            Value::Array(_) => ValueKind::Array,
+            Value::Xml(_) => ValueKind::Xml,
+            #[cfg(feature = "bigdecimal")]
+            Value::Numeric(_) => ValueKind::Numeric,
+            #[cfg(feature = "uuid")]
+            Value::Uuid(_) => ValueKind::Uuid,
+            #[cfg(feature = "chrono")]
+            Value::DateTime(_) => ValueKind::DateTime,
+            #[cfg(feature = "chrono")]
+            Value::Date(_) => ValueKind::Date,
+            #[cfg(feature = "chrono")]
+            Value::Time(_) => ValueKind::Time,
+            #[cfg(feature = "json")]
+            Value::Json(_) => ValueKind::Json,
+        }
+    }
+
     /// `true` if the `Value` is text.
     pub const fn is_text(&self) -> bool {
         matches!(self, Value::Text(_))
@@ -495,6 +580,15 @@ impl<'a> Value<'a> {
         matches!(self, Value::Array(_))
     }
 
+    /// Returns a slice of the values if the value is an array, otherwise
+    /// `None`.
+    pub fn as_array(&self) -> Option<&[Value<'a>]> {
+        match self {
+            Value::Array(Some(values)) => Some(values),
+            _ => None,
+        }
+    }
+
     /// `true` if the `Value` is of UUID type.
     #[cfg(feature = "uuid")]
     #[cfg_attr(feature = "docs", doc(cfg(feature = "uuid")))]
@@ -791,6 +885,37 @@ mod tests {
     #[cfg(feature = "chrono")]
     use std::str::FromStr;
 
+    #[test]
+    fn accessors_extract_the_matching_variant() {
+        assert_eq!(Some(42), Value::integer(42).as_i64());
+        assert_eq!(Some("Musti"), Value::text("Musti").as_str());
+        assert_eq!(Some(true), Value::boolean(true).as_bool());
+        assert_eq!(Some(1.5), Value::double(1.5).as_f64());
+        assert_eq!(Some(&b"cat"[..]), Value::bytes(b"cat".to_vec()).as_bytes());
+        assert_eq!(
+            Some(&[Value::integer(1), Value::integer(2)][..]),
+            Value::array(vec![1, 2]).as_array()
+        );
+
+        assert!(Value::Text(None).is_null());
+        assert!(!Value::integer(0).is_null());
+    }
+
+    #[test]
+    fn accessors_return_none_for_other_variants() {
+        assert_eq!(None, Value::text("42").as_i64());
+        assert_eq!(None, Value::integer(42).as_str());
+        assert_eq!(None, Value::text("true").as_bool());
+        assert_eq!(None, Value::integer(2).as_bool());
+        assert_eq!(None, Value::integer(1).as_f64());
+        assert_eq!(None, Value::integer(1).as_bytes());
+        assert_eq!(None, Value::integer(1).as_array());
+
+        assert_eq!(None, Value::Integer(None).as_i64());
+        assert_eq!(None, Value::Text(None).as_str());
+        assert_eq!(None, Value::Array(None).as_array());
+    }
+
     #[test]
     fn a_parameterized_value_of_ints_can_be_converted_into_a_vec() {
         let pv = Value::array(vec![1]);
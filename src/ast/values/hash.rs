@@ -0,0 +1,139 @@
+use super::Value;
+use std::{
+    hash::{Hash, Hasher},
+    mem,
+};
+
+impl<'a> PartialEq for Value<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Integer(a), Value::Integer(b)) => a == b,
+            (Value::Float(a), Value::Float(b)) => a.map(f32::to_bits) == b.map(f32::to_bits),
+            (Value::Double(a), Value::Double(b)) => a.map(f64::to_bits) == b.map(f64::to_bits),
+            (Value::Text(a), Value::Text(b)) => a == b,
+            (Value::Enum(a), Value::Enum(b)) => a == b,
+            (Value::Bytes(a), Value::Bytes(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+            (Value::Char(a), Value::Char(b)) => a == b,
+            (Value::Array(a), Value::Array(b)) => a == b,
+            (Value::Xml(a), Value::Xml(b)) => a == b,
+            #[cfg(feature = "bigdecimal")]
+            (Value::Numeric(a), Value::Numeric(b)) => a == b,
+            #[cfg(feature = "json")]
+            (Value::Json(a), Value::Json(b)) => a == b,
+            #[cfg(feature = "uuid")]
+            (Value::Uuid(a), Value::Uuid(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::DateTime(a), Value::DateTime(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::Date(a), Value::Date(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::Time(a), Value::Time(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Eq for Value<'a> {}
+
+impl<'a> Hash for Value<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            Value::Integer(i) => i.hash(state),
+            Value::Float(f) => f.map(f32::to_bits).hash(state),
+            Value::Double(f) => f.map(f64::to_bits).hash(state),
+            Value::Text(s) | Value::Enum(s) | Value::Xml(s) => s.hash(state),
+            Value::Bytes(b) => b.hash(state),
+            Value::Boolean(b) => b.hash(state),
+            Value::Char(c) => c.hash(state),
+            Value::Array(values) => values.hash(state),
+            #[cfg(feature = "bigdecimal")]
+            Value::Numeric(d) => d.hash(state),
+            // Objects are stored sorted by key, so equal values serialize to
+            // the same string.
+            #[cfg(feature = "json")]
+            Value::Json(j) => j.as_ref().map(|j| j.to_string()).hash(state),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(u) => u.hash(state),
+            #[cfg(feature = "chrono")]
+            Value::DateTime(dt) => dt.map(|dt| (dt.timestamp(), dt.timestamp_subsec_nanos())).hash(state),
+            #[cfg(feature = "chrono")]
+            Value::Date(d) => d.hash(state),
+            #[cfg(feature = "chrono")]
+            Value::Time(t) => t.hash(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "chrono")]
+    use chrono::{FixedOffset, TimeZone, Utc};
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of(value: &Value) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn equal_values_hash_equally() {
+        let a = Value::from("Musti");
+        let b = Value::Text(Some(String::from("Musti").into()));
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        let a = Value::array(vec![1, 2]);
+        let b = Value::array(vec![1, 2]);
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn distinct_values_differ() {
+        assert_ne!(Value::from(1), Value::from(2));
+        assert_ne!(hash_of(&Value::from(1)), hash_of(&Value::from(2)));
+
+        // Same content, different type.
+        assert_ne!(Value::Text(Some("a".into())), Value::Enum(Some("a".into())));
+        assert_ne!(hash_of(&Value::Integer(None)), hash_of(&Value::Text(None)));
+    }
+
+    #[test]
+    fn floats_are_compared_by_bits() {
+        let nan = Value::from(f64::NAN);
+
+        assert_eq!(nan, nan.clone());
+        assert_eq!(hash_of(&nan), hash_of(&nan.clone()));
+
+        assert_ne!(Value::from(0.0f64), Value::from(-0.0f64));
+        assert_ne!(hash_of(&Value::from(0.0f64)), hash_of(&Value::from(-0.0f64)));
+
+        assert_eq!(Value::from(1.5f32), Value::from(1.5f32));
+        assert_eq!(hash_of(&Value::from(1.5f32)), hash_of(&Value::from(1.5f32)));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn datetimes_are_compared_by_timestamp() {
+        let utc = Utc.ymd(2020, 1, 1).and_hms(12, 0, 0);
+        let offset = FixedOffset::east(2 * 3600).ymd(2020, 1, 1).and_hms(14, 0, 0);
+
+        let a = Value::from(utc);
+        let b = Value::from(offset.with_timezone(&Utc));
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        let c = Value::from(Utc.ymd(2020, 1, 1).and_hms_nano(12, 0, 0, 1));
+
+        assert_ne!(a, c);
+        assert_ne!(hash_of(&a), hash_of(&c));
+    }
+}
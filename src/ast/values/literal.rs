@@ -0,0 +1,252 @@
+use super::Value;
+use crate::{
+    connector::SqlFamily,
+    error::{Error, ErrorKind},
+};
+use std::fmt::Write;
+
+impl<'a> Value<'a> {
+    /// Renders the value as an SQL literal of the given database, escaping
+    /// the quotes and backslashes so the result is safe to embed to a query,
+    /// such as a `DEFAULT` clause.
+    ///
+    /// Date and time values are written in UTC without an offset. Arrays are
+    /// supported only on PostgreSQL, and infinite or `NaN` floats only on
+    /// PostgreSQL, returning an `UnsupportedFeature` error on the others.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, connector::SqlFamily};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// assert_eq!("'O''Brien'", Value::from("O'Brien").to_sql_literal(SqlFamily::Sqlite)?);
+    /// assert_eq!("NULL", Value::Integer(None).to_sql_literal(SqlFamily::Sqlite)?);
+    /// assert_eq!("123", Value::from(123).to_sql_literal(SqlFamily::Sqlite)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_sql_literal(&self, family: SqlFamily) -> crate::Result<String> {
+        if self.is_null() {
+            return Ok(String::from("NULL"));
+        }
+
+        let literal = match self {
+            Value::Integer(Some(i)) => i.to_string(),
+            Value::Float(Some(f)) => float_literal(family, f64::from(*f))?,
+            Value::Double(Some(f)) => float_literal(family, *f)?,
+            Value::Text(Some(s)) | Value::Enum(Some(s)) | Value::Xml(Some(s)) => text_literal(family, s),
+            Value::Char(Some(c)) => text_literal(family, &c.to_string()),
+            Value::Boolean(Some(b)) => bool_literal(family, *b).to_string(),
+            Value::Bytes(Some(b)) => bytes_literal(family, b),
+            Value::Array(Some(values)) if family.is_postgres() => {
+                let items: crate::Result<Vec<String>> = values.iter().map(|v| v.to_sql_literal(family)).collect();
+                format!("ARRAY[{}]", items?.join(", "))
+            }
+            Value::Array(Some(_)) => {
+                return Err(unsupported("Array literals are supported only on PostgreSQL."));
+            }
+            #[cfg(feature = "bigdecimal")]
+            Value::Numeric(Some(d)) => d.to_string(),
+            #[cfg(feature = "json")]
+            Value::Json(Some(j)) => text_literal(family, &serde_json::to_string(j)?),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(Some(u)) => text_literal(family, &u.to_hyphenated().to_string()),
+            #[cfg(feature = "chrono")]
+            Value::DateTime(Some(dt)) => text_literal(family, &dt.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+            #[cfg(feature = "chrono")]
+            Value::Date(Some(d)) => text_literal(family, &d.format("%Y-%m-%d").to_string()),
+            #[cfg(feature = "chrono")]
+            Value::Time(Some(t)) => text_literal(family, &t.format("%H:%M:%S%.f").to_string()),
+            _ => unreachable!("Null values are handled above."),
+        };
+
+        Ok(literal)
+    }
+}
+
+fn text_literal(family: SqlFamily, s: &str) -> String {
+    let quoted = s.replace('\'', "''");
+
+    match family {
+        // Without `standard_conforming_strings` a backslash could escape the
+        // closing quote. The `E` prefix works regardless of the setting.
+        #[cfg(feature = "postgresql")]
+        SqlFamily::Postgres if s.contains('\\') => format!("E'{}'", quoted.replace('\\', "\\\\")),
+        #[cfg(feature = "mysql")]
+        SqlFamily::Mysql => format!("'{}'", quoted.replace('\\', "\\\\")),
+        #[cfg(feature = "mssql")]
+        SqlFamily::Mssql => format!("N'{}'", quoted),
+        #[allow(unreachable_patterns)]
+        _ => format!("'{}'", quoted),
+    }
+}
+
+fn float_literal(family: SqlFamily, f: f64) -> crate::Result<String> {
+    if f.is_finite() {
+        return Ok(format!("{:?}", f));
+    }
+
+    if !family.is_postgres() {
+        return Err(unsupported("Infinite and NaN floats are supported only on PostgreSQL."));
+    }
+
+    let literal = if f.is_nan() {
+        "'NaN'"
+    } else if f.is_sign_positive() {
+        "'Infinity'"
+    } else {
+        "'-Infinity'"
+    };
+
+    Ok(literal.to_string())
+}
+
+fn bool_literal(family: SqlFamily, b: bool) -> &'static str {
+    match (family.is_postgres() || family.is_mysql(), b) {
+        (true, true) => "TRUE",
+        (true, false) => "FALSE",
+        (false, true) => "1",
+        (false, false) => "0",
+    }
+}
+
+fn bytes_literal(family: SqlFamily, b: &[u8]) -> String {
+    let mut hex = String::with_capacity(b.len() * 2);
+
+    for byte in b {
+        write!(&mut hex, "{:02x}", byte).expect("Writing to a string cannot fail.");
+    }
+
+    match family {
+        #[cfg(feature = "postgresql")]
+        SqlFamily::Postgres => format!("decode('{}', 'hex')", hex),
+        #[cfg(feature = "mssql")]
+        SqlFamily::Mssql => format!("0x{}", hex),
+        #[allow(unreachable_patterns)]
+        _ => format!("X'{}'", hex),
+    }
+}
+
+fn unsupported(msg: &'static str) -> Error {
+    let mut builder = Error::builder(ErrorKind::unsupported_feature(msg));
+    builder.set_original_message(msg);
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "chrono")]
+    #[allow(unused_imports)]
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_literals() {
+        let family = SqlFamily::Postgres;
+
+        assert_eq!("'O''Brien'", Value::from("O'Brien").to_sql_literal(family).unwrap());
+        assert_eq!(
+            "E'a\\\\'' OR 1=1 --'",
+            Value::from("a\\' OR 1=1 --").to_sql_literal(family).unwrap()
+        );
+        assert_eq!("NULL", Value::Text(None).to_sql_literal(family).unwrap());
+        assert_eq!("-123", Value::from(-123).to_sql_literal(family).unwrap());
+        assert_eq!("1.5", Value::from(1.5f64).to_sql_literal(family).unwrap());
+        assert_eq!("'NaN'", Value::from(f64::NAN).to_sql_literal(family).unwrap());
+        assert_eq!("TRUE", Value::from(true).to_sql_literal(family).unwrap());
+        assert_eq!("ARRAY[1, 2]", Value::array(vec![1, 2]).to_sql_literal(family).unwrap());
+        assert_eq!(
+            "decode('dead', 'hex')",
+            Value::bytes(vec![0xde_u8, 0xad]).to_sql_literal(family).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn mysql_literals() {
+        let family = SqlFamily::Mysql;
+
+        assert_eq!("'O''Brien'", Value::from("O'Brien").to_sql_literal(family).unwrap());
+        assert_eq!(
+            "'a\\\\'' OR 1=1 --'",
+            Value::from("a\\' OR 1=1 --").to_sql_literal(family).unwrap()
+        );
+        assert_eq!("NULL", Value::Integer(None).to_sql_literal(family).unwrap());
+        assert_eq!("123", Value::from(123).to_sql_literal(family).unwrap());
+        assert_eq!("0.25", Value::from(0.25f64).to_sql_literal(family).unwrap());
+        assert_eq!("FALSE", Value::from(false).to_sql_literal(family).unwrap());
+
+        let err = Value::from(f64::INFINITY).to_sql_literal(family).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "sqlite")]
+    fn sqlite_literals() {
+        let family = SqlFamily::Sqlite;
+
+        assert_eq!("'O''Brien'", Value::from("O'Brien").to_sql_literal(family).unwrap());
+        assert_eq!("'a\\'' --'", Value::from("a\\' --").to_sql_literal(family).unwrap());
+        assert_eq!("NULL", Value::Double(None).to_sql_literal(family).unwrap());
+        assert_eq!("123", Value::from(123).to_sql_literal(family).unwrap());
+        assert_eq!("1", Value::from(true).to_sql_literal(family).unwrap());
+        assert_eq!(
+            "X'dead'",
+            Value::bytes(vec![0xde_u8, 0xad]).to_sql_literal(family).unwrap()
+        );
+
+        let err = Value::array(vec![1, 2]).to_sql_literal(family).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "mssql")]
+    fn mssql_literals() {
+        let family = SqlFamily::Mssql;
+
+        assert_eq!("N'O''Brien'", Value::from("O'Brien").to_sql_literal(family).unwrap());
+        assert_eq!("NULL", Value::Boolean(None).to_sql_literal(family).unwrap());
+        assert_eq!("123", Value::from(123).to_sql_literal(family).unwrap());
+        assert_eq!("0", Value::from(false).to_sql_literal(family).unwrap());
+        assert_eq!(
+            "0xdead",
+            Value::bytes(vec![0xde_u8, 0xad]).to_sql_literal(family).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "chrono", feature = "postgresql"))]
+    fn postgres_datetime_literals() {
+        let dt = Value::from(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0));
+        let date = Value::from(NaiveDate::from_ymd(2020, 1, 1));
+
+        assert_eq!("'2020-01-01 00:00:00'", dt.to_sql_literal(SqlFamily::Postgres).unwrap());
+        assert_eq!("'2020-01-01'", date.to_sql_literal(SqlFamily::Postgres).unwrap());
+    }
+
+    #[test]
+    #[cfg(all(feature = "chrono", feature = "mysql"))]
+    fn mysql_datetime_literals() {
+        let dt = Value::from(Utc.ymd(2020, 1, 1).and_hms_milli(12, 30, 0, 500));
+
+        assert_eq!(
+            "'2020-01-01 12:30:00.500'",
+            dt.to_sql_literal(SqlFamily::Mysql).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "chrono", feature = "sqlite"))]
+    fn sqlite_datetime_literals() {
+        let dt = Value::from(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0));
+
+        assert_eq!("'2020-01-01 00:00:00'", dt.to_sql_literal(SqlFamily::Sqlite).unwrap());
+    }
+
+    #[test]
+    #[cfg(all(feature = "chrono", feature = "mssql"))]
+    fn mssql_datetime_literals() {
+        let dt = Value::from(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0));
+
+        assert_eq!("N'2020-01-01 00:00:00'", dt.to_sql_literal(SqlFamily::Mssql).unwrap());
+    }
+}
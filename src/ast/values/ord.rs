@@ -0,0 +1,137 @@
+use super::Value;
+use std::cmp::Ordering;
+
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::{BigDecimal, FromPrimitive};
+
+/// Orders the values for filtering and sorting rows in memory.
+///
+/// - Numbers compare numerically, also between the integer, float, double and
+///   numeric variants.
+/// - `NULL` sorts before any non-null value.
+/// - Values of the same type compare naturally, arrays element by element.
+/// - Other mismatched types, JSON values and two `NULL`s of different types
+///   are not comparable.
+///
+/// To stay consistent with equality, values of different types that are
+/// numerically equal, such as `1` and `1.0`, are not comparable either.
+///
+/// ```rust
+/// # use quaint::ast::*;
+/// assert!(Value::from(1) < Value::from(1.5f64));
+/// assert!(Value::Text(None) < Value::from("a"));
+/// assert_eq!(None, Value::from(1).partial_cmp(&Value::from("a")));
+/// ```
+impl<'a> PartialOrd for Value<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+
+        let ordering = match (self, other) {
+            (a, b) if a.is_null() && b.is_null() => None,
+            (a, _) if a.is_null() => Some(Ordering::Less),
+            (_, b) if b.is_null() => Some(Ordering::Greater),
+            (Value::Text(a), Value::Text(b)) => a.partial_cmp(b),
+            (Value::Enum(a), Value::Enum(b)) => a.partial_cmp(b),
+            (Value::Xml(a), Value::Xml(b)) => a.partial_cmp(b),
+            (Value::Bytes(a), Value::Bytes(b)) => a.partial_cmp(b),
+            (Value::Boolean(a), Value::Boolean(b)) => a.partial_cmp(b),
+            (Value::Char(a), Value::Char(b)) => a.partial_cmp(b),
+            (Value::Array(a), Value::Array(b)) => a.partial_cmp(b),
+            #[cfg(feature = "uuid")]
+            (Value::Uuid(a), Value::Uuid(b)) => a.partial_cmp(b),
+            #[cfg(feature = "chrono")]
+            (Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(b),
+            #[cfg(feature = "chrono")]
+            (Value::Date(a), Value::Date(b)) => a.partial_cmp(b),
+            #[cfg(feature = "chrono")]
+            (Value::Time(a), Value::Time(b)) => a.partial_cmp(b),
+            #[cfg(feature = "bigdecimal")]
+            (Value::Numeric(Some(a)), b) => as_numeric(b).and_then(|b| a.partial_cmp(&b)),
+            #[cfg(feature = "bigdecimal")]
+            (a, Value::Numeric(Some(b))) => as_numeric(a).and_then(|a| a.partial_cmp(b)),
+            (Value::Integer(Some(a)), Value::Integer(Some(b))) => a.partial_cmp(b),
+            (a, b) => match (as_f64(a), as_f64(b)) {
+                (Some(a), Some(b)) => a.partial_cmp(&b),
+                _ => None,
+            },
+        };
+
+        // Equal values were handled above, the rest are equal only by their
+        // numeric value or are zeros of different signs.
+        ordering.filter(|ordering| *ordering != Ordering::Equal)
+    }
+}
+
+fn as_f64(value: &Value<'_>) -> Option<f64> {
+    match value {
+        Value::Integer(Some(i)) => Some(*i as f64),
+        Value::Float(Some(f)) => Some(f64::from(*f)),
+        Value::Double(Some(f)) => Some(*f),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+fn as_numeric(value: &Value<'_>) -> Option<BigDecimal> {
+    match value {
+        Value::Integer(Some(i)) => Some(BigDecimal::from(*i)),
+        Value::Float(Some(f)) => BigDecimal::from_f32(*f),
+        Value::Double(Some(f)) => BigDecimal::from_f64(*f),
+        Value::Numeric(Some(d)) => Some(d.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_and_floats_compare_numerically() {
+        assert!(Value::from(1) < Value::from(2));
+        assert!(Value::from(-5) < Value::from(-4));
+        assert!(Value::from(1.5f64) < Value::from(2.5f64));
+        assert!(Value::from(1.5f32) > Value::from(1));
+        assert!(Value::from(2) > Value::from(1.5f64));
+    }
+
+    #[test]
+    fn strings_compare_lexicographically() {
+        assert!(Value::from("a") < Value::from("b"));
+        assert!(Value::from("ab") > Value::from("a"));
+        assert_eq!(Some(Ordering::Equal), Value::from("a").partial_cmp(&Value::from("a")));
+    }
+
+    #[test]
+    fn null_sorts_first() {
+        assert!(Value::Integer(None) < Value::from(i64::MIN));
+        assert!(Value::Text(None) < Value::from(""));
+        assert!(Value::from(1) > Value::Double(None));
+        assert_eq!(
+            Some(Ordering::Equal),
+            Value::Integer(None).partial_cmp(&Value::Integer(None))
+        );
+    }
+
+    #[test]
+    fn mismatched_types_are_not_comparable() {
+        assert_eq!(None, Value::from(1).partial_cmp(&Value::from("1")));
+        assert_eq!(None, Value::from(true).partial_cmp(&Value::from(1)));
+        assert_eq!(None, Value::Integer(None).partial_cmp(&Value::Text(None)));
+    }
+
+    #[test]
+    fn numerically_equal_values_of_different_types_are_not_comparable() {
+        assert_ne!(Value::from(1), Value::from(1.0f64));
+        assert_eq!(None, Value::from(1).partial_cmp(&Value::from(1.0f64)));
+        assert_eq!(None, Value::from(0.0f64).partial_cmp(&Value::from(-0.0f64)));
+    }
+
+    #[test]
+    fn arrays_compare_element_by_element() {
+        assert!(Value::array(vec![1, 2]) < Value::array(vec![1, 3]));
+        assert!(Value::array(vec![1]) < Value::array(vec![1, 0]));
+    }
+}
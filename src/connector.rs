@@ -11,6 +11,7 @@
 
 mod connection_info;
 pub(crate) mod metrics;
+mod named_params;
 mod queryable;
 mod result_set;
 #[cfg(any(feature = "mssql", feature = "postgresql", feature = "mysql"))]
@@ -35,6 +36,7 @@ pub use self::result_set::*;
 pub use connection_info::*;
 #[cfg(feature = "mssql")]
 pub use mssql::*;
+pub use named_params::*;
 pub use queryable::*;
 #[cfg(feature = "sqlite")]
 pub use sqlite::*;
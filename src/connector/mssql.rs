@@ -71,6 +71,7 @@ pub(crate) struct MssqlQueryParams {
     schema: String,
     trust_server_certificate: bool,
     connection_limit: Option<usize>,
+    pool_min_idle: Option<usize>,
     socket_timeout: Option<Duration>,
     connect_timeout: Option<Duration>,
     pool_timeout: Option<Duration>,
@@ -137,6 +138,12 @@ impl MssqlUrl {
         self.query_params.connection_limit()
     }
 
+    /// Minimum number of idle connections the pool opens when warmed up (if
+    /// used together with pooled Quaint).
+    pub fn pool_min_idle(&self) -> Option<usize> {
+        self.query_params.pool_min_idle
+    }
+
     /// A duration how long one query can take.
     pub fn socket_timeout(&self) -> Option<Duration> {
         self.query_params.socket_timeout()
@@ -404,6 +411,7 @@ impl MssqlUrl {
         let schema = props.remove("schema").unwrap_or_else(|| String::from("dbo"));
 
         let connection_limit = props.remove("connectionlimit").map(|param| param.parse()).transpose()?;
+        let pool_min_idle = props.remove("poolminidle").map(|param| param.parse()).transpose()?;
 
         let transaction_isolation_level = props
             .remove("isolationlevel")
@@ -461,6 +469,7 @@ impl MssqlUrl {
             schema,
             trust_server_certificate,
             connection_limit,
+            pool_min_idle,
             socket_timeout,
             connect_timeout,
             pool_timeout,
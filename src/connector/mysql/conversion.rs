@@ -1,3 +1,4 @@
+use super::ZeroDates;
 use crate::{
     ast::Value,
     connector::{queryable::TakeRow, TypeIdentifier},
@@ -64,9 +65,16 @@ pub fn conv_params<'a>(params: &[Value<'a>]) -> crate::Result<my::Params> {
                     d.map(|d| my::Value::Date(d.year() as u16, d.month() as u8, d.day() as u8, 0, 0, 0, 0))
                 }
                 #[cfg(feature = "chrono")]
-                Value::Time(t) => {
-                    t.map(|t| my::Value::Time(false, 0, t.hour() as u8, t.minute() as u8, t.second() as u8, 0))
-                }
+                Value::Time(t) => t.map(|t| {
+                    my::Value::Time(
+                        false,
+                        0,
+                        t.hour() as u8,
+                        t.minute() as u8,
+                        t.second() as u8,
+                        t.nanosecond() / 1000,
+                    )
+                }),
                 #[cfg(feature = "chrono")]
                 Value::DateTime(dt) => dt.map(|dt| {
                     my::Value::Date(
@@ -169,9 +177,7 @@ impl TypeIdentifier for my::Column {
             MYSQL_TYPE_TINY_BLOB | MYSQL_TYPE_MEDIUM_BLOB | MYSQL_TYPE_LONG_BLOB | MYSQL_TYPE_BLOB
         ) && self.character_set() == 63;
 
-        let is_bits = self.column_type() == MYSQL_TYPE_BIT && self.column_length() > 1;
-
-        is_a_blob || is_bits
+        is_a_blob || is_bit_field(self) || is_geometry(self)
     }
 
     fn is_bool(&self) -> bool {
@@ -191,136 +197,264 @@ impl TypeIdentifier for my::Column {
     }
 }
 
+/// A `BIT(n)` column wider than one bit, read as bytes in big-endian order.
+/// `BIT(1)` is a boolean.
+fn is_bit_field(column: &my::Column) -> bool {
+    column.column_type() == ColumnType::MYSQL_TYPE_BIT && column.column_length() > 1
+}
+
+#[cfg(not(feature = "bigdecimal"))]
+fn is_integral_decimal(column: &my::Column) -> bool {
+    column.is_real() && column.decimals() == 0
+}
+
+/// Spatial types in the internal format of MySQL: a four-byte SRID followed
+/// by the WKB representation.
+fn is_geometry(column: &my::Column) -> bool {
+    column.column_type() == ColumnType::MYSQL_TYPE_GEOMETRY
+}
+
+/// The time of a MySQL `TIME` or `DATETIME` value, failing instead of
+/// panicking on out of range parts.
+#[cfg(feature = "chrono")]
+fn time_from_parts(hours: u8, minutes: u8, seconds: u8, micros: u32) -> crate::Result<NaiveTime> {
+    NaiveTime::from_hms_micro_opt(hours.into(), minutes.into(), seconds.into(), micros).ok_or_else(|| {
+        let msg = format!("Invalid time {:02}:{:02}:{:02}.{:06}.", hours, minutes, seconds, micros);
+
+        Error::builder(ErrorKind::conversion(msg)).build()
+    })
+}
+
+/// The value of a MySQL `DATE` or `DATETIME`. Dates with a zero day or
+/// month, such as `0000-00-00`, are read as configured with `zero_dates`.
+#[cfg(feature = "chrono")]
+#[allow(clippy::too_many_arguments)]
+fn datetime_from_parts(
+    column: &str,
+    zero_dates: ZeroDates,
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    min: u8,
+    sec: u8,
+    micro: u32,
+) -> crate::Result<Value<'static>> {
+    if day == 0 || month == 0 {
+        return match zero_dates {
+            ZeroDates::Null => Ok(Value::DateTime(None)),
+            ZeroDates::Error => {
+                let msg = format!(
+                    "The column `{}` contained an invalid datetime value with either day or month set to zero.",
+                    column
+                );
+
+                Err(Error::builder(ErrorKind::value_out_of_range(msg)).build())
+            }
+        };
+    }
+
+    let time = time_from_parts(hour, min, sec, micro)?;
+
+    let date = NaiveDate::from_ymd_opt(year.into(), month.into(), day.into()).ok_or_else(|| {
+        let msg = format!(
+            "The column `{}` contained an invalid date {}-{}-{}.",
+            column, year, month, day
+        );
+
+        Error::builder(ErrorKind::conversion(msg)).build()
+    })?;
+
+    let dt = NaiveDateTime::new(date, time);
+
+    Ok(Value::datetime(DateTime::<Utc>::from_utc(dt, Utc)))
+}
+
+fn is_year(column: &my::Column) -> bool {
+    column.column_type() == ColumnType::MYSQL_TYPE_YEAR
+}
+
+/// Like `take_result_row`, but for rows read with the text protocol, where
+/// also the numbers are sent as text.
+pub(crate) fn take_text_result_row(row: &mut my::Row, zero_dates: ZeroDates) -> crate::Result<Vec<Value<'static>>> {
+    let values = take_result_row(row, zero_dates)?;
+
+    values
+        .into_iter()
+        .zip(row.columns_ref().iter())
+        .map(|(value, column)| match value {
+            Value::Text(Some(s)) if column.is_integer() => {
+                let i: i64 = s.parse().map_err(|_| {
+                    let msg = format!("Could not parse `{}` as an integer.", s);
+                    Error::builder(ErrorKind::conversion(msg)).build()
+                })?;
+
+                Ok(Value::integer(i))
+            }
+            Value::Text(Some(s)) if column.is_float() || column.is_double() => {
+                let f: f64 = s.parse().map_err(|_| {
+                    let msg = format!("Could not parse `{}` as a floating point number.", s);
+                    Error::builder(ErrorKind::conversion(msg)).build()
+                })?;
+
+                Ok(if column.is_float() {
+                    Value::float(f as f32)
+                } else {
+                    Value::double(f)
+                })
+            }
+            value => Ok(value),
+        })
+        .collect()
+}
+
 impl TakeRow for my::Row {
     fn take_result_row(&mut self) -> crate::Result<Vec<Value<'static>>> {
-        fn convert(row: &mut my::Row, i: usize) -> crate::Result<Value<'static>> {
-            let value = row.take(i).ok_or_else(|| {
-                let msg = "Index out of bounds";
-                let kind = ErrorKind::conversion(msg);
-
-                Error::builder(kind).build()
-            })?;
+        take_result_row(self, ZeroDates::Error)
+    }
+}
 
-            let column = row.columns_ref().get(i).ok_or_else(|| {
-                let msg = "Index out of bounds";
+/// Converts the values of the row, reading the zero dates as configured.
+pub(crate) fn take_result_row(row: &mut my::Row, zero_dates: ZeroDates) -> crate::Result<Vec<Value<'static>>> {
+    #[cfg_attr(not(feature = "chrono"), allow(unused_variables))]
+    fn convert(row: &mut my::Row, i: usize, zero_dates: ZeroDates) -> crate::Result<Value<'static>> {
+        let value = row.take(i).ok_or_else(|| {
+            let msg = "Index out of bounds";
+            let kind = ErrorKind::conversion(msg);
+
+            Error::builder(kind).build()
+        })?;
+
+        let column = row.columns_ref().get(i).ok_or_else(|| {
+            let msg = "Index out of bounds";
+            let kind = ErrorKind::conversion(msg);
+
+            Error::builder(kind).build()
+        })?;
+
+        let res = match value {
+            // JSON is returned as bytes.
+            #[cfg(feature = "json")]
+            my::Value::Bytes(b) if column.is_json() => serde_json::from_slice(&b).map(Value::json).map_err(|_| {
+                let msg = "Unable to convert bytes to JSON";
                 let kind = ErrorKind::conversion(msg);
 
                 Error::builder(kind).build()
-            })?;
-
-            let res = match value {
-                // JSON is returned as bytes.
-                #[cfg(feature = "json")]
-                my::Value::Bytes(b) if column.is_json() => {
-                    serde_json::from_slice(&b).map(Value::json).map_err(|_| {
-                        let msg = "Unable to convert bytes to JSON";
-                        let kind = ErrorKind::conversion(msg);
+            })?,
+            my::Value::Bytes(b) if column.is_enum() => {
+                let s = String::from_utf8(b)?;
+                Value::enum_variant(s)
+            }
+            // NEWDECIMAL returned as bytes. See https://mariadb.com/kb/en/resultset-row/#decimal-binary-encoding
+            #[cfg(feature = "bigdecimal")]
+            my::Value::Bytes(b) if column.is_real() => {
+                let s = String::from_utf8(b).map_err(|_| {
+                    let msg = "Could not convert NEWDECIMAL from bytes to String.";
+                    let kind = ErrorKind::conversion(msg);
 
-                        Error::builder(kind).build()
-                    })?
-                }
-                my::Value::Bytes(b) if column.is_enum() => {
-                    let s = String::from_utf8(b)?;
-                    Value::enum_variant(s)
-                }
-                // NEWDECIMAL returned as bytes. See https://mariadb.com/kb/en/resultset-row/#decimal-binary-encoding
-                #[cfg(feature = "bigdecimal")]
-                my::Value::Bytes(b) if column.is_real() => {
-                    let s = String::from_utf8(b).map_err(|_| {
-                        let msg = "Could not convert NEWDECIMAL from bytes to String.";
-                        let kind = ErrorKind::conversion(msg);
+                    Error::builder(kind).build()
+                })?;
 
-                        Error::builder(kind).build()
-                    })?;
+                let dec = s.parse().map_err(|_| {
+                    let msg = "Could not convert NEWDECIMAL string to a BigDecimal.";
+                    let kind = ErrorKind::conversion(msg);
 
-                    let dec = s.parse().map_err(|_| {
-                        let msg = "Could not convert NEWDECIMAL string to a BigDecimal.";
-                        let kind = ErrorKind::conversion(msg);
+                    Error::builder(kind).build()
+                })?;
 
-                        Error::builder(kind).build()
-                    })?;
+                Value::numeric(dec)
+            }
+            // Without decimal support, zero-scale decimals are integers and
+            // fail if they do not fit in an `i64`.
+            #[cfg(not(feature = "bigdecimal"))]
+            my::Value::Bytes(b) if is_integral_decimal(column) => {
+                let s = String::from_utf8(b)?;
 
-                    Value::numeric(dec)
-                }
-                my::Value::Bytes(b) if column.is_bool() => match b.as_slice() {
-                    [0] => Value::boolean(false),
-                    _ => Value::boolean(true),
-                },
-                // https://dev.mysql.com/doc/internals/en/character-set.html
-                my::Value::Bytes(b) if column.character_set() == 63 => Value::bytes(b),
-                my::Value::Bytes(s) => Value::text(String::from_utf8(s)?),
-                my::Value::Int(i) => Value::integer(i),
-                my::Value::UInt(i) => Value::integer(i64::try_from(i).map_err(|_| {
-                    let msg = "Unsigned integers larger than 9_223_372_036_854_775_807 are currently not handled.";
+                let i = s.parse::<i64>().map_err(|_| {
+                    let msg = format!(
+                        "The decimal `{}` in the column `{}` does not fit in an i64.",
+                        s,
+                        column.name_str()
+                    );
                     let kind = ErrorKind::value_out_of_range(msg);
 
                     Error::builder(kind).build()
-                })?),
-                my::Value::Float(f) => Value::from(f),
-                my::Value::Double(f) => Value::from(f),
-                #[cfg(feature = "chrono")]
-                my::Value::Date(year, month, day, hour, min, sec, micro) => {
-                    if day == 0 || month == 0 {
-                        let msg = format!(
-                            "The column `{}` contained an invalid datetime value with either day or month set to zero.",
-                            column.name_str()
-                        );
-                        let kind = ErrorKind::value_out_of_range(msg);
-                        return Err(Error::builder(kind).build());
-                    }
+                })?;
 
-                    let time = NaiveTime::from_hms_micro(hour.into(), min.into(), sec.into(), micro);
+                Value::integer(i)
+            }
+            my::Value::Bytes(b) if column.is_bool() => match b.as_slice() {
+                [0] => Value::boolean(false),
+                _ => Value::boolean(true),
+            },
+            // Bit fields are never text, regardless of the character set.
+            my::Value::Bytes(b) if is_bit_field(column) => Value::bytes(b),
+            my::Value::Bytes(b) if is_geometry(column) => Value::bytes(b),
+            // The text protocol returns years as strings.
+            my::Value::Bytes(b) if is_year(column) => {
+                let year = std::str::from_utf8(&b)
+                    .ok()
+                    .and_then(|s| s.parse::<i64>().ok())
+                    .ok_or_else(|| {
+                        let msg = format!("The column `{}` contained an invalid year.", column.name_str());
+                        let kind = ErrorKind::conversion(msg);
 
-                    let date = NaiveDate::from_ymd(year.into(), month.into(), day.into());
-                    let dt = NaiveDateTime::new(date, time);
+                        Error::builder(kind).build()
+                    })?;
 
-                    Value::datetime(DateTime::<Utc>::from_utc(dt, Utc))
-                }
-                #[cfg(feature = "chrono")]
-                my::Value::Time(is_neg, days, hours, minutes, seconds, micros) => {
-                    if is_neg {
-                        let kind = ErrorKind::conversion("Failed to convert a negative time");
-                        return Err(Error::builder(kind).build());
-                    }
+                Value::integer(year)
+            }
+            // https://dev.mysql.com/doc/internals/en/character-set.html
+            my::Value::Bytes(b) if column.character_set() == 63 => Value::bytes(b),
+            my::Value::Bytes(s) => Value::text(String::from_utf8(s)?),
+            my::Value::Int(i) => Value::integer(i),
+            my::Value::UInt(i) => Value::integer(i64::try_from(i).map_err(|_| {
+                let msg = "Unsigned integers larger than 9_223_372_036_854_775_807 are currently not handled.";
+                let kind = ErrorKind::value_out_of_range(msg);
 
-                    if days != 0 {
-                        let kind = ErrorKind::conversion("Failed to read a MySQL `time` as duration");
-                        return Err(Error::builder(kind).build());
-                    }
+                Error::builder(kind).build()
+            })?),
+            my::Value::Float(f) => Value::from(f),
+            my::Value::Double(f) => Value::from(f),
+            #[cfg(feature = "chrono")]
+            my::Value::Date(year, month, day, hour, min, sec, micro) => {
+                datetime_from_parts(&column.name_str(), zero_dates, year, month, day, hour, min, sec, micro)?
+            }
+            #[cfg(feature = "chrono")]
+            my::Value::Time(is_neg, days, hours, minutes, seconds, micros) => {
+                if is_neg {
+                    let kind = ErrorKind::conversion("Failed to convert a negative time");
+                    return Err(Error::builder(kind).build());
+                }
 
-                    let time = NaiveTime::from_hms_micro(hours.into(), minutes.into(), seconds.into(), micros);
-                    Value::time(time)
+                if days != 0 {
+                    let kind = ErrorKind::conversion("Failed to read a MySQL `time` as duration");
+                    return Err(Error::builder(kind).build());
                 }
-                my::Value::NULL => match column {
-                    t if t.is_bool() => Value::Boolean(None),
-                    t if t.is_enum() => Value::Enum(None),
-                    t if t.is_null() => Value::Integer(None),
-                    t if t.is_integer() => Value::Integer(None),
-                    t if t.is_float() => Value::Float(None),
-                    t if t.is_double() => Value::Double(None),
-                    t if t.is_text() => Value::Text(None),
-                    t if t.is_bytes() => Value::Bytes(None),
-                    #[cfg(feature = "bigdecimal")]
-                    t if t.is_real() => Value::Numeric(None),
-                    #[cfg(feature = "chrono")]
-                    t if t.is_datetime() => Value::DateTime(None),
-                    #[cfg(feature = "chrono")]
-                    t if t.is_time() => Value::Time(None),
-                    #[cfg(feature = "chrono")]
-                    t if t.is_date() => Value::Date(None),
-                    #[cfg(feature = "json")]
-                    t if t.is_json() => Value::Json(None),
-                    typ => {
-                        let msg = format!(
-                            "Value of type {:?} is not supported with the current configuration",
-                            typ
-                        );
 
-                        let kind = ErrorKind::conversion(msg);
-                        return Err(Error::builder(kind).build());
-                    }
-                },
-                #[cfg(not(feature = "chrono"))]
+                Value::time(time_from_parts(hours, minutes, seconds, micros)?)
+            }
+            my::Value::NULL => match column {
+                t if t.is_bool() => Value::Boolean(None),
+                t if t.is_enum() => Value::Enum(None),
+                t if t.is_null() => Value::Integer(None),
+                t if t.is_integer() => Value::Integer(None),
+                t if t.is_float() => Value::Float(None),
+                t if t.is_double() => Value::Double(None),
+                t if t.is_text() => Value::Text(None),
+                t if t.is_bytes() => Value::Bytes(None),
+                #[cfg(feature = "bigdecimal")]
+                t if t.is_real() => Value::Numeric(None),
+                #[cfg(not(feature = "bigdecimal"))]
+                t if is_integral_decimal(t) => Value::Integer(None),
+                #[cfg(feature = "chrono")]
+                t if t.is_datetime() => Value::DateTime(None),
+                #[cfg(feature = "chrono")]
+                t if t.is_time() => Value::Time(None),
+                #[cfg(feature = "chrono")]
+                t if t.is_date() => Value::Date(None),
+                #[cfg(feature = "json")]
+                t if t.is_json() => Value::Json(None),
                 typ => {
                     let msg = format!(
                         "Value of type {:?} is not supported with the current configuration",
@@ -328,19 +462,95 @@ impl TakeRow for my::Row {
                     );
 
                     let kind = ErrorKind::conversion(msg);
-                    Err(Error::builder(kind).build())?
+                    return Err(Error::builder(kind).build());
                 }
-            };
+            },
+            #[cfg(not(feature = "chrono"))]
+            typ => {
+                let msg = format!(
+                    "Value of type {:?} is not supported with the current configuration",
+                    typ
+                );
 
-            Ok(res)
-        }
+                let kind = ErrorKind::conversion(msg);
+                Err(Error::builder(kind).build())?
+            }
+        };
+
+        Ok(res)
+    }
 
-        let mut row = Vec::with_capacity(self.len());
+    let mut values = Vec::with_capacity(row.len());
 
-        for i in 0..self.len() {
-            row.push(convert(self, i)?);
-        }
+    for i in 0..row.len() {
+        values.push(convert(row, i, zero_dates)?);
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+#[cfg(feature = "chrono")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_parts_in_range_are_converted() {
+        let time = time_from_parts(23, 59, 59, 999_999).unwrap();
+        assert_eq!(NaiveTime::from_hms_micro(23, 59, 59, 999_999), time);
+    }
+
+    #[test]
+    fn time_parts_out_of_range_fail_gracefully() {
+        let err = time_from_parts(24, 0, 0, 0).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+
+        let err = time_from_parts(12, 60, 0, 0).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+
+        let err = time_from_parts(255, 255, 255, u32::MAX).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[test]
+    fn zero_dates_fail_by_default() {
+        let err = datetime_from_parts("data", ZeroDates::Error, 0, 0, 0, 0, 0, 0, 0).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ValueOutOfRange { .. }));
+
+        let err = datetime_from_parts("data", ZeroDates::Error, 2020, 1, 0, 0, 0, 0, 0).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ValueOutOfRange { .. }));
+    }
+
+    #[test]
+    fn invalid_dates_fail_gracefully() {
+        let err = datetime_from_parts("data", ZeroDates::Error, 2021, 2, 29, 0, 0, 0, 0).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+
+        let err = datetime_from_parts("data", ZeroDates::Error, 2021, 13, 1, 0, 0, 0, 0).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
 
-        Ok(row)
+    #[test]
+    fn zero_dates_can_be_read_as_null() {
+        let value = datetime_from_parts("data", ZeroDates::Null, 0, 0, 0, 0, 0, 0, 0).unwrap();
+        assert_eq!(Value::DateTime(None), value);
+
+        let value = datetime_from_parts("data", ZeroDates::Null, 2020, 2, 29, 12, 30, 0, 0).unwrap();
+        let expected = DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2020, 2, 29).and_hms(12, 30, 0), Utc);
+
+        assert_eq!(Value::datetime(expected), value);
+    }
+
+    #[test]
+    fn times_are_bound_with_microseconds() {
+        let time = NaiveTime::from_hms_micro(16, 20, 0, 123_456);
+        let params = conv_params(&[Value::time(time)]).unwrap();
+
+        match params {
+            my::Params::Positional(values) => {
+                assert_eq!(vec![my::Value::Time(false, 0, 16, 20, 0, 123_456)], values);
+            }
+            params => panic!("Expected positional parameters, got {:?}", params),
+        }
     }
 }
@@ -1,5 +1,6 @@
 use crate::error::{DatabaseConstraint, Error, ErrorKind};
 use mysql_async as my;
+use std::io;
 
 impl From<my::Error> for Error {
     fn from(e: my::Error) -> Error {
@@ -10,7 +11,17 @@ impl From<my::Error> for Error {
                 message: err.to_string(),
             })
             .build(),
-            my::Error::Io(io_error) => Error::builder(ErrorKind::ConnectionError(io_error.into())).build(),
+            my::Error::Io(my::IoError::Io(io_error)) if is_closed_connection(&io_error) => {
+                let mut builder = Error::builder(ErrorKind::ConnectionClosed);
+                builder.set_original_message(io_error.to_string());
+
+                builder.build()
+            }
+            my::Error::Io(my::IoError::Io(io_error)) if is_unreachable_database(&io_error) => {
+                Error::builder(ErrorKind::ConnectionError(io_error.into())).build()
+            }
+            my::Error::Io(my::IoError::Io(io_error)) => Error::builder(ErrorKind::IoError(io_error)).build(),
+            my::Error::Driver(my::DriverError::ConnectionClosed) => Error::builder(ErrorKind::ConnectionClosed).build(),
             my::Error::Driver(e) => Error::builder(ErrorKind::QueryError(e.into())).build(),
             my::Error::Server(ServerError { ref message, code, .. }) if code == 1062 => {
                 let constraint = message
@@ -195,6 +206,14 @@ impl From<my::Error> for Error {
 
                 builder.build()
             }
+            my::Error::Server(ServerError { ref message, code, .. }) if code == 1317 || code == 3024 => {
+                let mut builder = Error::builder(ErrorKind::QueryCanceled);
+
+                builder.set_original_code(code.to_string());
+                builder.set_original_message(message);
+
+                builder.build()
+            }
             my::Error::Server(ServerError {
                 ref message,
                 code,
@@ -219,3 +238,86 @@ impl From<my::Error> for Error {
         }
     }
 }
+
+/// The I/O errors of a connection dropped by the server or the network.
+fn is_closed_connection(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+    )
+}
+
+/// The I/O errors of a database that could not be reached at all.
+fn is_unreachable_database(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::TimedOut
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::AddrNotAvailable
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropped_connection_is_connection_closed() {
+        let io_error = io::Error::new(io::ErrorKind::ConnectionReset, "connection reset by peer");
+        let err = Error::from(my::Error::Io(my::IoError::Io(io_error)));
+
+        assert!(matches!(err.kind(), ErrorKind::ConnectionClosed));
+    }
+
+    #[test]
+    fn closed_connection_in_the_driver_is_connection_closed() {
+        let err = Error::from(my::Error::Driver(my::DriverError::ConnectionClosed));
+
+        assert!(matches!(err.kind(), ErrorKind::ConnectionClosed));
+    }
+
+    #[test]
+    fn unreachable_database_is_a_connection_error() {
+        for kind in &[io::ErrorKind::ConnectionRefused, io::ErrorKind::TimedOut] {
+            let io_error = io::Error::new(*kind, "could not connect");
+            let err = Error::from(my::Error::Io(my::IoError::Io(io_error)));
+
+            assert!(matches!(err.kind(), ErrorKind::ConnectionError(_)));
+        }
+    }
+
+    #[test]
+    fn other_io_errors_are_io_errors() {
+        let io_error = io::Error::new(io::ErrorKind::PermissionDenied, "permission denied");
+        let err = Error::from(my::Error::Io(my::IoError::Io(io_error)));
+
+        assert!(matches!(err.kind(), ErrorKind::IoError(_)));
+    }
+
+    #[test]
+    fn syntax_error_is_a_query_error() {
+        let err = Error::from(my::Error::Server(my::ServerError {
+            code: 1064,
+            message: String::from("You have an error in your SQL syntax"),
+            state: String::from("42000"),
+        }));
+
+        assert!(matches!(err.kind(), ErrorKind::QueryError(_)));
+        assert_eq!(Some("1064"), err.original_code());
+    }
+
+    #[test]
+    fn interrupted_query_is_query_canceled() {
+        let err = Error::from(my::Error::Server(my::ServerError {
+            code: 1317,
+            message: String::from("Query execution was interrupted"),
+            state: String::from("70100"),
+        }));
+
+        assert!(matches!(err.kind(), ErrorKind::QueryCanceled));
+    }
+}
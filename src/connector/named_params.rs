@@ -0,0 +1,271 @@
+use super::SqlFamily;
+use crate::{
+    ast::Value,
+    error::{Error, ErrorKind},
+};
+use std::collections::HashMap;
+
+/// Rewrites the `:name` placeholders of a raw SQL string into the positional
+/// form of the given database, returning the parameters in the order they
+/// are referenced.
+///
+/// Placeholders inside string literals, quoted identifiers, comments and
+/// PostgreSQL dollar-quoted strings are left untouched, as are PostgreSQL
+/// `::type` casts. A parameter used more than
+/// once is bound once in PostgreSQL and SQL Server, and repeated for every
+/// `?` in MySQL and SQLite.
+///
+/// ```
+/// # use quaint::{connector::{named_to_positional, SqlFamily}, Value};
+/// # use std::collections::HashMap;
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let mut params = HashMap::new();
+/// params.insert("name", Value::from("Musti"));
+/// params.insert("age", Value::from(5));
+///
+/// let sql = "SELECT * FROM cats WHERE name = :name AND age > :age";
+/// let (sql, params) = named_to_positional(SqlFamily::Postgres, sql, &params)?;
+///
+/// assert_eq!("SELECT * FROM cats WHERE name = $1 AND age > $2", sql);
+/// assert_eq!(vec![Value::from("Musti"), Value::from(5)], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn named_to_positional<'a>(
+    family: SqlFamily,
+    sql: &str,
+    params: &HashMap<&str, Value<'a>>,
+) -> crate::Result<(String, Vec<Value<'a>>)> {
+    let mut query = String::with_capacity(sql.len());
+    let mut positional = Vec::with_capacity(params.len());
+    let mut positions: Vec<&str> = Vec::new();
+    let mut closing: Option<String> = None;
+
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if let Some(close) = closing.as_deref() {
+            if sql[i..].starts_with(close) {
+                query.push_str(close);
+                chars.by_ref().take(close.len() - 1).for_each(drop);
+                closing = None;
+            } else {
+                query.push(c);
+            }
+
+            continue;
+        }
+
+        if let Some((open, close)) = skipped_region(family, &sql[i..]) {
+            query.push_str(open);
+            chars.by_ref().take(open.len() - 1).for_each(drop);
+            closing = Some(close);
+
+            continue;
+        }
+
+        match c {
+            ':' if chars.peek().map(|(_, c)| *c == ':').unwrap_or(false) => {
+                query.push(':');
+                chars.next();
+            }
+            ':' if chars.peek().map(|(_, c)| is_name_start(*c)).unwrap_or(false) => {
+                let start = i + 1;
+                let mut end = start;
+
+                while let Some((j, c)) = chars.peek() {
+                    if is_name_char(*c) {
+                        end = j + c.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                let name = &sql[start..end];
+
+                let value = params.get(name).ok_or_else(|| {
+                    let msg = format!("No value given for the named parameter `:{}`.", name);
+                    Error::builder(ErrorKind::conversion(msg)).build()
+                })?;
+
+                let position = match positions.iter().position(|n| *n == name) {
+                    Some(position) if !family.uses_anonymous_parameters() => position,
+                    _ => {
+                        positions.push(name);
+                        positional.push(value.clone());
+                        positions.len() - 1
+                    }
+                };
+
+                write_placeholder(family, &mut query, position + 1);
+                continue;
+            }
+            _ => (),
+        }
+
+        query.push(c);
+    }
+
+    Ok((query, positional))
+}
+
+impl SqlFamily {
+    fn uses_anonymous_parameters(self) -> bool {
+        match self {
+            #[cfg(feature = "postgresql")]
+            SqlFamily::Postgres => false,
+            #[cfg(feature = "mssql")]
+            SqlFamily::Mssql => false,
+            #[allow(unreachable_patterns)]
+            _ => true,
+        }
+    }
+}
+
+fn write_placeholder(family: SqlFamily, query: &mut String, position: usize) {
+    match family {
+        #[cfg(feature = "postgresql")]
+        SqlFamily::Postgres => query.push_str(&format!("${}", position)),
+        #[cfg(feature = "mssql")]
+        SqlFamily::Mssql => query.push_str(&format!("@P{}", position)),
+        #[allow(unreachable_patterns)]
+        _ => query.push('?'),
+    }
+}
+
+/// The opening and the closing of a string literal, quoted identifier or
+/// comment starting the string, if any. The openings and closings are ASCII.
+fn skipped_region(family: SqlFamily, rest: &str) -> Option<(&str, String)> {
+    if rest.starts_with("--") {
+        return Some((&rest[..2], String::from("\n")));
+    }
+
+    if rest.starts_with("/*") {
+        return Some((&rest[..2], String::from("*/")));
+    }
+
+    match rest.chars().next()? {
+        c @ '\'' | c @ '"' | c @ '`' => Some((&rest[..1], c.to_string())),
+        '[' if family.is_mssql() => Some((&rest[..1], String::from("]"))),
+        '#' if family.is_mysql() => Some((&rest[..1], String::from("\n"))),
+        '$' if family.is_postgres() => dollar_quote(rest).map(|tag| (tag, tag.to_string())),
+        _ => None,
+    }
+}
+
+/// The `$tag$` opening a PostgreSQL dollar-quoted string, if any. Positional
+/// parameters such as `$1` are not tags.
+fn dollar_quote(rest: &str) -> Option<&str> {
+    let end = rest[1..].find(|c: char| !is_name_char(c))? + 1;
+    let tag = &rest[1..end];
+
+    let valid = tag.chars().next().map(is_name_start).unwrap_or(true);
+
+    if valid && rest[end..].starts_with('$') {
+        Some(&rest[..=end])
+    } else {
+        None
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> HashMap<&'static str, Value<'static>> {
+        let mut params = HashMap::new();
+        params.insert("id", Value::from(1));
+        params.insert("name", Value::from("Musti"));
+        params
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_repeated_parameter_is_bound_once() {
+        let sql = "SELECT * FROM cats WHERE name = :name AND id = :id OR nick = :name";
+        let (sql, params) = named_to_positional(SqlFamily::Postgres, sql, &params()).unwrap();
+
+        assert_eq!("SELECT * FROM cats WHERE name = $1 AND id = $2 OR nick = $1", sql);
+        assert_eq!(vec![Value::from("Musti"), Value::from(1)], params);
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_casts_and_literals_are_not_parameters() {
+        let sql = "SELECT :id::int4, ':name', \":name\" FROM cats";
+        let (sql, params) = named_to_positional(SqlFamily::Postgres, sql, &params()).unwrap();
+
+        assert_eq!("SELECT $1::int4, ':name', \":name\" FROM cats", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_comments_are_not_parameters() {
+        let sql = "SELECT :id -- WHERE name = :name\nFROM cats /* :name */";
+        let (sql, params) = named_to_positional(SqlFamily::Postgres, sql, &params()).unwrap();
+
+        assert_eq!("SELECT $1 -- WHERE name = :name\nFROM cats /* :name */", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_dollar_quoted_strings_are_not_parameters() {
+        let sql = "SELECT $$:name$$, $body$ ':name' $body$, :id";
+        let (sql, params) = named_to_positional(SqlFamily::Postgres, sql, &params()).unwrap();
+
+        assert_eq!("SELECT $$:name$$, $body$ ':name' $body$, $1", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn mysql_repeated_parameter_is_bound_twice() {
+        let sql = "SELECT * FROM cats WHERE name = :name AND id = :id OR nick = :name";
+        let (sql, params) = named_to_positional(SqlFamily::Mysql, sql, &params()).unwrap();
+
+        assert_eq!("SELECT * FROM cats WHERE name = ? AND id = ? OR nick = ?", sql);
+        assert_eq!(vec![Value::from("Musti"), Value::from(1), Value::from("Musti")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "mssql")]
+    fn mssql_repeated_parameter_is_bound_once() {
+        let sql = "SELECT * FROM [cats:name] WHERE id = :id AND name = :name AND nick = :name";
+        let (sql, params) = named_to_positional(SqlFamily::Mssql, sql, &params()).unwrap();
+
+        assert_eq!(
+            "SELECT * FROM [cats:name] WHERE id = @P1 AND name = @P2 AND nick = @P2",
+            sql
+        );
+        assert_eq!(vec![Value::from(1), Value::from("Musti")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "sqlite")]
+    fn missing_parameter_is_an_error() {
+        let err = named_to_positional(SqlFamily::Sqlite, "SELECT :age", &params()).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn mysql_comments_are_not_parameters() {
+        let sql = "SELECT :id # :name\nFROM cats -- :name\n/* :name */";
+        let (sql, params) = named_to_positional(SqlFamily::Mysql, sql, &params()).unwrap();
+
+        assert_eq!("SELECT ? # :name\nFROM cats -- :name\n/* :name */", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+}
@@ -8,7 +8,10 @@ use crate::{
     visitor::{self, Visitor},
 };
 use async_trait::async_trait;
-use futures::{future::FutureExt, lock::Mutex};
+use futures::{
+    future::{self, FutureExt},
+    lock::Mutex,
+};
 use lru_cache::LruCache;
 use native_tls::{Certificate, Identity, TlsConnector};
 use percent_encoding::percent_decode;
@@ -16,9 +19,13 @@ use postgres_native_tls::MakeTlsConnector;
 use std::{
     borrow::{Borrow, Cow},
     fs,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     time::Duration,
 };
-use tokio_postgres::{config::SslMode, Client, Config, Statement};
+use tokio_postgres::{config::SslMode, types::Type as PostgresType, Client, Config, Statement};
 use url::Url;
 
 pub(crate) const DEFAULT_SCHEMA: &str = "public";
@@ -48,6 +55,7 @@ pub struct PostgreSql {
     pg_bouncer: bool,
     socket_timeout: Option<Duration>,
     statement_cache: Mutex<LruCache<String, Statement>>,
+    trim_char_columns: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -235,6 +243,11 @@ impl PostgresUrl {
         self.query_params.socket_timeout
     }
 
+    /// Whether the trailing spaces are removed from `CHAR(n)` values.
+    pub fn trim_char_columns(&self) -> bool {
+        self.query_params.trim_char_columns
+    }
+
     pub(crate) fn cache(&self) -> LruCache<String, Statement> {
         if self.query_params.pg_bouncer {
             LruCache::new(0)
@@ -245,6 +258,7 @@ impl PostgresUrl {
 
     fn parse_query_params(url: &Url) -> Result<PostgresUrlQueryParams, Error> {
         let mut connection_limit = None;
+        let mut pool_min_idle = None;
         let mut schema = String::from(DEFAULT_SCHEMA);
         let mut certificate_file = None;
         let mut identity_file = None;
@@ -257,6 +271,7 @@ impl PostgresUrl {
         let mut pool_timeout = Some(Duration::from_secs(5));
         let mut pg_bouncer = false;
         let mut statement_cache_size = 500;
+        let mut trim_char_columns = false;
 
         for (k, v) in url.query_pairs() {
             match k.as_ref() {
@@ -292,6 +307,11 @@ impl PostgresUrl {
                         .parse()
                         .map_err(|_| Error::builder(ErrorKind::InvalidConnectionArguments).build())?;
                 }
+                "trim_char_columns" => {
+                    trim_char_columns = v
+                        .parse()
+                        .map_err(|_| Error::builder(ErrorKind::InvalidConnectionArguments).build())?;
+                }
                 "sslaccept" => {
                     match v.as_ref() {
                         "strict" => {
@@ -322,6 +342,12 @@ impl PostgresUrl {
                         .map_err(|_| Error::builder(ErrorKind::InvalidConnectionArguments).build())?;
                     connection_limit = Some(as_int);
                 }
+                "pool_min_idle" => {
+                    let as_int: usize = v
+                        .parse()
+                        .map_err(|_| Error::builder(ErrorKind::InvalidConnectionArguments).build())?;
+                    pool_min_idle = Some(as_int);
+                }
                 "host" => {
                     host = Some(v.to_string());
                 }
@@ -370,6 +396,7 @@ impl PostgresUrl {
                 identity_password: Hidden(identity_password),
             },
             connection_limit,
+            pool_min_idle,
             schema,
             ssl_mode,
             host,
@@ -378,6 +405,7 @@ impl PostgresUrl {
             socket_timeout,
             pg_bouncer,
             statement_cache_size,
+            trim_char_columns,
         })
     }
 
@@ -390,6 +418,11 @@ impl PostgresUrl {
         self.query_params.connection_limit
     }
 
+    #[cfg(feature = "pooled")]
+    pub(crate) fn pool_min_idle(&self) -> Option<usize> {
+        self.query_params.pool_min_idle
+    }
+
     pub(crate) fn to_config(&self) -> Config {
         let mut config = Config::new();
 
@@ -414,6 +447,7 @@ impl PostgresUrl {
 pub(crate) struct PostgresUrlQueryParams {
     ssl_params: SslParams,
     connection_limit: Option<usize>,
+    pool_min_idle: Option<usize>,
     schema: String,
     ssl_mode: SslMode,
     pg_bouncer: bool,
@@ -422,6 +456,7 @@ pub(crate) struct PostgresUrlQueryParams {
     connect_timeout: Option<Duration>,
     pool_timeout: Option<Duration>,
     statement_cache_size: usize,
+    trim_char_columns: bool,
 }
 
 impl PostgreSql {
@@ -484,7 +519,77 @@ impl PostgreSql {
             socket_timeout: url.query_params.socket_timeout,
             pg_bouncer: url.query_params.pg_bouncer,
             statement_cache: Mutex::new(url.cache()),
+            trim_char_columns: url.query_params.trim_char_columns,
+        })
+    }
+
+    /// Runs the queries pipelined, sending all of them before reading the
+    /// results. The result sets are returned in the order of the queries.
+    ///
+    /// Only the executions are pipelined. Statements missing from the
+    /// statement cache are prepared first, one round-trip each.
+    ///
+    /// The queries are not run in a transaction, a failing query does not
+    /// roll back the others.
+    pub async fn pipeline(&self, queries: Vec<Query<'_>>) -> crate::Result<Vec<ResultSet>> {
+        let mut built = Vec::with_capacity(queries.len());
+
+        for query in queries {
+            built.push(visitor::Postgres::build(query)?);
+        }
+
+        let sql = built.iter().map(|(sql, _)| sql.as_str()).collect::<Vec<_>>().join("; ");
+
+        metrics::query("postgres.pipeline", &sql, &[], move || async move {
+            let mut statements = Vec::with_capacity(built.len());
+
+            for (sql, params) in built.iter() {
+                let stmt = self.fetch_cached(sql).await?;
+
+                if stmt.params().len() != params.len() {
+                    let kind = ErrorKind::IncorrectNumberOfParameters {
+                        expected: stmt.params().len(),
+                        actual: params.len(),
+                    };
+
+                    return Err(Error::builder(kind).build());
+                }
+
+                statements.push(stmt);
+            }
+
+            // The client sends a query when its future is first polled, so
+            // polling all of them together pipelines the queries.
+            let queries = statements
+                .iter()
+                .zip(built.iter())
+                .map(|(stmt, (_, params))| async move {
+                    let params = conversion::conv_params(params);
+                    self.client.0.query(stmt, params.as_slice()).await
+                });
+
+            let results = super::timeout::socket(self.socket_timeout, future::try_join_all(queries)).await?;
+            let mut result_sets = Vec::with_capacity(results.len());
+
+            for (stmt, rows) in statements.iter().zip(results) {
+                let mut result = ResultSet::new(stmt.to_column_names(), Vec::new());
+
+                for row in rows {
+                    let mut values = row.get_result_row()?;
+
+                    if self.trim_char_columns {
+                        trim_char_values(stmt, &mut values);
+                    }
+
+                    result.rows.push(values);
+                }
+
+                result_sets.push(result);
+            }
+
+            Ok(result_sets)
         })
+        .await
     }
 
     async fn fetch_cached(&self, sql: &str) -> crate::Result<Statement> {
@@ -543,6 +648,24 @@ impl PostgreSql {
     }
 }
 
+/// Numbers the cursors of `query_raw_in_batches`, keeping their names unique
+/// in a connection.
+static CURSOR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// `CHAR(n)` values are padded with spaces to the width of the column.
+fn trim_char_values(stmt: &Statement, row: &mut [Value<'_>]) {
+    for (column, value) in stmt.columns().iter().zip(row.iter_mut()) {
+        if column.type_() != &PostgresType::BPCHAR {
+            continue;
+        }
+
+        if let Value::Text(Some(text)) = value {
+            let len = text.trim_end_matches(' ').len();
+            text.to_mut().truncate(len);
+        }
+    }
+}
+
 impl TransactionCapable for PostgreSql {}
 
 #[async_trait]
@@ -579,7 +702,13 @@ impl Queryable for PostgreSql {
             let mut result = ResultSet::new(stmt.to_column_names(), Vec::new());
 
             for row in rows {
-                result.rows.push(row.get_result_row()?);
+                let mut values = row.get_result_row()?;
+
+                if self.trim_char_columns {
+                    trim_char_values(&stmt, &mut values);
+                }
+
+                result.rows.push(values);
             }
 
             Ok(result)
@@ -587,6 +716,87 @@ impl Queryable for PostgreSql {
         .await
     }
 
+    async fn query_raw_in_batches(
+        &self,
+        sql: &str,
+        params: &[Value<'_>],
+        fetch_size: usize,
+        on_batch: &mut (dyn FnMut(ResultSet) -> crate::Result<()> + Send),
+    ) -> crate::Result<()> {
+        check_fetch_size(fetch_size)?;
+
+        metrics::query("postgres.query_raw_in_batches", sql, params, move || async move {
+            let cursor = format!("quaint_cursor_{}", CURSOR_COUNTER.fetch_add(1, Ordering::Relaxed));
+
+            // A holdable cursor can be declared in and outside of a
+            // transaction. It stays open until closed.
+            let declare = format!("DECLARE {} NO SCROLL CURSOR WITH HOLD FOR {}", cursor, sql);
+            let declare = super::timeout::socket(self.socket_timeout, self.client.0.prepare(&declare)).await?;
+
+            if declare.params().len() != params.len() {
+                let kind = ErrorKind::IncorrectNumberOfParameters {
+                    expected: declare.params().len(),
+                    actual: params.len(),
+                };
+
+                return Err(Error::builder(kind).build());
+            }
+
+            super::timeout::socket(
+                self.socket_timeout,
+                self.client
+                    .0
+                    .execute(&declare, conversion::conv_params(params).as_slice()),
+            )
+            .await?;
+
+            let fetch_batches = async {
+                let fetch = format!("FETCH {} FROM {}", fetch_size, cursor);
+                let fetch = super::timeout::socket(self.socket_timeout, self.client.0.prepare(&fetch)).await?;
+                let columns = Arc::new(fetch.to_column_names());
+
+                loop {
+                    let rows = super::timeout::socket(self.socket_timeout, self.client.0.query(&fetch, &[])).await?;
+                    let fetched = rows.len();
+
+                    if fetched == 0 {
+                        break;
+                    }
+
+                    let mut batch = Vec::with_capacity(fetched);
+
+                    for row in rows {
+                        let mut values = row.get_result_row()?;
+
+                        if self.trim_char_columns {
+                            trim_char_values(&fetch, &mut values);
+                        }
+
+                        batch.push(values);
+                    }
+
+                    on_batch(ResultSet::with_shared_columns(columns.clone(), batch))?;
+
+                    if fetched < fetch_size {
+                        break;
+                    }
+                }
+
+                crate::Result::<()>::Ok(())
+            };
+
+            let result = fetch_batches.await;
+
+            // After a failure in a transaction, closing fails for the same
+            // reason and the rollback drops the cursor.
+            let closed = self.raw_cmd(&format!("CLOSE {}", cursor)).await;
+
+            result?;
+            closed
+        })
+        .await
+    }
+
     async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<u64> {
         metrics::query("postgres.execute_raw", sql, params, move || async move {
             let stmt = self.fetch_cached(sql).await?;
@@ -620,6 +830,17 @@ impl Queryable for PostgreSql {
         .await
     }
 
+    async fn validate(&self, q: Query<'_>) -> crate::Result<()> {
+        let (sql, params) = visitor::Postgres::build(q)?;
+        let sql = sql.as_str();
+
+        metrics::query("postgres.validate", sql, &params, move || async move {
+            self.fetch_cached(sql).await?;
+            Ok(())
+        })
+        .await
+    }
+
     async fn version(&self) -> crate::Result<Option<String>> {
         let query = r#"SELECT version()"#;
         let rows = self.query_raw(query, &[]).await?;
@@ -631,6 +852,65 @@ impl Queryable for PostgreSql {
         Ok(version_string)
     }
 
+    async fn query_with_deadline(&self, q: Query<'_>, deadline: Duration) -> crate::Result<ResultSet> {
+        // The timeout is set with `SET LOCAL`, so it needs a transaction to
+        // not outlive the query.
+        let tx = self.start_transaction().await?;
+
+        match tx.query_with_deadline(q, deadline).await {
+            Ok(result) => {
+                tx.commit().await?;
+                Ok(result)
+            }
+            Err(err) => {
+                // The rollback error would hide the cancellation.
+                let _ = tx.rollback().await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn query_with_deadline_in_transaction(&self, q: Query<'_>, deadline: Duration) -> crate::Result<ResultSet> {
+        // A zero `statement_timeout` would disable the timeout.
+        let millis = deadline.as_millis().max(1).to_string();
+
+        // `set_config` with `true` is the same as `SET LOCAL`.
+        let sql = "SELECT current_setting('statement_timeout') AS previous, \
+                   set_config('statement_timeout', $1, true)";
+
+        let row = self.query_raw(sql, &[Value::text(millis)]).await?.into_single()?;
+        let previous = row["previous"].to_string().unwrap_or_else(|| String::from("0"));
+
+        let result = self.query(q).await?;
+
+        // The rest of the transaction runs with the previous timeout.
+        let sql = "SELECT set_config('statement_timeout', $1, true)";
+        self.query_raw(sql, &[Value::text(previous)]).await?;
+
+        Ok(result)
+    }
+
+    async fn advisory_lock(&self, key: i64) -> crate::Result<()> {
+        self.execute_raw("SELECT pg_advisory_lock($1)", &[Value::integer(key)])
+            .await?;
+
+        Ok(())
+    }
+
+    async fn try_advisory_lock(&self, key: i64) -> crate::Result<bool> {
+        let sql = "SELECT pg_try_advisory_lock($1) AS locked";
+        let row = self.query_raw(sql, &[Value::integer(key)]).await?.into_single()?;
+
+        Ok(row["locked"].as_bool().unwrap_or(false))
+    }
+
+    async fn advisory_unlock(&self, key: i64) -> crate::Result<bool> {
+        let sql = "SELECT pg_advisory_unlock($1) AS unlocked";
+        let row = self.query_raw(sql, &[Value::integer(key)]).await?.into_single()?;
+
+        Ok(row["unlocked"].as_bool().unwrap_or(false))
+    }
+
     async fn server_reset_query(&self, tx: &Transaction<'_>) -> crate::Result<()> {
         if self.pg_bouncer {
             tx.raw_cmd("DEALLOCATE ALL").await
@@ -700,6 +980,71 @@ mod tests {
         assert_eq!(Some("\"musti-test\""), row[0].as_str());
     }
 
+    #[tokio::test]
+    async fn advisory_locks_exclude_other_sessions() {
+        let first = Quaint::new(&CONN_STR).await.unwrap();
+        let second = Quaint::new(&CONN_STR).await.unwrap();
+
+        first.advisory_lock(8_675_309).await.unwrap();
+
+        assert!(!second.try_advisory_lock(8_675_309).await.unwrap());
+        assert!(first.advisory_unlock(8_675_309).await.unwrap());
+
+        assert!(second.try_advisory_lock(8_675_309).await.unwrap());
+        assert!(second.advisory_unlock(8_675_309).await.unwrap());
+        assert!(!second.advisory_unlock(8_675_309).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn pipeline_returns_the_results_in_order() {
+        use crate::ast::*;
+
+        let url = PostgresUrl::new(Url::parse(&CONN_STR).unwrap()).unwrap();
+        let conn = PostgreSql::new(url).await.unwrap();
+
+        let queries = vec![
+            Query::from(Select::default().value(Expression::from("one").alias("a"))),
+            Query::from(Select::default().value(Expression::from("two").alias("b"))),
+            Query::from(Select::default().value(Expression::from("three").alias("c"))),
+        ];
+
+        let results = conn.pipeline(queries).await.unwrap();
+
+        assert_eq!(3, results.len());
+        assert_eq!(Some("one"), results[0].first().unwrap()["a"].as_str());
+        assert_eq!(Some("two"), results[1].first().unwrap()["b"].as_str());
+        assert_eq!(Some("three"), results[2].first().unwrap()["c"].as_str());
+    }
+
+    #[tokio::test]
+    async fn trim_char_columns() {
+        let read_padded = |trim: bool| async move {
+            let mut url = Url::parse(&CONN_STR).unwrap();
+            url.query_pairs_mut()
+                .append_pair("trim_char_columns", &trim.to_string());
+
+            let client = Quaint::new(url.as_str()).await.unwrap();
+
+            client
+                .raw_cmd("CREATE TEMPORARY TABLE char_test (value CHAR(6))")
+                .await
+                .unwrap();
+
+            client
+                .raw_cmd("INSERT INTO char_test (value) VALUES ('abc')")
+                .await
+                .unwrap();
+
+            let result_set = client.query_raw("SELECT value FROM char_test", &[]).await.unwrap();
+            let row = result_set.first().unwrap();
+
+            row[0].to_string()
+        };
+
+        assert_eq!(Some(String::from("abc")), read_padded(true).await);
+        assert_eq!(Some(String::from("abc   ")), read_padded(false).await);
+    }
+
     #[tokio::test]
     async fn should_map_nonexisting_database_error() {
         let mut url = Url::parse(&CONN_STR).unwrap();
@@ -777,4 +1122,29 @@ mod tests {
             },
         }
     }
+
+    #[tokio::test]
+    async fn query_deadline_does_not_outlive_the_query() {
+        use crate::ast::Select;
+
+        let conn = Quaint::new(&CONN_STR).await.unwrap();
+        let tx = conn.start_transaction().await.unwrap();
+
+        tx.raw_cmd("SET LOCAL statement_timeout = 0").await.unwrap();
+
+        let deadline = Duration::from_secs(5);
+        tx.query_with_deadline(Select::default().value(1).into(), deadline)
+            .await
+            .unwrap();
+
+        let row = tx
+            .query_raw("SHOW statement_timeout", &[])
+            .await
+            .unwrap()
+            .into_single()
+            .unwrap();
+        assert_eq!(Some("0"), row[0].as_str());
+
+        tx.commit().await.unwrap();
+    }
 }
@@ -226,6 +226,19 @@ impl From<tokio_postgres::error::Error> for Error {
 
                 builder.build()
             }
+            Some(code) if code == "57014" => {
+                let code = code.to_string();
+                let db_error = e.into_source().and_then(|e| e.downcast::<DbError>().ok());
+
+                let mut builder = Error::builder(ErrorKind::QueryCanceled);
+                builder.set_original_code(code);
+
+                if let Some(db_error) = db_error {
+                    builder.set_original_message(db_error.message());
+                }
+
+                builder.build()
+            }
             code => {
                 // This is necessary, on top of the other conversions, for the cases where a
                 // native_tls error comes wrapped in a tokio_postgres error.
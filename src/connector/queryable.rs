@@ -1,6 +1,11 @@
 use super::{ResultSet, Transaction};
-use crate::ast::*;
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+    visitor::PreparedQuery,
+};
 use async_trait::async_trait;
+use std::time::Duration;
 
 pub trait GetRow {
     fn get_result_row(&self) -> crate::Result<Vec<Value<'static>>>;
@@ -14,6 +19,17 @@ pub trait ToColumnNames {
     fn to_column_names(&self) -> Vec<String>;
 }
 
+/// The result of [`Queryable::execute_and_return`].
+///
+/// [`Queryable::execute_and_return`]: trait.Queryable.html#method.execute_and_return
+#[derive(Debug)]
+pub struct ExecuteResult {
+    /// The number of rows the statement affected.
+    pub affected: u64,
+    /// The rows the statement returned.
+    pub rows: ResultSet,
+}
+
 /// Represents a connection or a transaction that can be queried.
 #[async_trait]
 pub trait Queryable: Send + Sync {
@@ -23,6 +39,36 @@ pub trait Queryable: Send + Sync {
     /// Execute a query given as SQL, interpolating the given parameters.
     async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<ResultSet>;
 
+    /// Execute a query given as SQL, interpolating the given parameters and
+    /// handing the result rows to `on_batch` in sets of at most `fetch_size`
+    /// rows.
+    ///
+    /// PostgreSQL reads the rows with `FETCH` from a cursor and MySQL from
+    /// the connection as they're needed, so the whole result is never kept in
+    /// memory. Other databases load the full result first and split it into
+    /// batches.
+    ///
+    /// A `fetch_size` of zero fails with an `InvalidQuery` error.
+    async fn query_raw_in_batches(
+        &self,
+        sql: &str,
+        params: &[Value<'_>],
+        fetch_size: usize,
+        on_batch: &mut (dyn FnMut(ResultSet) -> crate::Result<()> + Send),
+    ) -> crate::Result<()> {
+        check_fetch_size(fetch_size)?;
+
+        let result_set = self.query_raw(sql, params).await?;
+        let mut rows = result_set.rows.into_iter().peekable();
+
+        while rows.peek().is_some() {
+            let batch = rows.by_ref().take(fetch_size).collect();
+            on_batch(ResultSet::with_shared_columns(result_set.columns.clone(), batch))?;
+        }
+
+        Ok(())
+    }
+
     /// Execute the given query, returning the number of affected rows.
     async fn execute(&self, q: Query<'_>) -> crate::Result<u64>;
 
@@ -30,6 +76,21 @@ pub trait Queryable: Send + Sync {
     /// returning the number of affected rows.
     async fn execute_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<u64>;
 
+    /// Execute a query built with `Visitor::prepare` using the given
+    /// parameters instead of the ones it was built with, returning the
+    /// number of affected rows.
+    async fn execute_prepared(&self, query: &PreparedQuery<'_>, params: &[Value<'_>]) -> crate::Result<u64> {
+        query.validate(params)?;
+        self.execute_raw(query.sql(), params).await
+    }
+
+    /// Execute a query built with `Visitor::prepare` using the given
+    /// parameters instead of the ones it was built with.
+    async fn query_prepared(&self, query: &PreparedQuery<'_>, params: &[Value<'_>]) -> crate::Result<ResultSet> {
+        query.validate(params)?;
+        self.query_raw(query.sql(), params).await
+    }
+
     /// Run a command in the database, for queries that can't be run using
     /// prepared statements.
     async fn raw_cmd(&self, cmd: &str) -> crate::Result<()>;
@@ -55,12 +116,120 @@ pub trait Queryable: Send + Sync {
         self.execute(q.into()).await
     }
 
+    /// Execute an `UPDATE` query, returning the rows defined in its
+    /// `RETURNING` clause.
+    async fn update_returning(&self, q: Update<'_>) -> crate::Result<ResultSet> {
+        self.query(q.into()).await
+    }
+
+    /// Execute an `INSERT` or `UPDATE` with a `RETURNING` clause, giving both
+    /// the number of affected rows and the returned rows.
+    ///
+    /// The number of affected rows is the number of returned rows, so other
+    /// queries fail with an `InvalidQuery` error instead of reporting zero
+    /// affected rows.
+    async fn execute_and_return(&self, q: Query<'_>) -> crate::Result<ExecuteResult> {
+        let has_returning = match &q {
+            Query::Insert(insert) => insert.returning.is_some(),
+            Query::Update(update) => update.returning.is_some(),
+            _ => false,
+        };
+
+        if !has_returning {
+            let msg = "Only an INSERT or UPDATE with a RETURNING clause can return its affected rows.";
+            return Err(Error::builder(ErrorKind::InvalidQuery(msg.into())).build());
+        }
+
+        let rows = self.query(q).await?;
+
+        Ok(ExecuteResult {
+            affected: rows.len() as u64,
+            rows,
+        })
+    }
+
     /// Execute a `DELETE` query, returning the number of affected rows.
     async fn delete(&self, q: Delete<'_>) -> crate::Result<()> {
         self.query(q.into()).await?;
         Ok(())
     }
 
+    /// Checks the query is valid by preparing it in the database without
+    /// running it, catching errors such as misspelled tables or columns.
+    /// Supported on PostgreSQL, MySQL and SQLite.
+    async fn validate(&self, _: Query<'_>) -> crate::Result<()> {
+        let msg = "Validating a query without running it is not supported in this database.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    /// Call a stored procedure with the given parameters, returning all the
+    /// result sets it produces. Currently supported only on MySQL.
+    async fn call_procedure(&self, _: &str, _: &[Value<'_>]) -> crate::Result<Vec<ResultSet>> {
+        let msg = "Fetching the result sets of a stored procedure is not supported in this database.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    /// Waits for the session-level advisory lock with the given key. The lock
+    /// is held until released with
+    /// [`advisory_unlock`](#method.advisory_unlock) or until the connection
+    /// is closed. Supported only on PostgreSQL.
+    async fn advisory_lock(&self, _: i64) -> crate::Result<()> {
+        Err(advisory_locks_unsupported())
+    }
+
+    /// Takes the session-level advisory lock with the given key if it's
+    /// free, returning `false` without waiting if it's not. Supported only on
+    /// PostgreSQL.
+    async fn try_advisory_lock(&self, _: i64) -> crate::Result<bool> {
+        Err(advisory_locks_unsupported())
+    }
+
+    /// Releases the session-level advisory lock with the given key,
+    /// returning `false` if the lock was not held. Supported only on
+    /// PostgreSQL.
+    async fn advisory_unlock(&self, _: i64) -> crate::Result<bool> {
+        Err(advisory_locks_unsupported())
+    }
+
+    /// Execute a query, canceling it in the database if it runs longer than
+    /// the given deadline. A canceled query returns
+    /// [`QueryCanceled`](../error/enum.ErrorKind.html#variant.QueryCanceled).
+    /// Supported on PostgreSQL and MySQL.
+    ///
+    /// On PostgreSQL the deadline is a `statement_timeout` set with `SET
+    /// LOCAL`, so outside of a transaction the query runs in a transaction of
+    /// its own. On MySQL the statement is stopped with `KILL QUERY` from
+    /// another connection. If the killed statement doesn't stop within the
+    /// socket timeout, or the deadline when there is none, the connection is
+    /// no longer [healthy](#method.is_healthy) and fails the following
+    /// queries with `ConnectionClosed`.
+    async fn query_with_deadline(&self, _: Query<'_>, _: Duration) -> crate::Result<ResultSet> {
+        let msg = "Query deadlines are not supported in this database.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    /// Like [`query_with_deadline`](#method.query_with_deadline), for a query
+    /// run in a transaction already open in this connection. Called by
+    /// [`Transaction`](struct.Transaction.html).
+    async fn query_with_deadline_in_transaction(&self, q: Query<'_>, deadline: Duration) -> crate::Result<ResultSet> {
+        self.query_with_deadline(q, deadline).await
+    }
+
     /// Execute an arbitrary function in the beginning of each transaction.
     async fn server_reset_query(&self, _: &Transaction<'_>) -> crate::Result<()> {
         Ok(())
@@ -70,6 +239,34 @@ pub trait Queryable: Send + Sync {
     fn begin_statement(&self) -> &'static str {
         "BEGIN"
     }
+
+    /// False if the connection can't be used anymore, such as a MySQL
+    /// connection whose canceled query didn't stop. The pool drops unhealthy
+    /// connections instead of reusing them.
+    fn is_healthy(&self) -> bool {
+        true
+    }
+}
+
+/// Fails with `InvalidQuery` if the batches of `query_raw_in_batches` would
+/// be empty.
+pub(crate) fn check_fetch_size(fetch_size: usize) -> crate::Result<()> {
+    if fetch_size == 0 {
+        let msg = "The fetch size must be at least one.";
+        return Err(Error::builder(ErrorKind::InvalidQuery(msg.into())).build());
+    }
+
+    Ok(())
+}
+
+fn advisory_locks_unsupported() -> Error {
+    let msg = "Advisory locks are not supported in this database.";
+    let kind = ErrorKind::unsupported_feature(msg);
+
+    let mut builder = Error::builder(kind);
+    builder.set_original_message(msg);
+
+    builder.build()
 }
 
 /// A thing that can start a new transaction.
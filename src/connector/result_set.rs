@@ -1,11 +1,14 @@
 mod index;
 mod result_row;
+mod tuples;
 
 pub use index::*;
 pub use result_row::*;
+pub use tuples::*;
 
 use crate::{ast::Value, error::*};
-use std::sync::Arc;
+use indexmap::IndexMap;
+use std::{convert::TryFrom, sync::Arc};
 
 #[cfg(feature = "json")]
 use serde_json::Map;
@@ -28,6 +31,15 @@ impl ResultSet {
         }
     }
 
+    /// A set of rows sharing the column names with another result set.
+    pub(crate) fn with_shared_columns(columns: Arc<Vec<String>>, rows: Vec<Vec<Value<'static>>>) -> Self {
+        Self {
+            columns,
+            rows,
+            last_insert_id: None,
+        }
+    }
+
     #[cfg(any(feature = "sqlite", feature = "mysql"))]
     pub(crate) fn set_last_insert_id(&mut self, id: u64) {
         self.last_insert_id = Some(id);
@@ -39,6 +51,22 @@ impl ResultSet {
         self.last_insert_id
     }
 
+    /// The last id inserted as a signed integer, matching the type of
+    /// `Value::Integer`. Fails with a conversion error if an unsigned id does
+    /// not fit in an `i64`.
+    pub fn last_insert_id_i64(&self) -> crate::Result<Option<i64>> {
+        match self.last_insert_id {
+            Some(id) => match i64::try_from(id) {
+                Ok(id) => Ok(Some(id)),
+                Err(_) => {
+                    let msg = format!("The last insert id {} does not fit in an i64.", id);
+                    Err(Error::builder(ErrorKind::conversion(msg)).build())
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
     /// An iterator of column names.
     pub fn columns(&self) -> &Vec<String> {
         &self.columns
@@ -67,6 +95,93 @@ impl ResultSet {
         })
     }
 
+    /// Converts the rows into maps from the column name to the value, the keys
+    /// in the order of the columns. Useful for serializing the results of
+    /// queries not known at compile time. If two columns share a name, the
+    /// latter value is kept.
+    ///
+    /// ```rust
+    /// # use quaint::{connector::ResultSet, Value};
+    /// let result_set = ResultSet::new(
+    ///     vec!["name".into(), "age".into()],
+    ///     vec![vec![Value::from("Musti"), Value::from(5)]],
+    /// );
+    ///
+    /// let maps = result_set.into_maps();
+    /// let keys: Vec<&str> = maps[0].keys().map(|k| k.as_str()).collect();
+    ///
+    /// assert_eq!(vec!["name", "age"], keys);
+    /// assert_eq!(Some(&Value::from("Musti")), maps[0].get("name"));
+    /// assert_eq!(Some(&Value::from(5)), maps[0].get("age"));
+    /// ```
+    pub fn into_maps(self) -> Vec<IndexMap<String, Value<'static>>> {
+        let columns = self.columns;
+
+        self.rows
+            .into_iter()
+            .map(|row| columns.iter().cloned().zip(row.into_iter()).collect())
+            .collect()
+    }
+
+    /// Converts every row with the given function, stopping at the first
+    /// error.
+    ///
+    /// ```rust
+    /// # use quaint::{connector::ResultSet, error::{Error, ErrorKind}, Value};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let result_set = ResultSet::new(
+    ///     vec!["name".into()],
+    ///     vec![vec![Value::from("Musti")], vec![Value::from("Naukio")]],
+    /// );
+    ///
+    /// let names = result_set.map_rows(|row| {
+    ///     row["name"]
+    ///         .to_string()
+    ///         .ok_or_else(|| Error::builder(ErrorKind::conversion("Not a string")).build())
+    /// })?;
+    ///
+    /// assert_eq!(vec!["Musti", "Naukio"], names);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_rows<T, F>(self, f: F) -> crate::Result<Vec<T>>
+    where
+        F: FnMut(ResultRow) -> crate::Result<T>,
+    {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Folds the rows into a single value, stopping at the first error.
+    pub fn try_fold_rows<B, F>(self, init: B, f: F) -> crate::Result<B>
+    where
+        F: FnMut(B, ResultRow) -> crate::Result<B>,
+    {
+        self.into_iter().try_fold(init, f)
+    }
+
+    /// Converts every row into a tuple by the positions of the values.
+    ///
+    /// ```rust
+    /// # use quaint::{connector::ResultSet, Value};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let result_set = ResultSet::new(
+    ///     vec!["id".into(), "name".into()],
+    ///     vec![vec![Value::from(1), Value::from("Musti")]],
+    /// );
+    ///
+    /// let cats: Vec<(i64, String)> = result_set.into_tuples()?;
+    ///
+    /// assert_eq!(vec![(1, String::from("Musti"))], cats);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_tuples<T>(self) -> crate::Result<Vec<T>>
+    where
+        T: FromResultRow,
+    {
+        self.map_rows(T::from_result_row)
+    }
+
     /// Takes the first row if existing, otherwise returns error.
     pub fn into_single(self) -> crate::Result<ResultRow> {
         match self.into_iter().next() {
@@ -130,3 +245,129 @@ impl From<ResultSet> for serde_json::Value {
         serde_json::Value::Array(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Cat {
+        name: String,
+        age: i64,
+    }
+
+    impl Cat {
+        fn from_row(row: ResultRow) -> crate::Result<Self> {
+            let invalid = |column: &str| {
+                let msg = format!("Invalid value in the column `{}`.", column);
+                Error::builder(ErrorKind::conversion(msg)).build()
+            };
+
+            let name = row["name"].to_string().ok_or_else(|| invalid("name"))?;
+            let age = row["age"].as_i64().ok_or_else(|| invalid("age"))?;
+
+            Ok(Self { name, age })
+        }
+    }
+
+    fn cats(rows: Vec<Vec<Value<'static>>>) -> ResultSet {
+        ResultSet::new(vec!["name".into(), "age".into()], rows)
+    }
+
+    #[test]
+    fn map_rows_converts_every_row() {
+        let result_set = cats(vec![
+            vec![Value::from("Musti"), Value::from(5)],
+            vec![Value::from("Naukio"), Value::from(3)],
+        ]);
+
+        let expected = vec![
+            Cat {
+                name: "Musti".into(),
+                age: 5,
+            },
+            Cat {
+                name: "Naukio".into(),
+                age: 3,
+            },
+        ];
+
+        assert_eq!(expected, result_set.map_rows(Cat::from_row).unwrap());
+    }
+
+    #[test]
+    fn map_rows_stops_at_the_first_error() {
+        let result_set = cats(vec![
+            vec![Value::from("Musti"), Value::from(5)],
+            vec![Value::from("Naukio"), Value::Integer(None)],
+            vec![Value::Text(None), Value::from(1)],
+        ]);
+
+        let mut converted = 0;
+
+        let err = result_set
+            .map_rows(|row| {
+                converted += 1;
+                Cat::from_row(row)
+            })
+            .unwrap_err();
+
+        assert_eq!(2, converted);
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(msg) if msg.contains("`age`")));
+    }
+
+    #[test]
+    fn try_fold_rows_sums_the_rows() {
+        let result_set = cats(vec![
+            vec![Value::from("Musti"), Value::from(5)],
+            vec![Value::from("Naukio"), Value::from(3)],
+        ]);
+
+        let total = result_set
+            .try_fold_rows(0, |total, row| Ok(total + Cat::from_row(row)?.age))
+            .unwrap();
+
+        assert_eq!(8, total);
+    }
+
+    #[test]
+    fn into_tuples_converts_by_position() {
+        let result_set = ResultSet::new(
+            vec!["id".into(), "name".into(), "weight".into()],
+            vec![
+                vec![Value::from(1), Value::from("Musti"), Value::from(4.5f64)],
+                vec![Value::from(2), Value::from("Naukio"), Value::from(3.25f64)],
+            ],
+        );
+
+        let cats: Vec<(i64, String, f64)> = result_set.into_tuples().unwrap();
+
+        assert_eq!(vec![(1, "Musti".into(), 4.5), (2, "Naukio".into(), 3.25)], cats);
+    }
+
+    #[test]
+    fn into_tuples_fails_on_wrong_arity() {
+        let result_set = cats(vec![vec![Value::from("Musti"), Value::from(5)]]);
+        let err = result_set.into_tuples::<(String, i64, f64)>().unwrap_err();
+
+        match err.kind() {
+            ErrorKind::ConversionError(msg) => {
+                assert_eq!("Expected a row of 3 columns, the row has 2 columns (name, age).", msg)
+            }
+            kind => panic!("Unexpected error {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn into_tuples_fails_on_wrong_type() {
+        let result_set = cats(vec![vec![Value::from("Musti"), Value::from("five")]]);
+        let err = result_set.into_tuples::<(String, i64)>().unwrap_err();
+
+        match err.kind() {
+            ErrorKind::ConversionError(msg) => {
+                assert_eq!("Cannot convert the column `age` at position 1: Not an i64", msg)
+            }
+            kind => panic!("Unexpected error {:?}", kind),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     ast::Value,
-    error::{Error, ErrorKind},
+    error::{Error, ErrorKind, Name},
 };
 use std::sync::Arc;
 
@@ -63,6 +63,12 @@ impl ResultRow {
         }
     }
 
+    /// Read the value with the given column name as a boolean. Usage
+    /// documentation in [ResultRowRef](struct.ResultRowRef.html).
+    pub fn get_as_bool(&self, name: &str) -> crate::Result<bool> {
+        value_as_bool(name, self.get(name))
+    }
+
     /// Make a referring [ResultRowRef](struct.ResultRowRef.html).
     pub fn as_ref(&self) -> ResultRowRef {
         ResultRowRef {
@@ -116,4 +122,41 @@ impl<'a> ResultRowRef<'a> {
             None
         }
     }
+
+    /// Read the value with the given column name as a boolean. Integers `0`
+    /// and `1` are read as `false` and `true`, for databases such as MySQL
+    /// storing booleans as `TINYINT(1)`.
+    ///
+    /// Fails with `ColumnNotFound` if the row has no such column, and with
+    /// `ResultTypeMismatch` if the value is not a boolean or is null.
+    ///
+    /// ```
+    /// # use quaint::{connector::*, Value};
+    /// let names = vec!["active".to_string(), "deleted".to_string(), "name".to_string()];
+    /// let rows = vec![vec![Value::boolean(true), Value::integer(0), Value::text("Musti")]];
+    ///
+    /// let result_set = ResultSet::new(names, rows);
+    /// let row = result_set.first().unwrap();
+    ///
+    /// assert!(row.get_as_bool("active").unwrap());
+    /// assert!(!row.get_as_bool("deleted").unwrap());
+    /// assert!(row.get_as_bool("name").is_err());
+    /// ```
+    pub fn get_as_bool(&self, name: &str) -> crate::Result<bool> {
+        value_as_bool(name, self.get(name))
+    }
+}
+
+fn value_as_bool(name: &str, value: Option<&Value<'static>>) -> crate::Result<bool> {
+    let value = value.ok_or_else(|| {
+        let kind = ErrorKind::ColumnNotFound {
+            column: Name::available(name),
+        };
+
+        Error::builder(kind).build()
+    })?;
+
+    value
+        .as_bool()
+        .ok_or_else(|| Error::builder(ErrorKind::ResultTypeMismatch("bool")).build())
 }
@@ -0,0 +1,79 @@
+use super::ResultRow;
+use crate::{
+    ast::Value,
+    error::{Error, ErrorKind},
+};
+use std::convert::TryFrom;
+
+/// A type built from the values of a row by their position. Implemented
+/// for tuples of up to eight values convertible from a `Value`, such as
+/// `i64`, `f64`, `String` and `bool`.
+///
+/// See [ResultSet::into_tuples](struct.ResultSet.html#method.into_tuples).
+pub trait FromResultRow: Sized {
+    /// Converts the row, failing if the number or the types of the values
+    /// do not match.
+    fn from_result_row(row: ResultRow) -> crate::Result<Self>;
+}
+
+fn check_arity(row: &ResultRow, expected: usize) -> crate::Result<()> {
+    if row.values.len() != expected {
+        let msg = format!(
+            "Expected a row of {} columns, the row has {} columns ({}).",
+            expected,
+            row.values.len(),
+            row.columns.join(", ")
+        );
+
+        return Err(Error::builder(ErrorKind::conversion(msg)).build());
+    }
+
+    Ok(())
+}
+
+fn convert<T>(row: &ResultRow, position: usize, value: Value<'static>) -> crate::Result<T>
+where
+    T: TryFrom<Value<'static>, Error = Error>,
+{
+    T::try_from(value).map_err(|err| {
+        let reason = match err.kind() {
+            ErrorKind::ConversionError(reason) => reason.to_string(),
+            kind => kind.to_string(),
+        };
+
+        let msg = format!(
+            "Cannot convert the column `{}` at position {}: {}",
+            row.columns[position], position, reason
+        );
+
+        Error::builder(ErrorKind::conversion(msg)).build()
+    })
+}
+
+macro_rules! tuple_from_result_row {
+    ($len:expr; $($position:tt: $name:ident),+) => {
+        impl<$($name),+> FromResultRow for ($($name,)+)
+        where
+            $($name: TryFrom<Value<'static>, Error = Error>),+
+        {
+            fn from_result_row(mut row: ResultRow) -> crate::Result<Self> {
+                check_arity(&row, $len)?;
+
+                let mut values = std::mem::take(&mut row.values).into_iter();
+
+                Ok(($(
+                    convert::<$name>(&row, $position, values.next().unwrap())?,
+                )+))
+            }
+        }
+    };
+}
+
+tuple_from_result_row!(1; 0: A);
+tuple_from_result_row!(2; 0: A, 1: B);
+tuple_from_result_row!(3; 0: A, 1: B, 2: C);
+tuple_from_result_row!(4; 0: A, 1: B, 2: C, 3: D);
+tuple_from_result_row!(5; 0: A, 1: B, 2: C, 3: D, 4: E);
+tuple_from_result_row!(6; 0: A, 1: B, 2: C, 3: D, 4: E, 5: F);
+tuple_from_result_row!(7; 0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G);
+tuple_from_result_row!(8; 0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H);
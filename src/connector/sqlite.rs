@@ -27,6 +27,7 @@ pub struct Sqlite {
 #[cfg_attr(feature = "docs", doc(cfg(feature = "sqlite")))]
 pub struct SqliteParams {
     pub connection_limit: Option<usize>,
+    pub pool_min_idle: Option<usize>,
     /// This is not a `PathBuf` because we need to `ATTACH` the database to the path, and this can
     /// only be done with UTF-8 paths.
     pub file_path: String,
@@ -52,6 +53,7 @@ impl TryFrom<&str> for SqliteParams {
             Err(Error::builder(ErrorKind::DatabaseUrlIsInvalid(path.to_str().unwrap().to_string())).build())
         } else {
             let mut connection_limit = None;
+            let mut pool_min_idle = None;
             let mut socket_timeout = None;
 
             if path_parts.len() > 1 {
@@ -69,6 +71,13 @@ impl TryFrom<&str> for SqliteParams {
 
                             connection_limit = Some(as_int);
                         }
+                        "pool_min_idle" => {
+                            let as_int: usize = v
+                                .parse()
+                                .map_err(|_| Error::builder(ErrorKind::InvalidConnectionArguments).build())?;
+
+                            pool_min_idle = Some(as_int);
+                        }
                         "socket_timeout" => {
                             let as_int = v
                                 .parse()
@@ -88,6 +97,7 @@ impl TryFrom<&str> for SqliteParams {
 
             Ok(Self {
                 connection_limit,
+                pool_min_idle,
                 file_path: path_str.to_owned(),
                 db_name: DEFAULT_SQLITE_SCHEMA_NAME.to_owned(),
                 socket_timeout,
@@ -184,6 +194,19 @@ impl Queryable for Sqlite {
         .await
     }
 
+    async fn validate(&self, q: Query<'_>) -> crate::Result<()> {
+        let (sql, params) = visitor::Sqlite::build(q)?;
+        let sql = sql.as_str();
+
+        metrics::query("sqlite.validate", sql, &params, move || async move {
+            let client = self.client.lock().await;
+            client.prepare_cached(sql)?;
+
+            Ok(())
+        })
+        .await
+    }
+
     async fn version(&self) -> crate::Result<Option<String>> {
         Ok(Some(rusqlite::version().into()))
     }
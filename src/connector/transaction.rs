@@ -1,6 +1,7 @@
 use super::*;
 use crate::ast::*;
 use async_trait::async_trait;
+use std::{sync::Mutex, time::Duration};
 
 /// A representation of an SQL database transaction. If not commited, a
 /// transaction will be rolled back by default when dropped.
@@ -9,11 +10,15 @@ use async_trait::async_trait;
 /// transaction object will panic.
 pub struct Transaction<'a> {
     pub(crate) inner: &'a dyn Queryable,
+    inserted_ids: Mutex<Vec<u64>>,
 }
 
 impl<'a> Transaction<'a> {
     pub(crate) async fn new(inner: &'a dyn Queryable, begin_stmt: &str) -> crate::Result<Transaction<'a>> {
-        let this = Self { inner };
+        let this = Self {
+            inner,
+            inserted_ids: Mutex::new(Vec::new()),
+        };
 
         inner.raw_cmd(begin_stmt).await?;
         inner.server_reset_query(&this).await?;
@@ -34,6 +39,16 @@ impl<'a> Transaction<'a> {
 
         Ok(())
     }
+
+    /// The ids generated by the inserts executed with
+    /// [`insert`](#method.insert) in this transaction, in the order of the
+    /// inserts. One id is recorded per statement: the first id of a
+    /// multi-row insert on MySQL and the last one on SQLite. PostgreSQL and
+    /// SQL Server don't report generated ids, read them with `RETURNING`
+    /// instead.
+    pub fn inserted_ids(&self) -> Vec<u64> {
+        self.inserted_ids.lock().unwrap().clone()
+    }
 }
 
 #[async_trait]
@@ -46,6 +61,26 @@ impl<'a> Queryable for Transaction<'a> {
         self.inner.execute(q).await
     }
 
+    async fn insert(&self, q: Insert<'_>) -> crate::Result<ResultSet> {
+        let result_set = self.inner.insert(q).await?;
+
+        if let Some(id) = result_set.last_insert_id() {
+            self.inserted_ids.lock().unwrap().push(id);
+        }
+
+        Ok(result_set)
+    }
+
+    async fn query_raw_in_batches(
+        &self,
+        sql: &str,
+        params: &[Value<'_>],
+        fetch_size: usize,
+        on_batch: &mut (dyn FnMut(ResultSet) -> crate::Result<()> + Send),
+    ) -> crate::Result<()> {
+        self.inner.query_raw_in_batches(sql, params, fetch_size, on_batch).await
+    }
+
     async fn query_raw(&self, sql: &str, params: &[Value<'_>]) -> crate::Result<ResultSet> {
         self.inner.query_raw(sql, params).await
     }
@@ -58,7 +93,39 @@ impl<'a> Queryable for Transaction<'a> {
         self.inner.raw_cmd(cmd).await
     }
 
+    async fn validate(&self, q: Query<'_>) -> crate::Result<()> {
+        self.inner.validate(q).await
+    }
+
     async fn version(&self) -> crate::Result<Option<String>> {
         self.inner.version().await
     }
+
+    async fn call_procedure(&self, name: &str, params: &[Value<'_>]) -> crate::Result<Vec<ResultSet>> {
+        self.inner.call_procedure(name, params).await
+    }
+
+    async fn advisory_lock(&self, key: i64) -> crate::Result<()> {
+        self.inner.advisory_lock(key).await
+    }
+
+    async fn try_advisory_lock(&self, key: i64) -> crate::Result<bool> {
+        self.inner.try_advisory_lock(key).await
+    }
+
+    async fn advisory_unlock(&self, key: i64) -> crate::Result<bool> {
+        self.inner.advisory_unlock(key).await
+    }
+
+    async fn query_with_deadline(&self, q: Query<'_>, deadline: Duration) -> crate::Result<ResultSet> {
+        self.inner.query_with_deadline_in_transaction(q, deadline).await
+    }
+
+    async fn query_with_deadline_in_transaction(&self, q: Query<'_>, deadline: Duration) -> crate::Result<ResultSet> {
+        self.inner.query_with_deadline_in_transaction(q, deadline).await
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.inner.is_healthy()
+    }
 }
@@ -167,6 +167,11 @@ pub enum ErrorKind {
     #[error("Error creating a database connection.")]
     ConnectionError(Box<dyn std::error::Error + Send + Sync + 'static>),
 
+    /// The connection was lost or closed by the server. The query can be
+    /// retried with a new connection.
+    #[error("The connection to the database was closed.")]
+    ConnectionClosed,
+
     #[error("Error reading the column value: {}", _0)]
     ColumnReadFailure(Box<dyn std::error::Error + Send + Sync + 'static>),
 
@@ -207,6 +212,9 @@ pub enum ErrorKind {
     #[error("Timed out during query execution.")]
     SocketTimeout,
 
+    #[error("The query was canceled.")]
+    QueryCanceled,
+
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
 
@@ -224,6 +232,21 @@ pub enum ErrorKind {
         actual
     )]
     IncorrectNumberOfParameters { expected: usize, actual: usize },
+
+    #[error("Too many parameters in a query. The limit is {}, got: {}.", limit, got)]
+    TooManyParameters { limit: usize, got: usize },
+
+    #[error("Feature not supported by the database: {}", _0)]
+    UnsupportedFeature(Cow<'static, str>),
+
+    #[error("Invalid identifier: {}", identifier)]
+    InvalidIdentifier { identifier: String },
+
+    #[error("Missing a value for the required column `{}`.", column)]
+    MissingRequiredColumn { column: String },
+
+    #[error("Invalid query: {}", _0)]
+    InvalidQuery(Cow<'static, str>),
 }
 
 impl ErrorKind {
@@ -236,6 +259,11 @@ impl ErrorKind {
         Self::ConversionError(msg.into())
     }
 
+    #[allow(dead_code)]
+    pub(crate) fn unsupported_feature(msg: impl Into<Cow<'static, str>>) -> Self {
+        Self::UnsupportedFeature(msg.into())
+    }
+
     #[allow(dead_code)]
     pub(crate) fn database_url_is_invalid(msg: impl Into<String>) -> Self {
         Self::DatabaseUrlIsInvalid(msg.into())
@@ -0,0 +1,272 @@
+//! Lightweight schema introspection.
+//!
+//! Builds the queries needed to list the tables of a schema, or the columns
+//! of a table, reading the catalog of the underlying database:
+//! `information_schema` on PostgreSQL, MySQL and SQL Server, and
+//! `sqlite_master`/`pragma_table_info` on SQLite.
+//!
+//! The queries are meant for quick checks, such as whether a column exists,
+//! and are not a replacement for a full migration tool.
+//!
+//! ```
+//! # use quaint::{introspect, connector::SqlFamily, visitor::{Visitor, Postgres}, Value};
+//! # fn main() -> Result<(), quaint::error::Error> {
+//! let query = introspect::columns(SqlFamily::Postgres, "public", "users");
+//! let (sql, params) = Postgres::build(query)?;
+//!
+//! assert_eq!(
+//!     "SELECT \"column_name\" AS \"name\", \"data_type\" AS \"data_type\", \
+//!      \"is_nullable\" AS \"is_nullable\", \"column_default\" AS \"column_default\" \
+//!      FROM \"information_schema\".\"columns\" \
+//!      WHERE (\"table_schema\" = $1 AND \"table_name\" = $2) ORDER BY \"ordinal_position\"",
+//!     sql
+//! );
+//!
+//! assert_eq!(vec![Value::from("public"), Value::from("users")], params);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    ast::*,
+    connector::{ResultRow, ResultSet, SqlFamily},
+    error::{Error, ErrorKind},
+};
+use std::convert::TryFrom;
+
+/// A column of a table, read from the database catalog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnInfo {
+    /// The name of the column.
+    pub name: String,
+    /// The data type, as reported by the database.
+    pub data_type: String,
+    /// `true` if the column accepts `NULL` values.
+    pub nullable: bool,
+    /// The default value expression, if any.
+    pub default: Option<String>,
+}
+
+impl ColumnInfo {
+    /// Converts the result of a [`columns`](fn.columns.html) query.
+    pub fn from_result_set(result_set: ResultSet) -> crate::Result<Vec<Self>> {
+        result_set.into_iter().map(Self::try_from).collect()
+    }
+}
+
+impl TryFrom<ResultRow> for ColumnInfo {
+    type Error = Error;
+
+    fn try_from(row: ResultRow) -> crate::Result<Self> {
+        let text = |column: &str| row.get(column).and_then(|v| v.to_string());
+
+        let name = text("name").ok_or_else(|| missing("name"))?;
+        let data_type = text("data_type").ok_or_else(|| missing("data_type"))?;
+        let nullable = text("is_nullable").ok_or_else(|| missing("is_nullable"))?;
+        let default = text("column_default");
+
+        Ok(Self {
+            name,
+            data_type,
+            nullable: nullable.eq_ignore_ascii_case("YES"),
+            default,
+        })
+    }
+}
+
+/// Converts the result of a [`tables`](fn.tables.html) query into a list of
+/// table names.
+pub fn table_names(result_set: ResultSet) -> crate::Result<Vec<String>> {
+    result_set
+        .into_iter()
+        .map(|row| {
+            row.get("name")
+                .and_then(|v| v.to_string())
+                .ok_or_else(|| missing("name"))
+        })
+        .collect()
+}
+
+/// A query listing the names of all tables in the given schema. On MySQL the
+/// schema is the name of the database, on SQLite the name the database was
+/// attached as.
+///
+/// The table names are returned in the `name` column.
+pub fn tables<'a>(family: SqlFamily, schema: &'a str) -> Query<'a> {
+    match family {
+        #[cfg(feature = "sqlite")]
+        SqlFamily::Sqlite => {
+            let conditions = "type".equals("table").and("name".not_begins_with("sqlite_"));
+
+            Select::from_table((schema, "sqlite_master"))
+                .column("name")
+                .so_that(conditions)
+                .order_by("name")
+                .into()
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let ident = catalog_identifier(family);
+            let conditions = Column::from(ident("table_schema"))
+                .equals(schema)
+                .and(Column::from(ident("table_type")).equals("BASE TABLE"));
+
+            Select::from_table((ident("information_schema"), ident("tables")))
+                .column(Column::from(ident("table_name")).alias("name"))
+                .so_that(conditions)
+                .order_by(Column::from(ident("table_name")))
+                .into()
+        }
+    }
+}
+
+/// A query listing the columns of the given table, in the order they were
+/// defined. The rows can be converted with
+/// [`ColumnInfo::from_result_set`](struct.ColumnInfo.html#method.from_result_set).
+pub fn columns<'a>(family: SqlFamily, schema: &'a str, table: &'a str) -> Query<'a> {
+    match family {
+        #[cfg(feature = "sqlite")]
+        SqlFamily::Sqlite => {
+            // `pragma_table_info` is a table-valued function we can't express
+            // with the AST.
+            let sql = format!(
+                "SELECT name, type AS data_type, \
+                 CASE WHEN \"notnull\" = 0 THEN 'YES' ELSE 'NO' END AS is_nullable, \
+                 dflt_value AS column_default \
+                 FROM pragma_table_info('{}', '{}') ORDER BY cid",
+                escape_literal(table),
+                escape_literal(schema),
+            );
+
+            Query::Raw(sql.into())
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let ident = catalog_identifier(family);
+            let conditions = Column::from(ident("table_schema"))
+                .equals(schema)
+                .and(Column::from(ident("table_name")).equals(table));
+
+            Select::from_table((ident("information_schema"), ident("columns")))
+                .column(Column::from(ident("column_name")).alias("name"))
+                .column(Column::from(ident("data_type")).alias("data_type"))
+                .column(Column::from(ident("is_nullable")).alias("is_nullable"))
+                .column(Column::from(ident("column_default")).alias("column_default"))
+                .so_that(conditions)
+                .order_by(Column::from(ident("ordinal_position")))
+                .into()
+        }
+    }
+}
+
+/// The catalog views are upper-case in MySQL and SQL Server, and must be
+/// referred as such with a case-sensitive collation.
+fn catalog_identifier(family: SqlFamily) -> impl Fn(&str) -> String {
+    let upper = family.is_mysql() || family.is_mssql();
+
+    move |name: &str| {
+        if upper {
+            name.to_uppercase()
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn escape_literal(s: &str) -> String {
+    s.replace('\'', "''")
+}
+
+fn missing(column: &str) -> Error {
+    let msg = format!("Introspection result is missing the `{}` column.", column);
+    Error::builder(ErrorKind::conversion(msg)).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use crate::visitor::Visitor;
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_tables() {
+        let (sql, params) = crate::visitor::Postgres::build(tables(SqlFamily::Postgres, "public")).unwrap();
+
+        assert_eq!(
+            "SELECT \"table_name\" AS \"name\" FROM \"information_schema\".\"tables\" \
+             WHERE (\"table_schema\" = $1 AND \"table_type\" = $2) ORDER BY \"table_name\"",
+            sql
+        );
+        assert_eq!(vec![Value::from("public"), Value::from("BASE TABLE")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "postgresql")]
+    fn postgres_columns() {
+        let (sql, params) = crate::visitor::Postgres::build(columns(SqlFamily::Postgres, "public", "users")).unwrap();
+
+        assert_eq!(
+            "SELECT \"column_name\" AS \"name\", \"data_type\" AS \"data_type\", \
+             \"is_nullable\" AS \"is_nullable\", \"column_default\" AS \"column_default\" \
+             FROM \"information_schema\".\"columns\" \
+             WHERE (\"table_schema\" = $1 AND \"table_name\" = $2) ORDER BY \"ordinal_position\"",
+            sql
+        );
+        assert_eq!(vec![Value::from("public"), Value::from("users")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "mysql")]
+    fn mysql_columns() {
+        let (sql, params) = crate::visitor::Mysql::build(columns(SqlFamily::Mysql, "db", "users")).unwrap();
+
+        assert_eq!(
+            "SELECT `COLUMN_NAME` AS `name`, `DATA_TYPE` AS `data_type`, `IS_NULLABLE` AS `is_nullable`, \
+             `COLUMN_DEFAULT` AS `column_default` FROM `INFORMATION_SCHEMA`.`COLUMNS` \
+             WHERE (`TABLE_SCHEMA` = ? AND `TABLE_NAME` = ?) ORDER BY `ORDINAL_POSITION`",
+            sql
+        );
+        assert_eq!(vec![Value::from("db"), Value::from("users")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "mssql")]
+    fn mssql_columns() {
+        let (sql, params) = crate::visitor::Mssql::build(columns(SqlFamily::Mssql, "dbo", "users")).unwrap();
+
+        assert_eq!(
+            "SELECT [COLUMN_NAME] AS [name], [DATA_TYPE] AS [data_type], [IS_NULLABLE] AS [is_nullable], \
+             [COLUMN_DEFAULT] AS [column_default] FROM [INFORMATION_SCHEMA].[COLUMNS] \
+             WHERE ([TABLE_SCHEMA] = @P1 AND [TABLE_NAME] = @P2) ORDER BY [ORDINAL_POSITION]",
+            sql
+        );
+        assert_eq!(vec![Value::from("dbo"), Value::from("users")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "sqlite")]
+    fn sqlite_tables() {
+        let (sql, params) = crate::visitor::Sqlite::build(tables(SqlFamily::Sqlite, "main")).unwrap();
+
+        assert_eq!(
+            "SELECT `name` FROM `main`.`sqlite_master` WHERE (`type` = ? AND `name` NOT LIKE ?) ORDER BY `name`",
+            sql
+        );
+        assert_eq!(vec![Value::from("table"), Value::from("sqlite_%")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "sqlite")]
+    fn sqlite_columns_escapes_the_table_name() {
+        let (sql, params) = crate::visitor::Sqlite::build(columns(SqlFamily::Sqlite, "main", "it's")).unwrap();
+
+        assert_eq!(
+            "SELECT name, type AS data_type, CASE WHEN \"notnull\" = 0 THEN 'YES' ELSE 'NO' END AS is_nullable, \
+             dflt_value AS column_default FROM pragma_table_info('it''s', 'main') ORDER BY cid",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+}
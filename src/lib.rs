@@ -125,6 +125,7 @@ extern crate bigdecimal_ as bigdecimal;
 pub mod ast;
 pub mod connector;
 pub mod error;
+pub mod introspect;
 #[cfg(feature = "pooled")]
 #[cfg_attr(feature = "docs", doc(cfg(pooled)))]
 pub mod pooled;
@@ -25,6 +25,11 @@
 //!
 //! - `connection_limit` defines the maximum number of connections opened to the
 //!   database.
+//! - `pool_min_idle` defines the number of connections opened in advance by
+//!   [`Quaint::warm_up`], so the first queries do not pay the connection cost.
+//!   The warm-up is a one-shot call: the pool does not open the connections
+//!   on its own, nor replace them when they are closed later.
+//!   `poolMinIdle` in SQL Server connection strings.
 //!
 //! ## SQLite
 //!
@@ -68,6 +73,9 @@
 //! - `statement_cache_size`, number of prepared statements kept cached.
 //!   Defaults to 500, which means caching is off. If `pgbouncer` mode is enabled,
 //!   caching is always off.
+//! - `trim_char_columns` either `true` or `false`. If set, the trailing spaces
+//!   padding the `CHAR(n)` values are removed when reading the results.
+//!   Defaults to `false`.
 //!
 //! ## MySQL
 //!
@@ -88,6 +96,14 @@
 //! - `pool_timeout` defined in seconds. If all connections are in use, the
 //!   database will return a `PoolTimeout` error after waiting for the given time.
 //!   If set to zero, no timeout.
+//! - `session_variable` a `name=value` pair set with `SET` on every new
+//!   connection, such as `time_zone=+00:00`. Can be given multiple times.
+//! - `stmt_cache_size` the number of prepared statements cached per
+//!   connection (default: 1000). If set to 0, the cache is disabled.
+//! - `zero_dates` either `error` (default) or `null`. Defines if dates
+//!   with a zero day or month, such as `0000-00-00`, fail the query or
+//!   are read as `NULL`.
+//! - `charset` the character set of the connection (default: `utf8mb4`).
 //!
 //! ## Microsoft SQL Server
 //!
@@ -130,6 +146,8 @@
 //!     builder.test_on_check_out(true);
 //!
 //!     let pool = builder.build();
+//!     pool.warm_up().await?;
+//!
 //!     let conn = pool.check_out().await?;
 //!     let result = conn.select(Select::default().value(1)).await?;
 //!
@@ -143,17 +161,23 @@
 //! ```
 //!
 //! [`builder`]: struct.Quaint.html#method.builder
+//! [`Quaint::warm_up`]: struct.Quaint.html#method.warm_up
 
 mod manager;
+#[cfg(feature = "mysql")]
+mod replicated;
 
 pub use manager::*;
+#[cfg(feature = "mysql")]
+pub use replicated::*;
 
 use crate::{
-    connector::ConnectionInfo,
+    connector::{ConnectionInfo, Transaction, TransactionCapable},
     error::{Error, ErrorKind},
 };
+use futures::future::{BoxFuture, FutureExt};
 use mobc::Pool;
-use std::{sync::Arc, time::Duration};
+use std::{panic::AssertUnwindSafe, sync::Arc, time::Duration};
 
 #[cfg(feature = "sqlite")]
 use std::convert::TryFrom;
@@ -165,6 +189,7 @@ pub struct Quaint {
     pub(crate) inner: Pool<QuaintManager>,
     connection_info: Arc<ConnectionInfo>,
     pool_timeout: Option<Duration>,
+    min_idle: usize,
 }
 
 /// A `Builder` to construct an instance of a [`Quaint`] pool.
@@ -174,6 +199,7 @@ pub struct Builder {
     manager: QuaintManager,
     connection_info: ConnectionInfo,
     connection_limit: usize,
+    min_idle: Option<usize>,
     max_idle: Option<u64>,
     max_idle_lifetime: Option<Duration>,
     health_check_interval: Option<Duration>,
@@ -191,6 +217,7 @@ impl Builder {
             manager,
             connection_info,
             connection_limit,
+            min_idle: None,
             max_idle: None,
             max_idle_lifetime: None,
             health_check_interval: None,
@@ -207,6 +234,23 @@ impl Builder {
         self.connection_limit = connection_limit;
     }
 
+    /// The number of connections opened in advance when calling
+    /// [`warm_up`] on the pool, kept idle until needed. Limited by
+    /// `connection_limit` and `max_idle`.
+    ///
+    /// The pool does not keep this many connections open by itself. Idle
+    /// connections closed later, for example by `max_idle_lifetime`, are not
+    /// replaced until [`warm_up`] is called again.
+    ///
+    /// - Defaults to not set, meaning connections are only opened on
+    /// [`check_out`].
+    ///
+    /// [`warm_up`]: struct.Quaint.html#method.warm_up
+    /// [`check_out`]: struct.Quaint.html#method.check_out
+    pub fn min_idle(&mut self, min_idle: usize) {
+        self.min_idle = Some(min_idle);
+    }
+
     /// The maximum number of idle connections the pool can contain at the same time. If a
     /// connection goes idle (a query returns) and there are already this number of idle connections
     /// in the pool, a connection will be closed immediately. Consider using `max_idle_lifetime` to
@@ -310,6 +354,7 @@ impl Builder {
             inner,
             connection_info,
             pool_timeout: self.pool_timeout,
+            min_idle: self.min_idle.unwrap_or(0).min(self.connection_limit),
         }
     }
 
@@ -357,12 +402,17 @@ impl Quaint {
                     builder.connection_limit(limit);
                 }
 
+                if let Some(min_idle) = params.pool_min_idle {
+                    builder.min_idle(min_idle);
+                }
+
                 Ok(builder)
             }
             #[cfg(feature = "mysql")]
             s if s.starts_with("mysql") => {
                 let url = crate::connector::MysqlUrl::new(url::Url::parse(s)?)?;
                 let connection_limit = url.connection_limit();
+                let pool_min_idle = url.pool_min_idle();
                 let pool_timeout = url.pool_timeout();
 
                 let manager = QuaintManager::Mysql { url };
@@ -372,6 +422,10 @@ impl Quaint {
                     builder.connection_limit(limit);
                 }
 
+                if let Some(min_idle) = pool_min_idle {
+                    builder.min_idle(min_idle);
+                }
+
                 if let Some(timeout) = pool_timeout {
                     builder.pool_timeout(timeout);
                 }
@@ -382,6 +436,7 @@ impl Quaint {
             s if s.starts_with("postgres") || s.starts_with("postgresql") => {
                 let url = crate::connector::PostgresUrl::new(url::Url::parse(s)?)?;
                 let connection_limit = url.connection_limit();
+                let pool_min_idle = url.pool_min_idle();
                 let pool_timeout = url.pool_timeout();
 
                 let manager = QuaintManager::Postgres { url };
@@ -391,6 +446,10 @@ impl Quaint {
                     builder.connection_limit(limit);
                 }
 
+                if let Some(min_idle) = pool_min_idle {
+                    builder.min_idle(min_idle);
+                }
+
                 if let Some(timeout) = pool_timeout {
                     builder.pool_timeout(timeout);
                 }
@@ -401,6 +460,7 @@ impl Quaint {
             s if s.starts_with("jdbc:sqlserver") || s.starts_with("sqlserver") => {
                 let url = crate::connector::MssqlUrl::new(s)?;
                 let connection_limit = url.connection_limit();
+                let pool_min_idle = url.pool_min_idle();
                 let pool_timeout = url.pool_timeout();
 
                 let manager = QuaintManager::Mssql { url };
@@ -410,6 +470,10 @@ impl Quaint {
                     builder.connection_limit(limit);
                 }
 
+                if let Some(min_idle) = pool_min_idle {
+                    builder.min_idle(min_idle);
+                }
+
                 if let Some(timeout) = pool_timeout {
                     builder.pool_timeout(timeout);
                 }
@@ -425,7 +489,44 @@ impl Quaint {
         self.inner.state().await.max_open as u32
     }
 
+    /// The number of open connections waiting in the pool.
+    pub async fn idle_connections(&self) -> usize {
+        self.inner.state().await.idle as usize
+    }
+
+    /// Opens the connections set with `pool_min_idle` in advance, returning
+    /// them to the pool as idle. Fails if any of the connections cannot be
+    /// opened.
+    ///
+    /// This is a one-shot warm-up, not called by the pool. Call it after
+    /// building the pool, and again to refill the idle connections if they
+    /// might have been closed since.
+    ///
+    /// The connections are returned to the pool in the background, and might
+    /// not be counted in [`idle_connections`] right after the call.
+    ///
+    /// [`idle_connections`]: #method.idle_connections
+    pub async fn warm_up(&self) -> crate::Result<()> {
+        let idle = self.idle_connections().await;
+
+        if idle >= self.min_idle {
+            return Ok(());
+        }
+
+        let check_outs = (idle..self.min_idle).map(|_| self.check_out());
+        let connections = futures::future::try_join_all(check_outs).await?;
+
+        drop(connections);
+
+        Ok(())
+    }
+
     /// Reserve a connection from the pool.
+    ///
+    /// The connection is held until the returned value is dropped, so all
+    /// queries run through it use the same database session. This is needed
+    /// for anything bound to the session without a transaction, such as
+    /// temporary tables, session variables or `LAST_INSERT_ID()`.
     pub async fn check_out(&self) -> crate::Result<PooledConnection> {
         let res = match self.pool_timeout {
             Some(duration) => crate::connector::metrics::check_out(self.inner.get_timeout(duration)).await,
@@ -448,6 +549,58 @@ impl Quaint {
         Ok(PooledConnection { inner })
     }
 
+    /// Runs the closure in a transaction on a connection from the pool,
+    /// committing if it returns `Ok` and rolling back otherwise.
+    ///
+    /// If the closure panics, the transaction is rolled back before the
+    /// panic continues, so the connection returns to the pool without an open
+    /// transaction. A connection failing to roll back is closed instead of
+    /// returned to the pool.
+    ///
+    /// ```no_run
+    /// # use quaint::{ast::*, connector::Queryable, pooled::Quaint};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), quaint::error::Error> {
+    /// let pool = Quaint::builder("postgres://postgres@localhost/test")?.build();
+    ///
+    /// let changed = pool
+    ///     .with_transaction(|tx| {
+    ///         Box::pin(async move {
+    ///             tx.execute(Update::table("cats").set("age", 1).into()).await
+    ///         })
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_transaction<F, T>(&self, f: F) -> crate::Result<T>
+    where
+        F: for<'t> FnOnce(&'t Transaction<'t>) -> BoxFuture<'t, crate::Result<T>>,
+    {
+        let conn = self.check_out().await?;
+        let tx = conn.start_transaction().await?;
+
+        let (rolled_back, result) = match AssertUnwindSafe(f(&tx)).catch_unwind().await {
+            Ok(Ok(value)) => {
+                tx.commit().await?;
+                return Ok(value);
+            }
+            Ok(Err(err)) => (tx.rollback().await.is_ok(), Ok(err)),
+            Err(panic) => (tx.rollback().await.is_ok(), Err(panic)),
+        };
+
+        drop(tx);
+
+        if !rolled_back {
+            conn.evict();
+        }
+
+        match result {
+            Ok(err) => Err(err),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+
     /// Info about the connection and underlying database.
     pub fn connection_info(&self) -> &ConnectionInfo {
         &self.connection_info
@@ -11,6 +11,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use mobc::{Connection as MobcPooled, Manager};
+use std::time::Duration;
 
 /// A connection from the pool. Implements
 /// [Queryable](connector/trait.Queryable.html).
@@ -18,6 +19,13 @@ pub struct PooledConnection {
     pub(crate) inner: MobcPooled<QuaintManager>,
 }
 
+impl PooledConnection {
+    /// Closes the connection instead of returning it to the pool.
+    pub(crate) fn evict(self) {
+        drop(self.inner.into_inner());
+    }
+}
+
 impl TransactionCapable for PooledConnection {}
 
 #[async_trait]
@@ -38,14 +46,56 @@ impl Queryable for PooledConnection {
         self.inner.execute_raw(sql, params).await
     }
 
+    async fn query_raw_in_batches(
+        &self,
+        sql: &str,
+        params: &[ast::Value<'_>],
+        fetch_size: usize,
+        on_batch: &mut (dyn FnMut(connector::ResultSet) -> crate::Result<()> + Send),
+    ) -> crate::Result<()> {
+        self.inner.query_raw_in_batches(sql, params, fetch_size, on_batch).await
+    }
+
     async fn raw_cmd(&self, cmd: &str) -> crate::Result<()> {
         self.inner.raw_cmd(cmd).await
     }
 
+    async fn validate(&self, q: ast::Query<'_>) -> crate::Result<()> {
+        self.inner.validate(q).await
+    }
+
     async fn version(&self) -> crate::Result<Option<String>> {
         self.inner.version().await
     }
 
+    async fn call_procedure(&self, name: &str, params: &[ast::Value<'_>]) -> crate::Result<Vec<connector::ResultSet>> {
+        self.inner.call_procedure(name, params).await
+    }
+
+    async fn advisory_lock(&self, key: i64) -> crate::Result<()> {
+        self.inner.advisory_lock(key).await
+    }
+
+    async fn try_advisory_lock(&self, key: i64) -> crate::Result<bool> {
+        self.inner.try_advisory_lock(key).await
+    }
+
+    async fn advisory_unlock(&self, key: i64) -> crate::Result<bool> {
+        self.inner.advisory_unlock(key).await
+    }
+
+    async fn query_with_deadline(&self, q: ast::Query<'_>, deadline: Duration) -> crate::Result<connector::ResultSet> {
+        self.inner.query_with_deadline(q, deadline).await
+    }
+
+    async fn query_with_deadline_in_transaction(
+        &self,
+        q: ast::Query<'_>,
+        deadline: Duration,
+    ) -> crate::Result<connector::ResultSet> {
+        self.inner.query_with_deadline_in_transaction(q, deadline).await
+    }
+
     async fn server_reset_query(&self, tx: &Transaction<'_>) -> crate::Result<()> {
         self.inner.server_reset_query(tx).await
     }
@@ -53,6 +103,10 @@ impl Queryable for PooledConnection {
     fn begin_statement(&self) -> &'static str {
         self.inner.begin_statement()
     }
+
+    fn is_healthy(&self) -> bool {
+        self.inner.is_healthy()
+    }
 }
 
 #[doc(hidden)]
@@ -118,11 +172,28 @@ impl Manager for QuaintManager {
         conn.raw_cmd("SELECT 1").await?;
         Ok(conn)
     }
+
+    fn validate(&self, conn: &mut Self::Connection) -> bool {
+        conn.is_healthy()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::pooled::Quaint;
+    use crate::{connector::Queryable, pooled::Quaint};
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    async fn wait_for_idle(pool: &Quaint, min_idle: usize) -> bool {
+        for _ in 0..50 {
+            if pool.idle_connections().await >= min_idle {
+                return true;
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        false
+    }
 
     #[tokio::test]
     #[cfg(feature = "mysql")]
@@ -210,4 +281,143 @@ mod tests {
 
         assert_eq!(10, pool.capacity().await as usize);
     }
+
+    #[tokio::test]
+    #[cfg(feature = "postgresql")]
+    async fn psql_checked_out_connection_keeps_the_session() {
+        let conn_string = format!(
+            "{}?connection_limit=2",
+            std::env::var("TEST_PSQL").expect("TEST_PSQL connection string not set.")
+        );
+
+        let pool = Quaint::builder(&conn_string).unwrap().build();
+        let conn = pool.check_out().await.unwrap();
+
+        conn.raw_cmd("CREATE TEMPORARY TABLE session_test (id int)")
+            .await
+            .unwrap();
+        conn.raw_cmd("INSERT INTO session_test (id) VALUES (1)").await.unwrap();
+
+        let res = conn.query_raw("SELECT id FROM session_test", &[]).await.unwrap();
+
+        assert_eq!(Some(1), res.into_single().unwrap()[0].as_i64());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn test_checked_out_connection_keeps_the_session() {
+        let conn_string = format!("file:db/test.db?connection_limit=2",);
+        let pool = Quaint::builder(&conn_string).unwrap().build();
+        let conn = pool.check_out().await.unwrap();
+
+        conn.raw_cmd("CREATE TEMPORARY TABLE session_test (id INTEGER)")
+            .await
+            .unwrap();
+        conn.raw_cmd("INSERT INTO session_test (id) VALUES (1)").await.unwrap();
+
+        let res = conn.query_raw("SELECT id FROM session_test", &[]).await.unwrap();
+
+        assert_eq!(Some(1), res.into_single().unwrap()[0].as_i64());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "postgresql")]
+    async fn psql_warm_up_opens_min_idle_connections() {
+        let conn_string = format!(
+            "{}?connection_limit=5&pool_min_idle=3",
+            std::env::var("TEST_PSQL").expect("TEST_PSQL connection string not set.")
+        );
+
+        let pool = Quaint::builder(&conn_string).unwrap().build();
+        pool.warm_up().await.unwrap();
+
+        assert!(wait_for_idle(&pool, 3).await);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn test_warm_up_opens_min_idle_connections() {
+        let conn_string = format!("file:db/test.db?connection_limit=5&pool_min_idle=3",);
+        let pool = Quaint::builder(&conn_string).unwrap().build();
+
+        assert_eq!(0, pool.idle_connections().await);
+
+        pool.warm_up().await.unwrap();
+
+        assert!(wait_for_idle(&pool, 3).await);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "postgresql")]
+    async fn psql_panicking_transaction_is_rolled_back() {
+        use futures::FutureExt;
+        use std::panic::AssertUnwindSafe;
+
+        let conn_string = format!(
+            "{}?connection_limit=1",
+            std::env::var("TEST_PSQL").expect("TEST_PSQL connection string not set.")
+        );
+
+        let pool = Quaint::builder(&conn_string).unwrap().build();
+
+        let res = AssertUnwindSafe(pool.with_transaction::<_, ()>(|tx| {
+            Box::pin(async move {
+                tx.raw_cmd("CREATE TABLE panicking_transaction (id int)").await?;
+                panic!("boom");
+            })
+        }))
+        .catch_unwind()
+        .await;
+
+        assert!(res.is_err());
+
+        // The only connection of the pool would see the table if the
+        // transaction was left open.
+        let conn = pool.check_out().await.unwrap();
+        let res = conn
+            .query_raw("SELECT to_regclass('panicking_transaction') IS NULL AS missing", &[])
+            .await
+            .unwrap();
+
+        assert_eq!(Some(true), res.into_single().unwrap()["missing"].as_bool());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn test_transaction_is_committed_or_rolled_back() {
+        let conn_string = format!("file:db/test.db?connection_limit=1",);
+        let pool = Quaint::builder(&conn_string).unwrap().build();
+
+        pool.check_out()
+            .await
+            .unwrap()
+            .raw_cmd("CREATE TABLE IF NOT EXISTS with_transaction_test (id INTEGER); DELETE FROM with_transaction_test")
+            .await
+            .unwrap();
+
+        pool.with_transaction(|tx| {
+            Box::pin(async move { tx.raw_cmd("INSERT INTO with_transaction_test (id) VALUES (1)").await })
+        })
+        .await
+        .unwrap();
+
+        let res = pool
+            .with_transaction(|tx| {
+                Box::pin(async move {
+                    tx.raw_cmd("INSERT INTO with_transaction_test (id) VALUES (2)").await?;
+                    tx.raw_cmd("INSERT INTO nonexisting_table (id) VALUES (3)").await
+                })
+            })
+            .await;
+
+        assert!(res.is_err());
+
+        let conn = pool.check_out().await.unwrap();
+        let res = conn
+            .query_raw("SELECT id FROM with_transaction_test", &[])
+            .await
+            .unwrap();
+
+        assert_eq!(Some(1), res.into_single().unwrap()[0].as_i64());
+    }
 }
@@ -0,0 +1,164 @@
+use super::{PooledConnection, Quaint};
+use crate::{
+    ast::Query,
+    connector::{Queryable, ResultSet, SqlFamily},
+    error::{Error, ErrorKind},
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// MySQL connection pools for a primary and its read replicas.
+///
+/// `SELECT` queries are sent to the replicas in turns, everything else to
+/// the primary. Selects locking the rows, such as `FOR UPDATE`, go to the
+/// primary. Without replicas, all queries go to the primary. Queries
+/// that must see the latest writes should use the [`primary`] pool
+/// directly, and so should transactions and raw queries.
+///
+/// ```no_run
+/// # use quaint::{ast::*, pooled::{Quaint, ReplicatedMysql}};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), quaint::error::Error> {
+/// let primary = Quaint::builder("mysql://root@primary/test")?.build();
+/// let replica = Quaint::builder("mysql://root@replica/test")?.build();
+/// let pools = ReplicatedMysql::new(primary, vec![replica])?;
+///
+/// // Runs on the replica.
+/// pools.query(Select::from_table("cats").into()).await?;
+///
+/// // Runs on the primary.
+/// pools.execute(Insert::single_into("cats").value("name", "Musti").into()).await?;
+///
+/// // Forced to the primary.
+/// let conn = pools.primary().check_out().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`primary`]: #method.primary
+pub struct ReplicatedMysql {
+    primary: Quaint,
+    replicas: Vec<Quaint>,
+    next_replica: AtomicUsize,
+}
+
+impl ReplicatedMysql {
+    /// Routes the queries between the primary and the replica pools. Fails
+    /// if any of the pools is not a MySQL pool.
+    pub fn new(primary: Quaint, replicas: Vec<Quaint>) -> crate::Result<Self> {
+        let all_mysql = std::iter::once(&primary)
+            .chain(replicas.iter())
+            .all(|pool| pool.connection_info().sql_family() == SqlFamily::Mysql);
+
+        if !all_mysql {
+            return Err(Error::builder(ErrorKind::InvalidConnectionArguments).build());
+        }
+
+        Ok(Self {
+            primary,
+            replicas,
+            next_replica: AtomicUsize::new(0),
+        })
+    }
+
+    /// The pool of the primary, for forcing a query to see the latest
+    /// writes.
+    pub fn primary(&self) -> &Quaint {
+        &self.primary
+    }
+
+    /// The pools of the replicas.
+    pub fn replicas(&self) -> &[Quaint] {
+        &self.replicas
+    }
+
+    /// The pool the query is sent to. Selects without a lock move to the
+    /// next replica on every call.
+    pub fn route(&self, query: &Query<'_>) -> &Quaint {
+        let read_only = matches!(query, Query::Select(select) if select.lock.is_none());
+
+        if !read_only || self.replicas.is_empty() {
+            return &self.primary;
+        }
+
+        let next = self.next_replica.fetch_add(1, Ordering::Relaxed);
+        &self.replicas[next % self.replicas.len()]
+    }
+
+    /// Reserve a connection from the pool the query is sent to.
+    pub async fn check_out_for(&self, query: &Query<'_>) -> crate::Result<PooledConnection> {
+        self.route(query).check_out().await
+    }
+
+    /// Execute a query returning the rows on the routed pool.
+    pub async fn query(&self, q: Query<'_>) -> crate::Result<ResultSet> {
+        self.check_out_for(&q).await?.query(q).await
+    }
+
+    /// Execute a query returning the number of changed rows on the routed
+    /// pool.
+    pub async fn execute(&self, q: Query<'_>) -> crate::Result<u64> {
+        self.check_out_for(&q).await?.execute(q).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::*;
+
+    fn pool(connection_limit: usize) -> Quaint {
+        let conn_string = std::env::var("TEST_MYSQL").expect("TEST_MYSQL connection string not set.");
+        let conn_string = format!("{}?connection_limit={}", conn_string, connection_limit);
+
+        Quaint::builder(&conn_string).unwrap().build()
+    }
+
+    #[tokio::test]
+    async fn selects_go_to_the_replicas_and_writes_to_the_primary() {
+        let pools = ReplicatedMysql::new(pool(3), vec![pool(5), pool(7)]).unwrap();
+
+        let select = Query::from(Select::default().value(1));
+        let insert = Query::from(Insert::single_into("cats").value("name", "Musti"));
+
+        assert_eq!(5, pools.route(&select).capacity().await);
+        assert_eq!(7, pools.route(&select).capacity().await);
+        assert_eq!(5, pools.route(&select).capacity().await);
+        assert_eq!(3, pools.route(&insert).capacity().await);
+    }
+
+    #[tokio::test]
+    async fn locking_selects_go_to_the_primary() {
+        let pools = ReplicatedMysql::new(pool(3), vec![pool(5)]).unwrap();
+
+        let for_update = Query::from(Select::from_table("cats").for_update());
+        let for_share = Query::from(Select::from_table("cats").for_share());
+        let skip_locked = Query::from(Select::from_table("cats").skip_locked());
+
+        assert_eq!(3, pools.route(&for_update).capacity().await);
+        assert_eq!(3, pools.route(&for_share).capacity().await);
+        assert_eq!(3, pools.route(&skip_locked).capacity().await);
+    }
+
+    #[tokio::test]
+    async fn without_replicas_selects_go_to_the_primary() {
+        let pools = ReplicatedMysql::new(pool(3), Vec::new()).unwrap();
+        let select = Query::from(Select::default().value(1));
+
+        assert_eq!(3, pools.route(&select).capacity().await);
+    }
+
+    #[tokio::test]
+    async fn queries_run_on_the_routed_pool() {
+        let pools = ReplicatedMysql::new(pool(3), vec![pool(5)]).unwrap();
+
+        let row = pools
+            .query(Select::default().value(1).into())
+            .await
+            .unwrap()
+            .into_single()
+            .unwrap();
+
+        assert_eq!(Some(1), row[0].as_i64());
+        assert_eq!(0, pools.primary().idle_connections().await);
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     connector::{self, ConnectionInfo, Queryable, TransactionCapable},
 };
 use async_trait::async_trait;
-use std::{fmt, sync::Arc};
+use std::{collections::HashMap, fmt, sync::Arc, time::Duration};
 
 #[cfg(feature = "sqlite")]
 use std::convert::TryFrom;
@@ -103,6 +103,14 @@ impl Quaint {
     /// - `connect_timeout` defined in seconds (default: 5). Connecting to a
     ///   database will return a `ConnectTimeout` error if taking more than the
     ///   defined value.
+    /// - `session_variable` a `name=value` pair set with `SET` on every new
+    ///   connection, such as `time_zone=+00:00`. Can be given multiple times.
+    /// - `stmt_cache_size` the number of prepared statements cached per
+    ///   connection (default: 1000). If set to 0, the cache is disabled.
+    /// - `zero_dates` either `error` (default) or `null`. Defines if dates
+    ///   with a zero day or month, such as `0000-00-00`, fail the query or
+    ///   are read as `NULL`.
+    /// - `charset` the character set of the connection (default: `utf8mb4`).
     ///
     /// Microsoft SQL Server:
     ///
@@ -182,6 +190,21 @@ impl Quaint {
         &self.connection_info
     }
 
+    /// Execute a query given as SQL with `:name` placeholders, binding the
+    /// parameters by name. See
+    /// [`named_to_positional`](connector/fn.named_to_positional.html) for the
+    /// rewriting rules.
+    pub async fn query_named(
+        &self,
+        sql: &str,
+        params: &HashMap<&str, ast::Value<'_>>,
+    ) -> crate::Result<connector::ResultSet> {
+        let family = self.connection_info.sql_family();
+        let (sql, params) = connector::named_to_positional(family, sql, params)?;
+
+        self.inner.query_raw(&sql, &params).await
+    }
+
     fn log_start(info: &ConnectionInfo) {
         let family = info.sql_family();
         let pg_bouncer = if info.pg_bouncer() { " in PgBouncer mode" } else { "" };
@@ -215,15 +238,61 @@ impl Queryable for Quaint {
         self.inner.execute_raw(sql, params).await
     }
 
+    async fn query_raw_in_batches(
+        &self,
+        sql: &str,
+        params: &[ast::Value<'_>],
+        fetch_size: usize,
+        on_batch: &mut (dyn FnMut(connector::ResultSet) -> crate::Result<()> + Send),
+    ) -> crate::Result<()> {
+        self.inner.query_raw_in_batches(sql, params, fetch_size, on_batch).await
+    }
+
     async fn raw_cmd(&self, cmd: &str) -> crate::Result<()> {
         self.inner.raw_cmd(cmd).await
     }
 
+    async fn validate(&self, q: ast::Query<'_>) -> crate::Result<()> {
+        self.inner.validate(q).await
+    }
+
     async fn version(&self) -> crate::Result<Option<String>> {
         self.inner.version().await
     }
 
+    async fn call_procedure(&self, name: &str, params: &[ast::Value<'_>]) -> crate::Result<Vec<connector::ResultSet>> {
+        self.inner.call_procedure(name, params).await
+    }
+
+    async fn advisory_lock(&self, key: i64) -> crate::Result<()> {
+        self.inner.advisory_lock(key).await
+    }
+
+    async fn try_advisory_lock(&self, key: i64) -> crate::Result<bool> {
+        self.inner.try_advisory_lock(key).await
+    }
+
+    async fn advisory_unlock(&self, key: i64) -> crate::Result<bool> {
+        self.inner.advisory_unlock(key).await
+    }
+
+    async fn query_with_deadline(&self, q: ast::Query<'_>, deadline: Duration) -> crate::Result<connector::ResultSet> {
+        self.inner.query_with_deadline(q, deadline).await
+    }
+
+    async fn query_with_deadline_in_transaction(
+        &self,
+        q: ast::Query<'_>,
+        deadline: Duration,
+    ) -> crate::Result<connector::ResultSet> {
+        self.inner.query_with_deadline_in_transaction(q, deadline).await
+    }
+
     fn begin_statement(&self) -> &'static str {
         self.inner.begin_statement()
     }
+
+    fn is_healthy(&self) -> bool {
+        self.inner.is_healthy()
+    }
 }
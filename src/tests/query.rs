@@ -17,6 +17,78 @@ async fn single_value(api: &mut dyn TestApi) -> crate::Result<()> {
     Ok(())
 }
 
+#[test_each_connector]
+async fn query_raw_in_batches(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int").await?;
+
+    let insert = (1..=10).fold(Insert::multi_into(&table, vec!["id"]), |insert, i| {
+        insert.values(vec![i])
+    });
+
+    api.conn().insert(insert.into()).await?;
+
+    let sql = format!("SELECT id FROM {} ORDER BY id", table);
+    let mut batch_sizes = Vec::new();
+    let mut ids = Vec::new();
+
+    api.conn()
+        .query_raw_in_batches(&sql, &[], 3, &mut |batch| {
+            batch_sizes.push(batch.len());
+            ids.extend(batch.into_iter().map(|row| row[0].as_i64().unwrap()));
+
+            Ok(())
+        })
+        .await?;
+
+    assert_eq!(vec![3, 3, 3, 1], batch_sizes);
+    assert_eq!((1..=10).collect::<Vec<i64>>(), ids);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgresql", "mysql"))]
+async fn query_raw_in_batches_with_parameters(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int").await?;
+
+    let insert = (1..=10).fold(Insert::multi_into(&table, vec!["id"]), |insert, i| {
+        insert.values(vec![i])
+    });
+
+    api.conn().insert(insert.into()).await?;
+
+    let placeholder = if api.system() == "postgres" { "$1" } else { "?" };
+    let sql = format!("SELECT id FROM {} WHERE id > {} ORDER BY id", table, placeholder);
+    let mut batch_sizes = Vec::new();
+
+    api.conn()
+        .query_raw_in_batches(&sql, &[Value::integer(6)], 2, &mut |batch| {
+            batch_sizes.push(batch.len());
+            Ok(())
+        })
+        .await?;
+
+    assert_eq!(vec![2, 2], batch_sizes);
+
+    // The connection is usable after reading all the batches.
+    let row = api.conn().select(Select::default().value(1)).await?.into_single()?;
+    assert_eq!(Some(1), row[0].as_i64());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn query_raw_in_batches_with_zero_fetch_size_fails(api: &mut dyn TestApi) -> crate::Result<()> {
+    let err = api
+        .conn()
+        .query_raw_in_batches("SELECT 1", &[], 0, &mut |_| Ok(()))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err.kind(), crate::error::ErrorKind::InvalidQuery(_)));
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn aliased_value(api: &mut dyn TestApi) -> crate::Result<()> {
     let select = Select::default().value(val!("foo").alias("bar"));
@@ -81,6 +153,35 @@ async fn transactions(api: &mut dyn TestApi) -> crate::Result<()> {
     Ok(())
 }
 
+#[test_each_connector(tags("mysql", "sqlite"))]
+async fn transaction_collects_the_inserted_ids(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api
+        .create_table(&format!("{}, name varchar(255)", api.autogen_id("id")))
+        .await?;
+
+    let tx = api.conn().start_transaction().await?;
+
+    for name in &["Musti", "Naukio", "Belka"] {
+        tx.insert(Insert::single_into(&table).value("name", *name)).await?;
+    }
+
+    tx.commit().await?;
+
+    let select = Select::from_table(&table).column("id").order_by("id");
+    let ids: Vec<u64> = api
+        .conn()
+        .select(select)
+        .await?
+        .into_iter()
+        .map(|row| row["id"].as_i64().unwrap() as u64)
+        .collect();
+
+    assert_eq!(3, ids.len());
+    assert_eq!(ids, tx.inserted_ids());
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn in_values_singular(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api.create_table("id int, id2 int").await?;
@@ -183,6 +284,38 @@ async fn not_in_values_tuple(api: &mut dyn TestApi) -> crate::Result<()> {
     Ok(())
 }
 
+#[test_each_connector(tags("postgresql", "sqlite"))]
+async fn in_values_join(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+
+    let insert = Insert::multi_into(&table, vec!["id", "name"])
+        .values(vec![Value::from(1), Value::from("Musti")])
+        .values(vec![Value::from(2), Value::from("Naukio")])
+        .values(vec![Value::from(3), Value::from("Belka")])
+        .values(vec![Value::from(4), Value::from("Pumba")]);
+
+    api.conn().insert(insert.into()).await?;
+
+    let ids =
+        |res: crate::connector::ResultSet| -> Vec<i64> { res.into_iter().filter_map(|r| r["id"].as_i64()).collect() };
+
+    let in_list = Select::from_table(&table)
+        .so_that(Column::from((&table, "id")).in_selection(vec![2, 4, 5]))
+        .order_by(Column::from((&table, "id")).ascend());
+
+    let joined = Select::from_table(&table)
+        .column(Column::from((&table, "id")))
+        .inner_join(Column::from((&table, "id")).in_values_join(vec![2, 4, 5]))
+        .order_by(Column::from((&table, "id")).ascend());
+
+    let expected = ids(api.conn().select(in_list).await?);
+
+    assert_eq!(vec![2, 4], expected);
+    assert_eq!(expected, ids(api.conn().select(joined).await?));
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn order_by_ascend(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api.create_table("id int, id2 int").await?;
@@ -628,6 +761,95 @@ async fn returning_insert(api: &mut dyn TestApi) -> crate::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "postgresql")]
+#[test_each_connector(tags("postgresql"))]
+async fn returning_insert_with_alias(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+    let insert = Insert::single_into(&table).value("id", 2).value("name", "Naukio");
+    let returning = vec![Column::from("id").alias("new_id"), Column::from("name")];
+
+    let res = api.conn().insert(Insert::from(insert).returning(returning)).await?;
+
+    assert_eq!(1, res.len());
+
+    let row = res.get(0).unwrap();
+    assert_eq!(Some(2), row["new_id"].as_i64());
+    assert_eq!(None, row.get("id"));
+
+    Ok(())
+}
+
+#[cfg(feature = "postgresql")]
+#[test_each_connector(tags("postgresql"))]
+async fn returning_update(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+    let insert = Insert::single_into(&table).value("id", 2).value("name", "Naukio");
+    api.conn().insert(insert.into()).await?;
+
+    let update = Update::table(&table)
+        .set("name", "Musti")
+        .so_that("id".equals(2))
+        .returning(vec!["id", "name"]);
+
+    let res = api.conn().update_returning(update).await?;
+    assert_eq!(1, res.len());
+
+    let row = res.get(0).unwrap();
+    assert_eq!(Some(2), row["id"].as_i64());
+    assert_eq!(Some("Musti"), row["name"].as_str());
+
+    Ok(())
+}
+
+#[cfg(feature = "postgresql")]
+#[test_each_connector(tags("postgresql"))]
+async fn execute_and_return_update(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+
+    let insert = Insert::multi_into(&table, vec!["id", "name"])
+        .values(vec![Value::from(1), Value::from("Musti")])
+        .values(vec![Value::from(2), Value::from("Naukio")])
+        .values(vec![Value::from(3), Value::from("Belka")]);
+
+    api.conn().insert(insert.into()).await?;
+
+    let update = Update::table(&table)
+        .set("name", "Cat")
+        .so_that("id".less_than(3))
+        .returning(vec!["id", "name"]);
+
+    let res = api.conn().execute_and_return(update.into()).await?;
+    assert_eq!(2, res.affected);
+    assert_eq!(2, res.rows.len());
+
+    for row in res.rows {
+        assert!(row["id"].as_i64().unwrap() < 3);
+        assert_eq!(Some("Cat"), row["name"].as_str());
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn execute_and_return_requires_a_returning_clause(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+
+    let insert = Insert::single_into(&table).value("id", 1).value("name", "Musti");
+    api.conn().insert(insert.into()).await?;
+
+    let update = Update::table(&table).set("name", "Naukio").so_that("id".equals(1));
+    let err = api.conn().execute_and_return(update.into()).await.unwrap_err();
+
+    assert!(matches!(err.kind(), crate::error::ErrorKind::InvalidQuery(_)));
+
+    // The rejected query is not run.
+    let select = Select::from_table(&table).column("name");
+    let row = api.conn().select(select).await?.into_single()?;
+    assert_eq!(Some("Musti"), row["name"].as_str());
+
+    Ok(())
+}
+
 #[cfg(feature = "mssql")]
 #[test_each_connector(tags("mssql"))]
 async fn multiple_resultset_should_return_the_last_one(api: &mut dyn TestApi) -> crate::Result<()> {
@@ -1102,6 +1324,65 @@ async fn text_columns_with_non_utf8_encodings_can_be_queried(api: &mut dyn TestA
     Ok(())
 }
 
+#[test_each_connector(tags("mysql"))]
+async fn year_and_bit_columns_can_be_read(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api
+        .create_table("id integer auto_increment primary key, y year, b bit(8)")
+        .await?;
+
+    api.conn()
+        .raw_cmd(&format!(
+            "INSERT INTO {} (y, b) VALUES (2021, b'10101010'), (NULL, NULL)",
+            table
+        ))
+        .await?;
+
+    let select = Select::from_table(&table).column("y").column("b").order_by("id");
+    let rows = api.conn().select(select).await?;
+
+    let row = rows.get(0).unwrap();
+    assert_eq!(Some(&Value::integer(2021)), row.get("y"));
+    assert_eq!(Some(&Value::bytes(vec![0b1010_1010])), row.get("b"));
+
+    let row = rows.get(1).unwrap();
+    assert_eq!(Some(&Value::Integer(None)), row.get("y"));
+    assert_eq!(Some(&Value::Bytes(None)), row.get("b"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn stored_procedure_returns_all_result_sets(api: &mut dyn TestApi) -> crate::Result<()> {
+    let procedure = api.get_name();
+
+    let create = format!(
+        "CREATE PROCEDURE {}(IN x INT) BEGIN SELECT x AS a; SELECT x + 1 AS b, x + 2 AS c; END",
+        procedure
+    );
+
+    api.conn().raw_cmd(&create).await?;
+
+    let result_sets = api.conn().call_procedure(&procedure, &[Value::integer(1)]).await;
+    api.conn().raw_cmd(&format!("DROP PROCEDURE {}", procedure)).await?;
+
+    let mut result_sets = result_sets?.into_iter();
+
+    let first = result_sets.next().unwrap();
+    assert_eq!(&vec!["a"], first.columns());
+    assert_eq!(Some(1), first.into_single()?.at(0).unwrap().as_i64());
+
+    let second = result_sets.next().unwrap();
+    assert_eq!(&vec!["b", "c"], second.columns());
+
+    let row = second.into_single()?;
+    assert_eq!(Some(2), row.at(0).unwrap().as_i64());
+    assert_eq!(Some(3), row.at(1).unwrap().as_i64());
+
+    assert!(result_sets.next().is_none());
+
+    Ok(())
+}
+
 #[test_each_connector(tags("mysql"))]
 async fn filtering_by_json_values_does_not_work_but_does_not_crash(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api
@@ -1789,3 +2070,328 @@ async fn ints_read_write_to_numeric(api: &mut dyn TestApi) -> crate::Result<()>
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn prepared_update_can_be_executed_with_different_parameters(api: &mut dyn TestApi) -> crate::Result<()> {
+    use crate::{connector::SqlFamily, visitor::Visitor};
+
+    let table = api.create_table("id int, value int").await?;
+
+    let insert = Insert::multi_into(&table, vec!["id", "value"])
+        .values(vec![1, 0])
+        .values(vec![2, 0])
+        .values(vec![3, 0]);
+
+    api.conn().insert(insert.into()).await?;
+
+    let update = Update::table(&table).set("value", 0).so_that("id".equals(0));
+
+    let prepared = match api.conn().connection_info().sql_family() {
+        #[cfg(feature = "postgresql")]
+        SqlFamily::Postgres => crate::visitor::Postgres::prepare(update)?,
+        #[cfg(feature = "mysql")]
+        SqlFamily::Mysql => crate::visitor::Mysql::prepare(update)?,
+        #[cfg(feature = "sqlite")]
+        SqlFamily::Sqlite => crate::visitor::Sqlite::prepare(update)?,
+        #[cfg(feature = "mssql")]
+        SqlFamily::Mssql => crate::visitor::Mssql::prepare(update)?,
+    };
+
+    for (id, value) in &[(1, 10), (2, 20), (3, 30)] {
+        let params = [Value::integer(*value), Value::integer(*id)];
+        let changes = api.conn().execute_prepared(&prepared, &params).await?;
+
+        assert_eq!(1, changes);
+    }
+
+    let select = Select::from_table(&table).column("value").order_by("id");
+    let values: Vec<_> = api
+        .conn()
+        .select(select)
+        .await?
+        .into_iter()
+        .map(|row| row[0].as_i64())
+        .collect();
+
+    assert_eq!(vec![Some(10), Some(20), Some(30)], values);
+
+    let err = api
+        .conn()
+        .execute_prepared(&prepared, &[Value::integer(1)])
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), crate::error::ErrorKind::ConversionError(_)));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn tinyint_columns_can_be_read_as_bool(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, value tinyint(1)").await?;
+
+    let insert = Insert::multi_into(&table, vec!["id", "value"])
+        .values(vec![1, 1])
+        .values(vec![2, 0]);
+
+    api.conn().insert(insert.into()).await?;
+
+    let select = Select::from_table(&table).column("value").order_by("id");
+    let rows = api.conn().select(select).await?;
+
+    assert!(rows.get(0).unwrap().get_as_bool("value")?);
+    assert!(!rows.get(1).unwrap().get_as_bool("value")?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgresql"))]
+async fn boolean_columns_can_be_read_as_bool(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, value boolean, name text").await?;
+
+    let insert = Insert::multi_into(&table, vec!["id", "value", "name"])
+        .values(vec![Value::from(1), Value::from(true), Value::from("Musti")])
+        .values(vec![Value::from(2), Value::from(false), Value::from("Naukio")]);
+
+    api.conn().insert(insert.into()).await?;
+
+    let select = Select::from_table(&table).column("value").column("name").order_by("id");
+    let rows = api.conn().select(select).await?;
+
+    assert!(rows.get(0).unwrap().get_as_bool("value")?);
+    assert!(!rows.get(1).unwrap().get_as_bool("value")?);
+
+    let err = rows.get(0).unwrap().get_as_bool("name").unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        crate::error::ErrorKind::ResultTypeMismatch("bool")
+    ));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn large_auto_increment_ids_are_not_truncated(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api
+        .create_table("id bigint unsigned auto_increment primary key, value int")
+        .await?;
+
+    api.conn()
+        .raw_cmd(&format!("ALTER TABLE `{}` AUTO_INCREMENT = 5000000000", table))
+        .await?;
+
+    let insert = Insert::single_into(&table).value("value", 1);
+    let res = api.conn().insert(insert.into()).await?;
+
+    assert_eq!(Some(5_000_000_000), res.last_insert_id());
+    assert_eq!(Some(5_000_000_000), res.last_insert_id_i64()?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn large_row_ids_are_not_truncated(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id integer primary key, value int").await?;
+
+    let insert = Insert::single_into(&table)
+        .value("id", 5_000_000_000_i64)
+        .value("value", 1);
+    api.conn().insert(insert.into()).await?;
+
+    let insert = Insert::single_into(&table).value("value", 2);
+    let res = api.conn().insert(insert.into()).await?;
+
+    assert_eq!(Some(5_000_000_001), res.last_insert_id());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgresql"))]
+async fn single_precision_floats_are_not_widened(api: &mut dyn TestApi) -> crate::Result<()> {
+    let column_type = if api.system() == "mysql" { "float" } else { "float4" };
+    let table = api.create_table(&format!("id int, value {}", column_type)).await?;
+
+    let insert = Insert::single_into(&table)
+        .value("id", 1)
+        .value("value", Value::float(20000.0));
+    api.conn().insert(insert.into()).await?;
+
+    let select = Select::from_table(&table).column("value");
+    let row = api.conn().select(select).await?.into_single()?;
+
+    assert_eq!(Value::float(20000.0), row["value"]);
+    assert_eq!(Some(20000.0), row["value"].as_f32());
+    assert_eq!("20000", row["value"].as_f32().unwrap().to_string());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn qualified_asterisk_with_joined_column(api: &mut dyn TestApi) -> crate::Result<()> {
+    let users = api.create_table("id int, name varchar(255)").await?;
+    let orders = api.create_table("user_id int, total int").await?;
+
+    let insert = Insert::single_into(&users).value("id", 1).value("name", "Musti");
+    api.conn().insert(insert.into()).await?;
+
+    let insert = Insert::single_into(&orders).value("user_id", 1).value("total", 42);
+    api.conn().insert(insert.into()).await?;
+
+    let join = orders
+        .as_str()
+        .on((orders.as_str(), "user_id").equals(Column::from((users.as_str(), "id"))));
+
+    let select = Select::from_table(&users)
+        .value(Table::from(&users).asterisk())
+        .column((orders.as_str(), "total"))
+        .inner_join(join);
+
+    let res = api.conn().select(select).await?;
+
+    assert_eq!(&vec!["id", "name", "total"], res.columns());
+
+    let row = res.into_single()?;
+    assert_eq!(Some(1), row["id"].as_i64());
+    assert_eq!(Some("Musti"), row["name"].as_str());
+    assert_eq!(Some(42), row["total"].as_i64());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgresql", "sqlite"))]
+async fn validate_prepares_without_running(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+
+    let insert = Insert::single_into(&table).value("id", 1).value("name", "Musti");
+    api.conn().validate(insert.into()).await?;
+
+    let rows = api.conn().select(Select::from_table(&table)).await?;
+    assert!(rows.is_empty());
+
+    let select = Select::from_table(&table).column("nonexistent");
+    let err = api.conn().validate(select.into()).await.unwrap_err();
+
+    let message = err
+        .original_message()
+        .map(String::from)
+        .unwrap_or_else(|| err.to_string());
+    assert!(message.contains("nonexistent"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn geometry_columns_are_read_as_bytes(api: &mut dyn TestApi) -> crate::Result<()> {
+    use std::convert::TryInto;
+
+    let table = api
+        .create_table("id integer auto_increment primary key, location point")
+        .await?;
+
+    api.conn()
+        .raw_cmd(&format!(
+            "INSERT INTO {} (location) VALUES (ST_GeomFromText('POINT(1 2)')), (NULL)",
+            table
+        ))
+        .await?;
+
+    let select = Select::from_table(&table).column("location").order_by("id");
+    let rows = api.conn().select(select).await?;
+
+    let point = rows.get(0).unwrap()["location"].as_bytes().unwrap().to_vec();
+
+    // The SRID, followed by the byte order, the type and the coordinates in
+    // WKB.
+    assert_eq!(25, point.len());
+    assert_eq!(&[0, 0, 0, 0, 1, 1, 0, 0, 0], &point[..9]);
+    assert_eq!(1.0, f64::from_le_bytes(point[9..17].try_into().unwrap()));
+    assert_eq!(2.0, f64::from_le_bytes(point[17..25].try_into().unwrap()));
+
+    assert_eq!(Value::Bytes(None), rows.get(1).unwrap()["location"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgresql"))]
+async fn typed_text_binds_an_hstore_literal(api: &mut dyn TestApi) -> crate::Result<()> {
+    api.conn().raw_cmd("CREATE EXTENSION IF NOT EXISTS hstore").await?;
+
+    let table = api.create_table("id int, attributes hstore").await?;
+
+    let insert = Insert::single_into(&table)
+        .value("id", 1)
+        .value("attributes", typed_text(r#""color"=>"red""#, "hstore"));
+
+    api.conn().insert(insert.into()).await?;
+
+    let select = Select::from_table(&table).value(raw("attributes::text").alias("attributes"));
+    let row = api.conn().select(select).await?.into_single()?;
+
+    assert_eq!(Some(r#""color"=>"red""#), row["attributes"].as_str());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "sqlite"))]
+async fn positional_aliases_name_unnamed_expressions(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, name varchar(255)").await?;
+
+    let insert = Insert::single_into(&table).value("id", 1).value("name", "musti");
+    api.conn().insert(insert.into()).await?;
+
+    let select = Select::from_table(&table)
+        .column("name")
+        .value(Expression::from(Column::from("id")) + Expression::from(1))
+        .value(upper(Column::from("name")))
+        .positional_aliases();
+
+    let row = api.conn().select(select).await?.into_single()?;
+
+    assert_eq!(Some("musti"), row["name"].as_str());
+    assert_eq!(Some(2), row["_col1"].as_i64());
+    assert_eq!(Some("MUSTI"), row["_col2"].as_str());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgresql", "sqlite"))]
+async fn recursive_cte_walks_a_hierarchy(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api.create_table("id int, parent_id int").await?;
+
+    let insert = Insert::multi_into(&table, vec!["id", "parent_id"])
+        .values(vec![Value::integer(1), Value::Integer(None)])
+        .values(vec![Value::integer(2), Value::integer(1)])
+        .values(vec![Value::integer(3), Value::integer(2)])
+        .values(vec![Value::integer(4), Value::integer(1)])
+        .values(vec![Value::integer(5), Value::Integer(None)]);
+
+    api.conn().insert(insert.into()).await?;
+
+    let anchor = Select::from_table(&table)
+        .column("id")
+        .column("parent_id")
+        .so_that("id".equals(2));
+
+    let recursive = Select::from_table(&table)
+        .column((table.as_str(), "id"))
+        .column((table.as_str(), "parent_id"))
+        .inner_join("tree".on((table.as_str(), "parent_id").equals(Column::from(("tree", "id")))));
+
+    let select = Select::from_table("tree").column("id").order_by("id").with_recursive(
+        "tree",
+        vec!["id", "parent_id"],
+        anchor,
+        recursive,
+    );
+
+    let ids: Vec<i64> = api
+        .conn()
+        .select(select)
+        .await?
+        .into_iter()
+        .map(|row| row["id"].as_i64().unwrap())
+        .collect();
+
+    assert_eq!(vec![2, 3], ids);
+
+    Ok(())
+}
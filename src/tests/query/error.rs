@@ -173,6 +173,25 @@ async fn bigint_unsigned_positive_value_out_of_range(api: &mut dyn TestApi) -> c
     Ok(())
 }
 
+#[cfg(not(feature = "bigdecimal"))]
+#[test_each_connector(tags("mysql"))]
+async fn integral_decimal_value_out_of_range(api: &mut dyn TestApi) -> crate::Result<()> {
+    let table = api
+        .create_table("id int4 auto_increment primary key, big decimal(30,0)")
+        .await?;
+
+    let insert = format!(
+        r#"INSERT INTO `{}` (`big`) VALUES (123456789012345678901234567890)"#,
+        table
+    );
+    api.conn().execute_raw(&insert, &[]).await.unwrap();
+    let result = api.conn().select(Select::from_table(&table)).await;
+
+    assert!(matches!(result.unwrap_err().kind(), ErrorKind::ValueOutOfRange { .. }));
+
+    Ok(())
+}
+
 #[test_each_connector(tags("mysql", "mssql", "postgresql"))]
 async fn length_mismatch(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api.create_table("value varchar(3)").await?;
@@ -338,3 +357,70 @@ async fn should_execute_multi_statement_queries_with_raw_cmd(api: &mut dyn TestA
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql", "sqlite", "mssql"))]
+async fn advisory_locks_are_unsupported(api: &mut dyn TestApi) -> crate::Result<()> {
+    let err = api.conn().advisory_lock(1).await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+
+    let err = api.conn().try_advisory_lock(1).await.unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgresql"))]
+async fn queries_over_the_deadline_are_canceled(api: &mut dyn TestApi) -> crate::Result<()> {
+    let sleep = match api.system() {
+        "postgres" => "SELECT pg_sleep(10)",
+        _ => "SELECT SLEEP(10)",
+    };
+
+    let started = std::time::Instant::now();
+    let deadline = std::time::Duration::from_millis(200);
+
+    let err = api
+        .conn()
+        .query_with_deadline(sleep.into(), deadline)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err.kind(), ErrorKind::QueryCanceled));
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+
+    // The connection is still usable after the cancellation.
+    let row = api.conn().select(Select::default().value(1)).await?.into_single()?;
+    assert_eq!(Some(1), row[0].as_i64());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql", "postgresql"))]
+async fn queries_within_the_deadline_return_results(api: &mut dyn TestApi) -> crate::Result<()> {
+    let select = Select::default().value(1);
+    let deadline = std::time::Duration::from_secs(5);
+
+    let row = api
+        .conn()
+        .query_with_deadline(select.into(), deadline)
+        .await?
+        .into_single()?;
+    assert_eq!(Some(1), row[0].as_i64());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite", "mssql"))]
+async fn query_deadlines_are_unsupported(api: &mut dyn TestApi) -> crate::Result<()> {
+    let select = Select::default().value(1);
+    let deadline = std::time::Duration::from_secs(5);
+
+    let err = api
+        .conn()
+        .query_with_deadline(select.into(), deadline)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+
+    Ok(())
+}
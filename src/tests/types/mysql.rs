@@ -98,6 +98,15 @@ test_type!(decimal(
     Value::numeric(bigdecimal::BigDecimal::from_str("3.14").unwrap())
 ));
 
+#[cfg(not(feature = "bigdecimal"))]
+test_type!(decimal_without_scale(
+    mysql,
+    "decimal(10,0)",
+    Value::Integer(None),
+    Value::integer(1234567890),
+    Value::integer(-42)
+));
+
 #[cfg(feature = "bigdecimal")]
 test_type!(float_decimal(
     mysql,
@@ -128,6 +137,14 @@ test_type!(bit1(
     (Value::integer(1), Value::boolean(true)),
 ));
 
+test_type!(bit8(
+    mysql,
+    "bit(8)",
+    Value::Bytes(None),
+    Value::bytes(vec![0b1010_1010]),
+    Value::bytes(vec![0])
+));
+
 test_type!(bit64(
     mysql,
     "bit(64)",
@@ -216,3 +233,28 @@ test_type!(timestamp(mysql, "timestamp", {
     let dt = chrono::DateTime::parse_from_rfc3339("2020-02-27T19:10:22Z").unwrap();
     Value::datetime(dt.with_timezone(&chrono::Utc))
 }));
+
+#[cfg(feature = "chrono")]
+test_type!(time_with_microseconds(
+    mysql,
+    "time(6)",
+    Value::Time(None),
+    Value::time(chrono::NaiveTime::from_hms_micro(16, 20, 00, 123_456))
+));
+
+#[cfg(feature = "chrono")]
+test_type!(datetime_with_microseconds(
+    mysql,
+    "datetime(6)",
+    Value::DateTime(None),
+    {
+        let dt = chrono::DateTime::parse_from_rfc3339("2020-02-27T19:10:22.123456Z").unwrap();
+        Value::datetime(dt.with_timezone(&chrono::Utc))
+    }
+));
+
+#[cfg(feature = "chrono")]
+test_type!(timestamp_with_microseconds(mysql, "timestamp(6)", {
+    let dt = chrono::DateTime::parse_from_rfc3339("2020-02-27T19:10:22.123456Z").unwrap();
+    Value::datetime(dt.with_timezone(&chrono::Utc))
+}));
@@ -287,6 +287,36 @@ test_type!(timestamptz(postgresql, "timestamptz", Value::DateTime(None), {
     Value::datetime(dt.with_timezone(&chrono::Utc))
 }));
 
+#[cfg(feature = "chrono")]
+test_type!(timestamp_with_microseconds(
+    postgresql,
+    "timestamp(6)",
+    Value::DateTime(None),
+    {
+        let dt = chrono::DateTime::parse_from_rfc3339("2020-02-27T19:10:22.123456Z").unwrap();
+        Value::datetime(dt.with_timezone(&chrono::Utc))
+    }
+));
+
+#[cfg(feature = "chrono")]
+test_type!(timestamptz_with_microseconds(
+    postgresql,
+    "timestamptz(6)",
+    Value::DateTime(None),
+    {
+        let dt = chrono::DateTime::parse_from_rfc3339("2020-02-27T19:10:22.123456Z").unwrap();
+        Value::datetime(dt.with_timezone(&chrono::Utc))
+    }
+));
+
+#[cfg(feature = "chrono")]
+test_type!(time_with_microseconds(
+    postgresql,
+    "time(6)",
+    Value::Time(None),
+    Value::time(chrono::NaiveTime::from_hms_micro(16, 20, 00, 123_456))
+));
+
 #[cfg(feature = "chrono")]
 test_type!(timestamptz_array(postgresql, "timestamptz[]", Value::Array(None), {
     let dt = chrono::DateTime::parse_from_rfc3339("2020-02-27T19:10:22Z").unwrap();
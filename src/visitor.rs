@@ -10,8 +10,10 @@
 mod mssql;
 #[cfg(feature = "mysql")]
 mod mysql;
+mod parameters;
 #[cfg(feature = "postgresql")]
 mod postgres;
+mod prepared;
 #[cfg(feature = "sqlite")]
 mod sqlite;
 
@@ -19,16 +21,151 @@ mod sqlite;
 pub use self::mssql::Mssql;
 #[cfg(feature = "mysql")]
 pub use self::mysql::Mysql;
+pub(crate) use self::parameters::ParameterCollector;
 #[cfg(feature = "postgresql")]
 pub use self::postgres::Postgres;
+pub use self::prepared::PreparedQuery;
 #[cfg(feature = "sqlite")]
 pub use self::sqlite::Sqlite;
 
-use crate::ast::*;
-use std::fmt;
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+};
+use std::{borrow::Cow, fmt};
 
 pub type Result = crate::Result<()>;
 
+/// Fails with `TooManyParameters`, if the number of parameters is over the limit.
+pub(crate) fn check_parameter_count(limit: usize, got: usize) -> Result {
+    if got > limit {
+        let kind = ErrorKind::TooManyParameters { limit, got };
+        return Err(Error::builder(kind).build());
+    }
+
+    Ok(())
+}
+
+/// Fails with `UnsupportedFeature` if the insert has a conflict target, for
+/// the dialects not writing one.
+pub(crate) fn check_no_conflict_target(insert: &Insert<'_>) -> Result {
+    if insert.conflict_target.is_some() {
+        let msg = "Conflict targets are only supported with ON CONFLICT DO NOTHING in PostgreSQL.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        return Err(builder.build());
+    }
+
+    Ok(())
+}
+
+/// Removes the comment delimiters from the content of an SQL comment, so it
+/// can't close the comment early. Removing a delimiter can join a new one,
+/// e.g. `*/*//`, so the removal is repeated until none are left.
+pub(crate) fn sanitize_comment(comment: &str) -> String {
+    let mut comment = comment.to_string();
+
+    while comment.contains("*/") || comment.contains("/*") {
+        comment = comment.replace("*/", "").replace("/*", "");
+    }
+
+    comment
+}
+
+/// Lowercases the unquoted words of the query, leaving the string literals,
+/// the delimited identifiers, the comments and the named parameters as they
+/// were written.
+pub(crate) fn lowercase_keywords(sql: &str, quote_open: &str, quote_close: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut rest = sql;
+
+    while let Some(c) = rest.chars().next() {
+        let (token, lowercase) = if c == '\'' {
+            (quoted(rest, "'", "'"), false)
+        } else if rest.starts_with(quote_open) {
+            (quoted(rest, quote_open, quote_close), false)
+        } else if rest.starts_with("/*") {
+            (quoted(rest, "/*", "*/"), false)
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or_else(|| rest.len());
+
+            (&rest[..end], c.is_ascii_alphabetic() && !result.ends_with('@'))
+        } else {
+            (&rest[..c.len_utf8()], false)
+        };
+
+        if lowercase {
+            result.push_str(&token.to_ascii_lowercase());
+        } else {
+            result.push_str(token);
+        }
+
+        rest = &rest[token.len()..];
+    }
+
+    result
+}
+
+/// The start of `s` up to and including the closing delimiter, or all of `s`
+/// if the delimiter is missing.
+fn quoted<'s>(s: &'s str, open: &str, close: &str) -> &'s str {
+    match s[open.len()..].find(close) {
+        Some(i) => &s[..open.len() + i + close.len()],
+        None => s,
+    }
+}
+
+/// The case of the SQL keywords written by
+/// [`Visitor::build_with_keyword_case`].
+///
+/// [`Visitor::build_with_keyword_case`]: trait.Visitor.html#method.build_with_keyword_case
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCase {
+    /// `SELECT`, the default.
+    Upper,
+    /// `select`.
+    Lower,
+}
+
+impl Default for KeywordCase {
+    fn default() -> Self {
+        Self::Upper
+    }
+}
+
+/// Collation names are written without quoting, so only the characters
+/// allowed in an identifier and the given extra characters pass.
+pub(crate) fn check_collation(collation: &str, extra: &[char]) -> Result {
+    let valid = !collation.is_empty()
+        && collation
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || extra.contains(&c));
+
+    if !valid {
+        let msg = format!("Invalid collation name `{}`.", collation);
+        return Err(Error::builder(ErrorKind::conversion(msg)).build());
+    }
+
+    Ok(())
+}
+
+/// The size of a query, for deciding whether a generated query should be
+/// split into smaller batches. See [`Visitor::estimate`].
+///
+/// [`Visitor::estimate`]: trait.Visitor.html#method.estimate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryStats {
+    /// The length of the SQL string in bytes.
+    pub byte_len: usize,
+    /// The number of parameters bound to the query.
+    pub param_count: usize,
+}
+
 /// A function travelling through the query AST, building the final query string
 /// and gathering parameters sent to the database together with the query.
 pub trait Visitor<'a> {
@@ -38,6 +175,8 @@ pub trait Visitor<'a> {
     const C_BACKTICK_CLOSE: &'static str;
     /// Wildcard character to be used in `LIKE` queries.
     const C_WILDCARD: &'static str;
+    /// The maximum number of parameters the database accepts in one query.
+    const C_MAX_PARAMETERS: usize;
 
     /// Convert the given `Query` to an SQL string and a vector of parameters.
     /// When certain parameters are replaced with the `C_PARAM` character in the
@@ -45,6 +184,9 @@ pub trait Visitor<'a> {
     ///
     /// The point of entry for visiting query ASTs.
     ///
+    /// Fails with a `TooManyParameters` error if the query has more parameters
+    /// than [`C_MAX_PARAMETERS`](#associatedconstant.C_MAX_PARAMETERS).
+    ///
     /// ```
     /// # use quaint::{ast::*, visitor::*, error::Error};
     /// # fn main() -> Result {
@@ -62,9 +204,187 @@ pub trait Visitor<'a> {
     /// # }
     /// ```
     fn build<Q>(query: Q) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        Self::build_with_parameter_limit(query, Self::C_MAX_PARAMETERS)
+    }
+
+    /// Like `build`, but fails with a `TooManyParameters` error if the query
+    /// has more parameters than the given limit.
+    ///
+    /// ```
+    /// # use quaint::{ast::*, val, visitor::*, error::ErrorKind};
+    /// let insert = Insert::multi_into("cats", vec!["name", "age"])
+    ///     .values(vec![val!("Musti"), val!(5)])
+    ///     .values(vec![val!("Naukio"), val!(7)]);
+    ///
+    /// let err = Sqlite::build_with_parameter_limit(insert, 3).unwrap_err();
+    ///
+    /// assert!(matches!(err.kind(), ErrorKind::TooManyParameters { limit: 3, got: 4 }));
+    /// ```
+    fn build_with_parameter_limit<Q>(query: Q, limit: usize) -> crate::Result<(String, Vec<Value<'a>>)>
     where
         Q: Into<Query<'a>>;
 
+    /// The length of the SQL and the number of parameters of the query. Does
+    /// not fail if the query has too many parameters, so the result can be
+    /// compared to
+    /// [`C_MAX_PARAMETERS`](#associatedconstant.C_MAX_PARAMETERS).
+    ///
+    /// ```
+    /// # use quaint::{ast::*, visitor::*};
+    /// # fn main() -> Result {
+    /// let insert = Insert::multi_into("cats", vec!["name", "age"])
+    ///     .values(vec!["Musti", "5"])
+    ///     .values(vec!["Naukio", "7"]);
+    ///
+    /// let stats = Sqlite::estimate(insert)?;
+    ///
+    /// assert_eq!(4, stats.param_count);
+    /// assert_eq!("INSERT INTO `cats` (`name`, `age`) VALUES (?,?), (?,?)".len(), stats.byte_len);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn estimate<Q>(query: Q) -> crate::Result<QueryStats>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let (sql, params) = Self::build_with_parameter_limit(query, usize::MAX)?;
+
+        Ok(QueryStats {
+            byte_len: sql.len(),
+            param_count: params.len(),
+        })
+    }
+
+    /// Like `build`, but returns a [`PreparedQuery`] that can be executed
+    /// with different parameters, see
+    /// [`Queryable::execute_prepared`](../connector/trait.Queryable.html#method.execute_prepared).
+    ///
+    /// [`PreparedQuery`]: struct.PreparedQuery.html
+    fn prepare<Q>(query: Q) -> crate::Result<PreparedQuery<'a>>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let (sql, params) = Self::build(query)?;
+        Ok(PreparedQuery::new(sql, params))
+    }
+
+    /// Like `build`, but appends the given comment to the end of the query,
+    /// e.g. for trace context read by monitoring tools in the
+    /// [sqlcommenter](https://google.github.io/sqlcommenter/) format. Comment
+    /// delimiters in the content are removed.
+    ///
+    /// ```
+    /// # use quaint::{ast::*, visitor::*};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("cats");
+    /// let (sql, _) = Mysql::build_with_comment(query, "traceparent='00-4bf92f-00f067-01'")?;
+    ///
+    /// assert_eq!("SELECT `cats`.* FROM `cats` /* traceparent='00-4bf92f-00f067-01' */", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn build_with_comment<Q>(query: Q, comment: &str) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let (mut sql, params) = Self::build(query)?;
+
+        sql.push_str(" /* ");
+        sql.push_str(&sanitize_comment(comment));
+        sql.push_str(" */");
+
+        Ok((sql, params))
+    }
+
+    /// Like `build`, but writes the keywords in the given case. With
+    /// `KeywordCase::Lower`, all unquoted words are lowercased, including the
+    /// function and type names and the words of raw SQL fragments. String
+    /// literals, identifiers and comments are left as they are.
+    ///
+    /// ```
+    /// # use quaint::{ast::*, visitor::*};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("Cats").so_that("name".equals("Musti"));
+    /// let (sql, _) = Postgres::build_with_keyword_case(query, KeywordCase::Lower)?;
+    ///
+    /// assert_eq!("select \"Cats\".* from \"Cats\" where \"name\" = $1", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn build_with_keyword_case<Q>(query: Q, case: KeywordCase) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let (sql, params) = Self::build(query)?;
+
+        match case {
+            KeywordCase::Upper => Ok((sql, params)),
+            KeywordCase::Lower => {
+                let sql = lowercase_keywords(&sql, Self::C_BACKTICK_OPEN, Self::C_BACKTICK_CLOSE);
+                Ok((sql, params))
+            }
+        }
+    }
+
+    /// Renders only the given conditions, without the `WHERE` keyword, for
+    /// splicing into hand-written SQL. Combined conditions are surrounded
+    /// with parentheses, so the fragment keeps its meaning next to other
+    /// operators.
+    ///
+    /// The placeholders are numbered from one in PostgreSQL and SQL Server.
+    ///
+    /// ```
+    /// # use quaint::{ast::*, visitor::*};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let conditions = "a".equals(1).and("b".greater_than(2));
+    /// let (sql, params) = Mysql::build_condition(conditions)?;
+    ///
+    /// assert_eq!("(`a` = ? AND `b` > ?)", sql);
+    /// assert_eq!(vec![Value::from(1), Value::from(2)], params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn build_condition<T>(conditions: T) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        T: Into<ConditionTree<'a>>;
+
+    /// Like `build`, but returns the type of every parameter together with
+    /// the value. The type is known also for null values, which is useful when
+    /// the parameter types must be declared explicitly to the database.
+    ///
+    /// ```
+    /// # use quaint::{ast::*, visitor::*};
+    /// # fn main() -> Result {
+    /// let query = Insert::single_into("cats").value("name", "Musti").value("age", Value::Integer(None));
+    /// let (_, params) = Sqlite::build_with_types(query)?;
+    ///
+    /// assert_eq!(
+    ///     vec![(Value::text("Musti"), ValueKind::Text), (Value::Integer(None), ValueKind::Integer)],
+    ///     params,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn build_with_types<Q>(query: Q) -> crate::Result<(String, Vec<(Value<'a>, ValueKind)>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let (sql, params) = Self::build(query)?;
+
+        let params = params
+            .into_iter()
+            .map(|value| {
+                let kind = value.kind();
+                (value, kind)
+            })
+            .collect();
+
+        Ok((sql, params))
+    }
+
     /// Write to the query.
     fn write<D: fmt::Display>(&mut self, s: D) -> Result;
 
@@ -90,6 +410,24 @@ pub trait Visitor<'a> {
     /// The `LIMIT` and `OFFSET` statement in the query
     fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> Result;
 
+    /// The limit and offset in the SQL standard `OFFSET ... ROWS FETCH NEXT
+    /// ... ROWS ONLY` form.
+    fn visit_fetch_first(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> Result {
+        if let Some(offset) = offset {
+            self.write(" OFFSET ")?;
+            self.visit_parameterized(offset)?;
+            self.write(" ROWS")?;
+        }
+
+        if let Some(limit) = limit {
+            self.write(" FETCH NEXT ")?;
+            self.visit_parameterized(limit)?;
+            self.write(" ROWS ONLY")?;
+        }
+
+        Ok(())
+    }
+
     /// A walk through an `INSERT` statement
     fn visit_insert(&mut self, insert: Insert<'a>) -> Result;
 
@@ -135,18 +473,45 @@ pub trait Visitor<'a> {
     }
 
     fn visit_join_data(&mut self, data: JoinData<'a>) -> Result {
+        if data.lateral {
+            self.visit_lateral()?;
+        }
+
         self.visit_table(data.table, true)?;
         self.write(" ON ")?;
         self.visit_conditions(data.conditions)
     }
 
+    /// The `LATERAL` keyword of a join.
+    fn visit_lateral(&mut self) -> Result {
+        self.write("LATERAL ")
+    }
+
     /// A walk through a `SELECT` statement
-    fn visit_select(&mut self, select: Select<'a>) -> Result {
+    fn visit_select(&mut self, mut select: Select<'a>) -> Result {
+        if select.positional_aliases {
+            select.columns = std::mem::take(&mut select.columns)
+                .into_iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    if column.is_unnamed() {
+                        column.alias(format!("_col{}", i))
+                    } else {
+                        column
+                    }
+                })
+                .collect();
+        }
+
         let number_of_ctes = select.ctes.len();
 
         if number_of_ctes > 0 {
             self.write("WITH ")?;
 
+            if select.ctes.iter().any(|cte| cte.recursive) {
+                self.visit_recursive_keyword()?;
+            }
+
             for (i, cte) in select.ctes.into_iter().enumerate() {
                 self.visit_cte(cte)?;
 
@@ -160,6 +525,10 @@ pub trait Visitor<'a> {
 
         self.write("SELECT ")?;
 
+        if !select.optimizer_hints.is_empty() {
+            self.visit_optimizer_hints(select.optimizer_hints)?;
+        }
+
         if select.distinct {
             self.write("DISTINCT ")?;
         }
@@ -189,7 +558,7 @@ pub trait Visitor<'a> {
                                 self.write(".*")?;
                             }
                             None => {
-                                self.visit_table(table.clone(), false)?;
+                                self.visit_table(table.clone().without_partitions(), false)?;
                                 self.write(".*")?;
                             }
                         },
@@ -206,7 +575,7 @@ pub trait Visitor<'a> {
                                 // We only want the join to appear in the FROM clause
                                 unjoined_table.typ = TableType::Table(table_name.to_owned());
 
-                                self.visit_table(unjoined_table, false)?;
+                                self.visit_table(unjoined_table.without_partitions(), false)?;
                                 self.write(".*")?;
                             }
                         },
@@ -234,9 +603,19 @@ pub trait Visitor<'a> {
                 self.write(" WHERE ")?;
                 self.visit_conditions(conditions)?;
             }
-            if !select.grouping.is_empty() {
+            if !select.grouping.is_empty() || select.grouping_set.is_some() {
+                let has_grouping = !select.grouping.is_empty();
+
                 self.write(" GROUP BY ")?;
                 self.visit_grouping(select.grouping)?;
+
+                if let Some(grouping_set) = select.grouping_set {
+                    if has_grouping {
+                        self.write(", ")?;
+                    }
+
+                    self.visit_grouping_set(grouping_set)?;
+                }
             }
             if let Some(conditions) = select.having {
                 self.write(" HAVING ")?;
@@ -247,7 +626,15 @@ pub trait Visitor<'a> {
                 self.visit_ordering(select.ordering)?;
             }
 
-            self.visit_limit_and_offset(select.limit, select.offset)?;
+            if select.fetch_first {
+                self.visit_fetch_first(select.limit, select.offset)?;
+            } else {
+                self.visit_limit_and_offset(select.limit, select.offset)?;
+            }
+
+            if let Some(lock) = select.lock {
+                self.visit_lock(lock)?;
+            }
         } else if select.columns.is_empty() {
             self.write(" *")?;
         } else {
@@ -257,6 +644,20 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
+    /// The row-level locking clause of a `SELECT` statement.
+    fn visit_lock(&mut self, lock: Lock) -> Result {
+        match lock.typ {
+            LockType::Update => self.write(" FOR UPDATE")?,
+            LockType::Share => self.write(" FOR SHARE")?,
+        }
+
+        match lock.wait {
+            LockWait::Wait => Ok(()),
+            LockWait::NoWait => self.write(" NOWAIT"),
+            LockWait::SkipLocked => self.write(" SKIP LOCKED"),
+        }
+    }
+
     /// A walk through an `UPDATE` statement
     fn visit_update(&mut self, update: Update<'a>) -> Result {
         self.write("UPDATE ")?;
@@ -283,9 +684,25 @@ pub trait Visitor<'a> {
             self.visit_conditions(conditions)?;
         }
 
+        if let Some(returning) = update.returning {
+            self.visit_update_returning(returning)?;
+        }
+
         Ok(())
     }
 
+    /// The `RETURNING` clause of an `UPDATE` statement. Not supported by
+    /// default.
+    fn visit_update_returning(&mut self, _: Vec<Column<'a>>) -> Result {
+        let msg = "Returning the updated rows is not supported in the database.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
     /// A walk through an `DELETE` statement
     fn visit_delete(&mut self, delete: Delete<'a>) -> Result {
         self.write("DELETE FROM ")?;
@@ -319,7 +736,205 @@ pub trait Visitor<'a> {
 
     /// Visit an SQL `MERGE` query.
     fn visit_merge(&mut self, _merge: Merge<'a>) -> Result {
-        unimplemented!("Merges not supported for the underlying database.")
+        let msg = "MERGE is not supported for the underlying database.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    /// A `REPLACE` statement, supported only on MySQL and SQLite.
+    fn visit_replace(&mut self, _: Replace<'a>) -> Result {
+        let msg = "REPLACE is not supported for the underlying database.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    /// A walk through a `CREATE TABLE` statement
+    fn visit_create_table(&mut self, create: CreateTable<'a>) -> Result {
+        self.write("CREATE TABLE ")?;
+        self.visit_table(create.table, false)?;
+        self.write(" ")?;
+
+        self.surround_with("(", ")", |ref mut s| {
+            let len = create.columns.len();
+
+            for (i, column) in create.columns.into_iter().enumerate() {
+                s.visit_column_definition(column)?;
+
+                if i < (len - 1) {
+                    s.write(", ")?;
+                }
+            }
+
+            for foreign_key in create.foreign_keys {
+                s.write(", ")?;
+                s.visit_foreign_key(foreign_key)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// A foreign key constraint in a `CREATE TABLE` statement.
+    fn visit_foreign_key(&mut self, foreign_key: ForeignKey<'a>) -> Result {
+        if let Some(name) = foreign_key.name {
+            self.write("CONSTRAINT ")?;
+            self.delimited_identifiers(&[&*name])?;
+            self.write(" ")?;
+        }
+
+        self.write("FOREIGN KEY ")?;
+        self.visit_index_columns(foreign_key.columns)?;
+        self.write(" REFERENCES ")?;
+        self.visit_table(foreign_key.referenced_table, false)?;
+        self.write(" ")?;
+        self.visit_index_columns(foreign_key.referenced_columns)?;
+
+        if let Some(action) = foreign_key.on_delete {
+            self.write(" ON DELETE ")?;
+            self.visit_referential_action(action)?;
+        }
+
+        if let Some(action) = foreign_key.on_update {
+            self.write(" ON UPDATE ")?;
+            self.visit_referential_action(action)?;
+        }
+
+        Ok(())
+    }
+
+    /// The `ON DELETE` or `ON UPDATE` action of a foreign key.
+    fn visit_referential_action(&mut self, action: ReferentialAction) -> Result {
+        match action {
+            ReferentialAction::Cascade => self.write("CASCADE"),
+            ReferentialAction::SetNull => self.write("SET NULL"),
+            ReferentialAction::SetDefault => self.write("SET DEFAULT"),
+            ReferentialAction::Restrict => self.write("RESTRICT"),
+            ReferentialAction::NoAction => self.write("NO ACTION"),
+        }
+    }
+
+    /// A column in a `CREATE TABLE` statement.
+    fn visit_column_definition(&mut self, definition: ColumnDefinition<'a>) -> Result {
+        let auto_increment = definition.has(ColumnConstraint::AutoIncrement);
+
+        self.delimited_identifiers(&[&*definition.name])?;
+        self.write(" ")?;
+        self.visit_column_type(definition.typ, auto_increment)?;
+
+        if definition.has(ColumnConstraint::PrimaryKey) {
+            self.write(" PRIMARY KEY")?;
+        }
+
+        if auto_increment {
+            self.visit_auto_increment()?;
+        }
+
+        if definition.has(ColumnConstraint::NotNull) {
+            self.write(" NOT NULL")?;
+        }
+
+        if definition.has(ColumnConstraint::Unique) {
+            self.write(" UNIQUE")?;
+        }
+
+        Ok(())
+    }
+
+    /// The type of a column in a `CREATE TABLE` statement. Databases using a
+    /// serial type for auto-incrementing columns get the flag here.
+    fn visit_column_type(&mut self, typ: ColumnType, _auto_increment: bool) -> Result {
+        match typ {
+            ColumnType::Integer => self.write("INTEGER"),
+            ColumnType::BigInt => self.write("BIGINT"),
+            ColumnType::Text => self.write("TEXT"),
+            ColumnType::Varchar(len) => self.write(format!("VARCHAR({})", len)),
+            ColumnType::Boolean => self.write("BOOLEAN"),
+            ColumnType::Double => self.write("DOUBLE PRECISION"),
+            ColumnType::DateTime => self.write("TIMESTAMP"),
+            ColumnType::Bytes => self.write("BLOB"),
+        }
+    }
+
+    /// The keyword of an auto-incrementing column, written after the
+    /// `PRIMARY KEY` constraint.
+    fn visit_auto_increment(&mut self) -> Result {
+        self.write(" AUTO_INCREMENT")
+    }
+
+    /// A walk through a `CREATE TABLE ... AS SELECT` statement
+    fn visit_create_table_as(&mut self, create: CreateTableAs<'a>) -> Result {
+        self.write("CREATE TABLE ")?;
+        self.visit_table(create.table, false)?;
+        self.write(" AS ")?;
+        self.visit_select(create.select)
+    }
+
+    /// A walk through a `CREATE INDEX` statement
+    fn visit_create_index(&mut self, create: CreateIndex<'a>) -> Result {
+        let name = create.index_name();
+
+        if create.unique {
+            self.write("CREATE UNIQUE INDEX ")?;
+        } else {
+            self.write("CREATE INDEX ")?;
+        }
+
+        self.delimited_identifiers(&[&*name])?;
+        self.write(" ON ")?;
+        self.visit_table(create.table, false)?;
+        self.write(" ")?;
+        self.visit_index_columns(create.columns)
+    }
+
+    /// The parenthesized column list of a `CREATE INDEX` statement.
+    fn visit_index_columns(&mut self, columns: Vec<Cow<'a, str>>) -> Result {
+        self.surround_with("(", ")", |ref mut s| {
+            let len = columns.len();
+
+            for (i, column) in columns.into_iter().enumerate() {
+                s.delimited_identifiers(&[&*column])?;
+
+                if i < (len - 1) {
+                    s.write(", ")?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// A walk through a `DROP TABLE` statement
+    fn visit_drop_table(&mut self, drop: DropTable<'a>) -> Result {
+        self.write("DROP TABLE ")?;
+
+        if drop.if_exists {
+            self.write("IF EXISTS ")?;
+        }
+
+        self.visit_table(drop.table, false)
+    }
+
+    /// A walk through a `DROP INDEX` statement. The index is qualified with
+    /// the database of the table, if any.
+    fn visit_drop_index(&mut self, drop: DropIndex<'a>) -> Result {
+        self.write("DROP INDEX ")?;
+
+        if drop.if_exists {
+            self.write("IF EXISTS ")?;
+        }
+
+        match drop.table.database {
+            Some(database) => self.delimited_identifiers(&[&*database, &*drop.name]),
+            None => self.delimited_identifiers(&[&*drop.name]),
+        }
     }
 
     /// A walk through a complete `Query` statement
@@ -333,6 +948,12 @@ pub trait Visitor<'a> {
             Query::Delete(delete) => self.visit_delete(*delete),
             Query::Union(union) => self.visit_union(*union),
             Query::Merge(merge) => self.visit_merge(*merge),
+            Query::Replace(replace) => self.visit_replace(*replace),
+            Query::CreateTable(create) => self.visit_create_table(*create),
+            Query::CreateTableAs(create) => self.visit_create_table_as(*create),
+            Query::CreateIndex(create) => self.visit_create_index(*create),
+            Query::DropTable(drop) => self.visit_drop_table(*drop),
+            Query::DropIndex(drop) => self.visit_drop_index(*drop),
             Query::Raw(string) => self.write(string),
         }
     }
@@ -344,6 +965,11 @@ pub trait Visitor<'a> {
         }
     }
 
+    /// Optimizer hints of a `SELECT`, ignored by default.
+    fn visit_optimizer_hints(&mut self, _: Vec<Cow<'a, str>>) -> Result {
+        Ok(())
+    }
+
     /// A walk through a union of `SELECT` statements
     fn visit_union(&mut self, mut ua: Union<'a>) -> Result {
         let number_of_ctes = ua.ctes.len();
@@ -351,6 +977,10 @@ pub trait Visitor<'a> {
         if number_of_ctes > 0 {
             self.write("WITH ")?;
 
+            if ua.ctes.iter().any(|cte| cte.recursive) {
+                self.visit_recursive_keyword()?;
+            }
+
             for (i, cte) in ua.ctes.into_iter().enumerate() {
                 self.visit_cte(cte)?;
 
@@ -443,12 +1073,17 @@ pub trait Visitor<'a> {
             ExpressionKind::Values(values) => self.visit_values(*values)?,
             ExpressionKind::Asterisk(table) => match table {
                 Some(table) => {
-                    self.visit_table(*table, false)?;
+                    self.visit_table(table.without_partitions(), false)?;
                     self.write(".*")?
                 }
                 None => self.write("*")?,
             },
             ExpressionKind::Default => self.write("DEFAULT")?,
+            ExpressionKind::Null => self.write("NULL")?,
+            ExpressionKind::Collate(expr, collation) => self.visit_collate(*expr, collation)?,
+            ExpressionKind::RawSql(sql) => self.write(sql)?,
+            ExpressionKind::Case(case) => self.visit_case(*case)?,
+            ExpressionKind::TypedText(text, type_name) => self.visit_typed_text(text, type_name)?,
         }
 
         if let Some(alias) = value.alias {
@@ -460,6 +1095,26 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
+    /// A text parameter cast to the named type. The type name is written as
+    /// given.
+    fn visit_typed_text(&mut self, text: Cow<'a, str>, type_name: Cow<'a, str>) -> Result {
+        self.write("CAST(")?;
+        self.visit_parameterized(Value::Text(Some(text)))?;
+        self.write(" AS ")?;
+        self.write(type_name)?;
+        self.write(")")
+    }
+
+    /// An expression with a `COLLATE` clause. The collation name is written
+    /// as given.
+    fn visit_collate(&mut self, expr: Expression<'a>, collation: Cow<'a, str>) -> Result {
+        check_collation(&collation, &[])?;
+
+        self.visit_expression(expr)?;
+        self.write(" COLLATE ")?;
+        self.write(collation)
+    }
+
     fn visit_multiple_tuple_comparison(&mut self, left: Row<'a>, right: Values<'a>, negate: bool) -> Result {
         self.visit_row(left)?;
         self.write(if negate { " NOT IN " } else { " IN " })?;
@@ -480,6 +1135,21 @@ pub trait Visitor<'a> {
         })
     }
 
+    /// A `VALUES` list used as a table in a `FROM` or `JOIN` clause.
+    fn visit_values_table(&mut self, values: Values<'a>) -> Result {
+        self.surround_with("(VALUES ", ")", |ref mut s| {
+            let len = values.len();
+            for (i, row) in values.into_iter().enumerate() {
+                s.visit_row(row)?;
+
+                if i < (len - 1) {
+                    s.write(",")?;
+                }
+            }
+            Ok(())
+        })
+    }
+
     /// A database table identifier
     fn visit_table(&mut self, table: Table<'a>, include_alias: bool) -> Result {
         match table.typ {
@@ -487,7 +1157,7 @@ pub trait Visitor<'a> {
                 Some(database) => self.delimited_identifiers(&[&*database, &*table_name])?,
                 None => self.delimited_identifiers(&[&*table_name])?,
             },
-            TableType::Values(values) => self.visit_values(values)?,
+            TableType::Values(values) => self.visit_values_table(values)?,
             TableType::Query(select) => self.surround_with("(", ")", |ref mut s| s.visit_select(select))?,
             TableType::JoinedTable((table_name, joins)) => {
                 match table.database {
@@ -498,22 +1168,56 @@ pub trait Visitor<'a> {
             }
         };
 
+        // The partitions belong to the table in every statement, not only
+        // where it can be aliased.
+        if !table.partitions.is_empty() {
+            self.visit_partitions(table.partitions)?;
+        }
+
         if include_alias {
             if let Some(alias) = table.alias {
                 self.write(" AS ")?;
 
                 self.delimited_identifiers(&[&*alias])?;
             };
+
+            if !table.index_hints.is_empty() {
+                self.visit_index_hints(table.index_hints)?;
+            }
         }
 
         Ok(())
     }
 
+    /// Index hints for the query planner, written after the table. Only
+    /// supported in MySQL.
+    fn visit_index_hints(&mut self, _: Vec<IndexHint<'a>>) -> Result {
+        let msg = "Index hints are only supported in MySQL.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    /// The partitions the query is limited to, written after the table. Only
+    /// supported in MySQL.
+    fn visit_partitions(&mut self, _: Vec<Cow<'a, str>>) -> Result {
+        let msg = "Selecting partitions is only supported in MySQL.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
     /// A database column identifier
     fn visit_column(&mut self, column: Column<'a>) -> Result {
         match column.table {
             Some(table) => {
-                self.visit_table(table, false)?;
+                self.visit_table(table.without_partitions(), false)?;
                 self.write(".")?;
                 self.delimited_identifiers(&[&*column.name])?;
             }
@@ -544,6 +1248,26 @@ pub trait Visitor<'a> {
         })
     }
 
+    /// A `CASE operand WHEN value THEN result ... END` expression.
+    fn visit_case(&mut self, case: Case<'a>) -> Result {
+        self.write("CASE ")?;
+        self.visit_expression(*case.operand)?;
+
+        for (value, result) in case.branches {
+            self.write(" WHEN ")?;
+            self.visit_expression(value)?;
+            self.write(" THEN ")?;
+            self.visit_expression(result)?;
+        }
+
+        if let Some(otherwise) = case.otherwise {
+            self.write(" ELSE ")?;
+            self.visit_expression(*otherwise)?;
+        }
+
+        self.write(" END")
+    }
+
     /// A walk through the query conditions
     fn visit_conditions(&mut self, tree: ConditionTree<'a>) -> Result {
         match tree {
@@ -551,7 +1275,7 @@ pub trait Visitor<'a> {
                 let len = expressions.len();
 
                 for (i, expr) in expressions.into_iter().enumerate() {
-                    s.visit_expression(expr)?;
+                    s.visit_predicate(expr)?;
 
                     if i < (len - 1) {
                         s.write(" AND ")?;
@@ -564,7 +1288,7 @@ pub trait Visitor<'a> {
                 let len = expressions.len();
 
                 for (i, expr) in expressions.into_iter().enumerate() {
-                    s.visit_expression(expr)?;
+                    s.visit_predicate(expr)?;
 
                     if i < (len - 1) {
                         s.write(" OR ")?;
@@ -575,14 +1299,20 @@ pub trait Visitor<'a> {
             }),
             ConditionTree::Not(expression) => self.surround_with("(", ")", |ref mut s| {
                 s.write("NOT ")?;
-                s.visit_expression(*expression)
+                s.visit_predicate(*expression)
             }),
-            ConditionTree::Single(expression) => self.visit_expression(*expression),
+            ConditionTree::Single(expression) => self.visit_predicate(*expression),
             ConditionTree::NoCondition => self.write("1=1"),
             ConditionTree::NegativeCondition => self.write("1=0"),
         }
     }
 
+    /// An expression used as a condition. A bare column is a boolean
+    /// column, written as is.
+    fn visit_predicate(&mut self, expression: Expression<'a>) -> Result {
+        self.visit_expression(expression)
+    }
+
     /// A comparison expression
     fn visit_compare(&mut self, compare: Compare<'a>) -> Result {
         match compare {
@@ -752,6 +1482,7 @@ pub trait Visitor<'a> {
                     self.visit_expression(right)
                 }
             },
+            Compare::InArray(left, values, chunk_size) => self.visit_in_array(*left, values, chunk_size),
             Compare::Like(left, right) => {
                 self.visit_expression(*left)?;
 
@@ -839,7 +1570,72 @@ pub trait Visitor<'a> {
                 self.write(" ")?;
                 self.visit_expression(*right)
             }
+            Compare::Quantified(left, comparator, quantifier, right) => {
+                self.visit_quantified_comparison(*left, comparator, quantifier, *right)
+            }
+        }
+    }
+
+    /// A comparison against the rows of a subquery with `ANY` or `ALL`.
+    fn visit_quantified_comparison(
+        &mut self,
+        left: Expression<'a>,
+        comparator: &'static str,
+        quantifier: Quantifier,
+        right: Expression<'a>,
+    ) -> Result {
+        self.visit_expression(left)?;
+        self.write(" ")?;
+        self.write(comparator)?;
+
+        match quantifier {
+            Quantifier::Any => self.write(" ANY ")?,
+            Quantifier::All => self.write(" ALL ")?,
         }
+
+        self.visit_expression(right)
+    }
+
+    /// An array comparison, by default rendered as `IN` lists of at most
+    /// `chunk_size` values, combined with `OR`. A zero size means the
+    /// default, `DEFAULT_IN_ARRAY_CHUNK_SIZE`.
+    fn visit_in_array(&mut self, left: Expression<'a>, values: Vec<Value<'a>>, chunk_size: usize) -> Result {
+        if values.is_empty() {
+            return self.write("1=0");
+        }
+
+        // The variant is public, so the size can be zero without going
+        // through `Compare::chunk_size`.
+        let chunk_size = if chunk_size == 0 {
+            DEFAULT_IN_ARRAY_CHUNK_SIZE
+        } else {
+            chunk_size
+        };
+
+        let len = (values.len() + chunk_size - 1) / chunk_size;
+        let mut values = values.into_iter();
+
+        if len > 1 {
+            self.write("(")?;
+        }
+
+        for i in 0..len {
+            let chunk: Vec<_> = values.by_ref().take(chunk_size).collect();
+
+            self.visit_expression(left.clone())?;
+            self.write(" IN ")?;
+            self.visit_row(Row::from(chunk))?;
+
+            if i < (len - 1) {
+                self.write(" OR ")?;
+            }
+        }
+
+        if len > 1 {
+            self.write(")")?;
+        }
+
+        Ok(())
     }
 
     fn visit_equals(&mut self, left: Expression<'a>, right: Expression<'a>) -> Result {
@@ -894,12 +1690,162 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
+    /// A `ROLLUP`, `CUBE` or `GROUPING SETS` in the `GROUP BY` section of the
+    /// query.
+    fn visit_grouping_set(&mut self, grouping_set: GroupingSet<'a>) -> Result {
+        match grouping_set {
+            GroupingSet::Rollup(values) => {
+                self.write("ROLLUP ")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_grouping(Grouping::new(values)))
+            }
+            GroupingSet::Cube(values) => {
+                self.write("CUBE ")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_grouping(Grouping::new(values)))
+            }
+            GroupingSet::Sets(sets) => {
+                let len = sets.len();
+                self.write("GROUPING SETS (")?;
+
+                for (i, set) in sets.into_iter().enumerate() {
+                    self.surround_with("(", ")", |ref mut s| s.visit_grouping(Grouping::new(set)))?;
+
+                    if i < (len - 1) {
+                        self.write(", ")?;
+                    }
+                }
+
+                self.write(")")
+            }
+        }
+    }
+
     fn visit_average(&mut self, avg: Average<'a>) -> Result {
         self.write("AVG")?;
         self.surround_with("(", ")", |ref mut s| s.visit_column(avg.column))?;
         Ok(())
     }
 
+    fn visit_substring(&mut self, substring: Substring<'a>) -> Result {
+        self.write("SUBSTRING")?;
+
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_expression(*substring.expression)?;
+            s.write(", ")?;
+            s.visit_expression(*substring.start)?;
+            s.write(", ")?;
+            s.visit_expression(*substring.length)
+        })
+    }
+
+    fn visit_pad(&mut self, pad: Pad<'a>) -> Result {
+        match pad.side {
+            PadSide::Left => self.write("LPAD")?,
+            PadSide::Right => self.write("RPAD")?,
+        }
+
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_expression(*pad.expression)?;
+            s.write(", ")?;
+            s.visit_expression(*pad.length)?;
+            s.write(", ")?;
+            s.visit_expression(*pad.pad)
+        })
+    }
+
+    fn visit_greatest(&mut self, greatest: Greatest<'a>) -> Result {
+        self.write("GREATEST")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_expressions(greatest.exprs))
+    }
+
+    fn visit_least(&mut self, least: Least<'a>) -> Result {
+        self.write("LEAST")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_expressions(least.exprs))
+    }
+
+    /// An aggregate with a `FILTER (WHERE ...)` clause.
+    fn visit_aggregate_filter(&mut self, filter: AggregateFilter<'a>) -> Result {
+        self.visit_function(*filter.function)?;
+        self.write(" FILTER ")?;
+        self.surround_with("(WHERE ", ")", |ref mut s| s.visit_conditions(filter.conditions))
+    }
+
+    /// An aggregate filter for databases without the `FILTER` clause,
+    /// aggregating over `CASE WHEN <conditions> THEN <expression> END`
+    /// instead. The rows not matching the conditions turn into `NULL`, which
+    /// the aggregates ignore.
+    fn visit_aggregate_filter_as_case(&mut self, filter: AggregateFilter<'a>) -> Result {
+        let (name, expr) = match filter.function.typ_ {
+            FunctionType::Count(count) if count.exprs.len() <= 1 => {
+                let name = if count.distinct { "COUNT(DISTINCT" } else { "COUNT" };
+                let expr = count.exprs.into_iter().next().filter(|expr| !expr.is_asterisk());
+                (name, expr)
+            }
+            FunctionType::Sum(sum) => ("SUM", Some(*sum.expr)),
+            FunctionType::Average(avg) => ("AVG", Some(avg.column.into())),
+            FunctionType::Minimum(min) => ("MIN", Some(min.column.into())),
+            FunctionType::Maximum(max) => ("MAX", Some(max.column.into())),
+            _ => {
+                let msg = "Only COUNT, SUM, AVG, MIN and MAX can be filtered without the FILTER clause.";
+                let kind = ErrorKind::unsupported_feature(msg);
+
+                let mut builder = Error::builder(kind);
+                builder.set_original_message(msg);
+
+                return Err(builder.build());
+            }
+        };
+
+        let (open, close) = if name.ends_with("DISTINCT") {
+            (" CASE WHEN ", " END)")
+        } else {
+            ("(CASE WHEN ", " END)")
+        };
+
+        self.write(name)?;
+
+        self.surround_with(open, close, |ref mut s| {
+            s.visit_conditions(filter.conditions)?;
+            s.write(" THEN ")?;
+
+            match expr {
+                Some(expr) => s.visit_expression(expr),
+                None => s.write("1"),
+            }
+        })
+    }
+
+    /// `COUNT(DISTINCT ...)`. Counting distinct combinations of more than one
+    /// expression is only supported in MySQL.
+    fn visit_count_distinct(&mut self, exprs: Vec<Expression<'a>>) -> Result {
+        if exprs.len() > 1 {
+            let msg = "Counting distinct values of multiple expressions is only supported in MySQL.";
+            let kind = ErrorKind::unsupported_feature(msg);
+
+            let mut builder = Error::builder(kind);
+            builder.set_original_message(msg);
+
+            return Err(builder.build());
+        }
+
+        self.write("COUNT")?;
+        self.surround_with("(DISTINCT ", ")", |ref mut s| s.visit_columns(exprs))
+    }
+
+    /// A comma-separated list of expressions.
+    fn visit_expressions(&mut self, exprs: Vec<Expression<'a>>) -> Result {
+        let len = exprs.len();
+
+        for (i, expr) in exprs.into_iter().enumerate() {
+            self.visit_expression(expr)?;
+
+            if i < (len - 1) {
+                self.write(", ")?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn visit_function(&mut self, fun: Function<'a>) -> Result {
         match fun.typ_ {
             FunctionType::RowNumber(fun_rownum) => {
@@ -910,6 +1856,9 @@ pub trait Visitor<'a> {
                     self.surround_with("(", ")", |ref mut s| s.visit_partitioning(fun_rownum.over))?;
                 }
             }
+            FunctionType::Count(fun_count) if fun_count.distinct => {
+                self.visit_count_distinct(fun_count.exprs)?;
+            }
             FunctionType::Count(fun_count) => {
                 if fun_count.exprs.is_empty() {
                     self.write("COUNT(*)")?;
@@ -949,6 +1898,26 @@ pub trait Visitor<'a> {
                 self.write("MAX")?;
                 self.surround_with("(", ")", |ref mut s| s.visit_column(max.column))?;
             }
+            FunctionType::Substring(substring) => {
+                self.visit_substring(substring)?;
+            }
+            FunctionType::Pad(pad) => {
+                self.visit_pad(pad)?;
+            }
+            FunctionType::Greatest(greatest) => {
+                self.visit_greatest(greatest)?;
+            }
+            FunctionType::Least(least) => {
+                self.visit_least(least)?;
+            }
+            FunctionType::AggregateFilter(filter) => {
+                self.visit_aggregate_filter(filter)?;
+            }
+            FunctionType::Window(window) => {
+                self.visit_function(*window.function)?;
+                self.write(" OVER ")?;
+                self.surround_with("(", ")", |ref mut s| s.visit_partitioning(window.over))?;
+            }
         };
 
         if let Some(alias) = fun.alias {
@@ -972,7 +1941,7 @@ pub trait Visitor<'a> {
                 }
             }
 
-            if !over.ordering.is_empty() {
+            if !over.ordering.is_empty() || over.frame.is_some() {
                 self.write(" ")?;
             }
         }
@@ -980,11 +1949,46 @@ pub trait Visitor<'a> {
         if !over.ordering.is_empty() {
             self.write("ORDER BY ")?;
             self.visit_ordering(over.ordering)?;
+
+            if over.frame.is_some() {
+                self.write(" ")?;
+            }
+        }
+
+        if let Some(frame) = over.frame {
+            self.visit_window_frame(frame)?;
         }
 
         Ok(())
     }
 
+    /// A window frame, always written in the `BETWEEN` form.
+    fn visit_window_frame(&mut self, frame: WindowFrame) -> Result {
+        match frame.units {
+            FrameUnits::Rows => self.write("ROWS BETWEEN ")?,
+            FrameUnits::Range => self.write("RANGE BETWEEN ")?,
+        }
+
+        self.visit_frame_bound(frame.start)?;
+        self.write(" AND ")?;
+        self.visit_frame_bound(frame.end)
+    }
+
+    fn visit_frame_bound(&mut self, bound: FrameBound) -> Result {
+        match bound {
+            FrameBound::UnboundedPreceding => self.write("UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(n) => self.write(format!("{} PRECEDING", n)),
+            FrameBound::CurrentRow => self.write("CURRENT ROW"),
+            FrameBound::Following(n) => self.write(format!("{} FOLLOWING", n)),
+            FrameBound::UnboundedFollowing => self.write("UNBOUNDED FOLLOWING"),
+        }
+    }
+
+    /// Marks the common table expressions as possibly recursive.
+    fn visit_recursive_keyword(&mut self) -> Result {
+        self.write("RECURSIVE ")
+    }
+
     fn visit_cte(&mut self, cte: CommonTableExpression<'a>) -> Result {
         let cols = cte
             .columns
@@ -4,10 +4,11 @@ use crate::prelude::Aliasable;
 use crate::prelude::Query;
 use crate::{
     ast::{
-        Column, Comparable, Expression, ExpressionKind, Insert, IntoRaw, Join, JoinData, Joinable, Merge, OnConflict,
-        Order, Ordering, Row, Table, TypeFamily, Values,
+        Column, ColumnType, Comparable, ConditionTree, CreateTableAs, DropIndex, Expression, ExpressionKind, Insert,
+        IntoRaw, Join, JoinData, Joinable, Lock, Merge, OnConflict, Order, Ordering, ReferentialAction, Row, Table,
+        TypeFamily, Values,
     },
-    prelude::Average,
+    prelude::{AggregateFilter, Average, Pad},
     visitor, Value,
 };
 use std::{convert::TryFrom, fmt::Write, iter};
@@ -125,8 +126,10 @@ impl<'a> Visitor<'a> for Mssql<'a> {
     const C_BACKTICK_OPEN: &'static str = "[";
     const C_BACKTICK_CLOSE: &'static str = "]";
     const C_WILDCARD: &'static str = "%";
+    // SQL Server takes 2100 parameters, two of which are used by `sp_executesql`.
+    const C_MAX_PARAMETERS: usize = 2098;
 
-    fn build<Q>(query: Q) -> crate::Result<(String, Vec<Value<'a>>)>
+    fn build_with_parameter_limit<Q>(query: Q, limit: usize) -> crate::Result<(String, Vec<Value<'a>>)>
     where
         Q: Into<crate::ast::Query<'a>>,
     {
@@ -138,6 +141,23 @@ impl<'a> Visitor<'a> for Mssql<'a> {
 
         Mssql::visit_query(&mut this, query.into())?;
 
+        visitor::check_parameter_count(limit, this.parameters.len())?;
+
+        Ok((this.query, this.parameters))
+    }
+
+    fn build_condition<T>(conditions: T) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        let mut this = Mssql {
+            query: String::with_capacity(256),
+            parameters: Vec::with_capacity(16),
+            order_by_set: false,
+        };
+
+        Mssql::visit_conditions(&mut this, conditions.into())?;
+
         Ok((this.query, this.parameters))
     }
 
@@ -161,8 +181,11 @@ impl<'a> Visitor<'a> for Mssql<'a> {
                 .expect_left("Top-level query was right")
                 .into(),
             // Replacing the `ON CONFLICT DO NOTHING` clause with a `MERGE` statement.
+            // An insert with a conflict target is left for `visit_insert` to reject.
             Query::Insert(insert) => match insert.on_conflict {
-                Some(OnConflict::DoNothing) => Merge::try_from(*insert).unwrap().into(),
+                Some(OnConflict::DoNothing) if insert.conflict_target.is_none() => {
+                    Merge::try_from(*insert).unwrap().into()
+                }
                 _ => Query::Insert(insert),
             },
             _ => query,
@@ -315,6 +338,18 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         }
     }
 
+    fn visit_predicate(&mut self, expression: Expression<'a>) -> visitor::Result {
+        // Without a boolean type, a bit column must be compared to be used as
+        // a condition.
+        match expression.kind {
+            ExpressionKind::Column(column) => {
+                self.visit_column(*column)?;
+                self.write(" = 1")
+            }
+            _ => self.visit_expression(expression),
+        }
+    }
+
     fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
         let add_ordering = |this: &mut Self| {
             if !this.order_by_set {
@@ -355,7 +390,18 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         }
     }
 
+    // SQL Server finds the recursive expressions without a keyword.
+    fn visit_recursive_keyword(&mut self) -> visitor::Result {
+        Ok(())
+    }
+
+    fn visit_fetch_first(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
+        self.visit_limit_and_offset(limit, offset)
+    }
+
     fn visit_insert(&mut self, insert: Insert<'a>) -> visitor::Result {
+        visitor::check_no_conflict_target(&insert)?;
+
         if let Some(returning) = insert.returning.as_ref().map(|r| r.clone()) {
             self.create_generated_keys(returning)?;
             self.write(" ")?;
@@ -447,6 +493,22 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         self.write(" ON ")?;
         self.visit_conditions(merge.using.on_conditions)?;
 
+        if !merge.when_matched.is_empty() {
+            self.write(" WHEN MATCHED THEN UPDATE SET ")?;
+
+            let len = merge.when_matched.len();
+
+            for (i, (column, value)) in merge.when_matched.into_iter().enumerate() {
+                self.visit_column(column)?;
+                self.write(" = ")?;
+                self.visit_expression(value)?;
+
+                if i < (len - 1) {
+                    self.write(", ")?;
+                }
+            }
+        }
+
         if let Some(query) = merge.when_not_matched {
             self.write(" WHEN NOT MATCHED THEN ")?;
             self.visit_query(query)?;
@@ -477,6 +539,99 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         })
     }
 
+    fn visit_aggregate_filter(&mut self, filter: AggregateFilter<'a>) -> visitor::Result {
+        self.visit_aggregate_filter_as_case(filter)
+    }
+
+    fn visit_values_table(&mut self, _: Values<'a>) -> visitor::Result {
+        let msg = "Using a VALUES list as a table is not implemented for SQL Server.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_column_type(&mut self, typ: ColumnType, _: bool) -> visitor::Result {
+        match typ {
+            ColumnType::Integer => self.write("INT"),
+            ColumnType::BigInt => self.write("BIGINT"),
+            ColumnType::Text => self.write("NVARCHAR(MAX)"),
+            ColumnType::Varchar(len) => self.write(format!("NVARCHAR({})", len)),
+            ColumnType::Boolean => self.write("BIT"),
+            ColumnType::Double => self.write("FLOAT(53)"),
+            ColumnType::DateTime => self.write("DATETIME2"),
+            ColumnType::Bytes => self.write("VARBINARY(MAX)"),
+        }
+    }
+
+    fn visit_auto_increment(&mut self) -> visitor::Result {
+        self.write(" IDENTITY(1,1)")
+    }
+
+    fn visit_referential_action(&mut self, action: ReferentialAction) -> visitor::Result {
+        match action {
+            // T-SQL has no `RESTRICT`, `NO ACTION` fails the same way.
+            ReferentialAction::Restrict | ReferentialAction::NoAction => self.write("NO ACTION"),
+            ReferentialAction::Cascade => self.write("CASCADE"),
+            ReferentialAction::SetNull => self.write("SET NULL"),
+            ReferentialAction::SetDefault => self.write("SET DEFAULT"),
+        }
+    }
+
+    fn visit_drop_index(&mut self, drop: DropIndex<'a>) -> visitor::Result {
+        self.write("DROP INDEX ")?;
+
+        if drop.if_exists {
+            self.write("IF EXISTS ")?;
+        }
+
+        self.delimited_identifiers(&[&*drop.name])?;
+        self.write(" ON ")?;
+        self.visit_table(drop.table, false)
+    }
+
+    fn visit_lock(&mut self, _: Lock) -> visitor::Result {
+        let msg = "Row-level locking clauses are not supported in T-SQL.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_create_table_as(&mut self, _: CreateTableAs<'a>) -> visitor::Result {
+        let msg = "CREATE TABLE AS is not supported in T-SQL, use SELECT INTO instead.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_lateral(&mut self) -> visitor::Result {
+        let msg = "LATERAL joins are not supported in T-SQL, use APPLY instead.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_pad(&mut self, _: Pad<'a>) -> visitor::Result {
+        let msg = "LPAD and RPAD functions are not supported in T-SQL.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
     // MSSQL doesn't support tuples, we do AND/OR.
     fn visit_multiple_tuple_comparison(&mut self, left: Row<'a>, right: Values<'a>, negate: bool) -> visitor::Result {
         let row_len = left.len();
@@ -557,8 +712,9 @@ impl<'a> Visitor<'a> for Mssql<'a> {
 mod tests {
     use crate::{
         ast::*,
+        error::ErrorKind,
         val,
-        visitor::{Mssql, Visitor},
+        visitor::{KeywordCase, Mssql, Visitor},
     };
     use indoc::indoc;
 
@@ -1458,7 +1614,7 @@ mod tests {
             "
             MERGE INTO [foo]
             USING (SELECT @P1 AS [wtf], @P2 AS [lol]) AS [dual] ([wtf],[lol])
-            ON (([foo].[bar] = @P3 AND [dual].[wtf] = [foo].[wtf]) OR (1=0 AND [dual].[lol] = [foo].[lol]))
+            ON ([foo].[bar] = @P3 AND [dual].[wtf] = [foo].[wtf])
             WHEN NOT MATCHED THEN
             INSERT ([wtf],[lol]) VALUES ([dual].[wtf],[dual].[lol]);
         "
@@ -1673,4 +1829,233 @@ mod tests {
             sql
         );
     }
+
+    #[test]
+    fn test_insert_over_the_default_parameter_limit() {
+        let insert = (0..1050).fold(Insert::multi_into("cats", vec!["name", "age"]), |insert, i| {
+            insert.values(vec![val!("Musti"), val!(i)])
+        });
+
+        let err = Mssql::build(insert).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::TooManyParameters { limit: 2098, got: 2100 }
+        ));
+    }
+
+    #[test]
+    fn test_substring() {
+        let expected = expected_values("SELECT SUBSTRING([name], @P1, @P2) FROM [users]", vec![2, 3]);
+        let query = Select::from_table("users").value(substring(Column::from("name"), 2, 3));
+        let (sql, params) = Mssql::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_drop_table_and_index() {
+        let (sql, _) = Mssql::build(DropTable::table("user").if_exists()).unwrap();
+        assert_eq!("DROP TABLE IF EXISTS [user]", sql);
+
+        let (sql, _) = Mssql::build(DropIndex::index("user_email_idx", "user")).unwrap();
+        assert_eq!("DROP INDEX [user_email_idx] ON [user]", sql);
+
+        let (sql, _) = Mssql::build(DropIndex::index("user_email_idx", "user").if_exists()).unwrap();
+        assert_eq!("DROP INDEX IF EXISTS [user_email_idx] ON [user]", sql);
+    }
+
+    #[test]
+    fn test_create_index() {
+        let query = CreateIndex::on("users", vec!["email"]).unique().name("idx_users_email");
+        let (sql, _) = Mssql::build(query).unwrap();
+
+        assert_eq!("CREATE UNIQUE INDEX [idx_users_email] ON [users] ([email])", sql);
+
+        let (sql, _) = Mssql::build(CreateIndex::on("users", vec!["name", "age"])).unwrap();
+
+        assert_eq!("CREATE INDEX [users_name_age_idx] ON [users] ([name], [age])", sql);
+    }
+
+    #[test]
+    fn test_merge_with_matched_and_not_matched_branches() {
+        let source = Select::default()
+            .value(Expression::from(1).alias("id"))
+            .value(Expression::from("Musti").alias("name"))
+            .value(Expression::from(7).alias("age"));
+
+        let using = source
+            .into_using(
+                "src",
+                vec![Column::from("id"), Column::from("name"), Column::from("age")],
+            )
+            .on(Column::from(("cats", "id")).equals(Column::from(("src", "id"))));
+
+        let insert = Insert::multi(vec!["id", "name", "age"]).values(vec![
+            Column::from(("src", "id")),
+            Column::from(("src", "name")),
+            Column::from(("src", "age")),
+        ]);
+
+        let merge = Merge::new("cats", using)
+            .when_matched_set("name", Column::from(("src", "name")))
+            .when_matched_set("age", Column::from(("src", "age")))
+            .when_not_matched(insert);
+
+        let (sql, params) = Mssql::build(merge).unwrap();
+
+        let expected_sql = indoc!(
+            "
+            MERGE INTO [cats]
+            USING (SELECT @P1 AS [id], @P2 AS [name], @P3 AS [age]) AS [src] ([id],[name],[age])
+            ON [cats].[id] = [src].[id]
+            WHEN MATCHED THEN UPDATE SET [name] = [src].[name], [age] = [src].[age]
+            WHEN NOT MATCHED THEN
+            INSERT ([id],[name],[age]) VALUES ([src].[id],[src].[name],[src].[age]);
+        "
+        );
+
+        assert_eq!(expected_sql.replace('\n', " ").trim(), sql);
+        assert_eq!(vec![Value::from(1), Value::from("Musti"), Value::from(7)], params);
+    }
+
+    #[test]
+    fn test_foreign_key_restrict_is_no_action() {
+        let foreign_key = ForeignKey::new(vec!["owner_id"], "users", vec!["id"]).on_delete(ReferentialAction::Restrict);
+
+        let query = CreateTable::new("cats")
+            .column("owner_id", ColumnType::Integer, vec![])
+            .foreign_key(foreign_key);
+
+        let (sql, _) = Mssql::build(query).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE [cats] ([owner_id] INT, FOREIGN KEY ([owner_id]) REFERENCES [users] ([id]) ON DELETE NO ACTION)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_fetch_first_uses_the_native_syntax() {
+        let query = Select::from_table("users")
+            .order_by("id")
+            .limit(10)
+            .offset(20)
+            .fetch_first();
+        let (sql, params) = Mssql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT [users].* FROM [users] ORDER BY [id] OFFSET @P1 ROWS FETCH NEXT @P2 ROWS ONLY",
+            sql
+        );
+        assert_eq!(vec![Value::from(20), Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_row_in_tuples_is_expanded() {
+        let cond = Row::from((Column::from("a"), Column::from("b"))).in_tuples(vec![(1, 2), (3, 4)]);
+        let (sql, params) = Mssql::build(Select::from_table("foo").so_that(cond)).unwrap();
+
+        assert_eq!(
+            "SELECT [foo].* FROM [foo] WHERE (([a] = @P1 AND [b] = @P2) OR ([a] = @P3 AND [b] = @P4))",
+            sql
+        );
+
+        assert_eq!(
+            vec![Value::from(1), Value::from(2), Value::from(3), Value::from(4)],
+            params
+        );
+    }
+
+    #[test]
+    fn test_create_table_as_select_is_not_supported() {
+        let select = Select::from_table("users").column("id");
+        let err = Mssql::build(select.into_table("snapshot")).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_bare_boolean_column_condition() {
+        let conditions = ConditionTree::from(Column::from("is_active")).and("age".greater_than(3));
+        let (sql, params) = Mssql::build(Select::from_table("users").so_that(conditions)).unwrap();
+
+        assert_eq!(
+            "SELECT [users].* FROM [users] WHERE ([is_active] = 1 AND [age] > @P1)",
+            sql
+        );
+        assert_eq!(vec![Value::from(3)], params);
+    }
+
+    #[test]
+    fn lowercase_keywords_keep_the_parameter_names() {
+        let query = Select::from_table("Cats").so_that("Name".equals("Musti"));
+        let (sql, _) = Mssql::build_with_keyword_case(query, KeywordCase::Lower).unwrap();
+
+        assert_eq!("select [Cats].* from [Cats] where [Name] = @P1", sql);
+    }
+
+    #[test]
+    fn recursive_cte_has_no_keyword() {
+        let anchor = Select::from_table("nodes").column("id").so_that("id".equals(1));
+
+        let recursive = Select::from_table("nodes")
+            .column(("nodes", "id"))
+            .inner_join("tree".on(("nodes", "parent_id").equals(Column::from(("tree", "id")))));
+
+        let query = Select::from_table("tree")
+            .column("id")
+            .with_recursive("tree", vec!["id"], anchor, recursive);
+
+        let (sql, _) = Mssql::build(query).unwrap();
+
+        assert_eq!(
+            "WITH [tree] ([id]) AS (SELECT [id] FROM [nodes] WHERE [id] = @P1 \
+             UNION ALL SELECT [nodes].[id] FROM [nodes] INNER JOIN [tree] ON [nodes].[parent_id] = [tree].[id]) \
+             SELECT [id] FROM [tree]",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let query = Select::from_table("users").value(count_distinct(Column::from("name")));
+        let (sql, _) = Mssql::build(query).unwrap();
+
+        assert_eq!("SELECT COUNT(DISTINCT [name]) FROM [users]", sql);
+    }
+
+    #[test]
+    fn test_count_distinct_multiple_columns_is_not_supported() {
+        let columns = vec![Column::from("a"), Column::from("b")];
+        let query = Select::from_table("users").value(count_distinct(columns));
+        let err = Mssql::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_conflict_target_is_not_supported() {
+        let target = ConflictTarget::columns(vec!["email"]).so_that("deleted".equals(false));
+        let insert = Insert::from(Insert::single_into("users").value("email", "musti@example.com"))
+            .on_conflict(OnConflict::DoNothing)
+            .conflict_target(target);
+
+        let err = Mssql::build(insert).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_values_table_is_not_implemented() {
+        let join = Column::from(("users", "id")).in_values_join(vec![1, 2, 3]);
+        let query = Select::from_table("users").inner_join(join);
+        let err = Mssql::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+        assert_eq!(
+            Some("Using a VALUES list as a table is not implemented for SQL Server."),
+            err.original_message()
+        );
+    }
 }
@@ -3,7 +3,10 @@ use crate::{
     error::{Error, ErrorKind},
     visitor::{self, Visitor},
 };
-use std::fmt::{self, Write};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 /// A visitor to generate queries for the MySQL database.
 ///
@@ -15,6 +18,70 @@ pub struct Mysql<'a> {
 }
 
 impl<'a> Mysql<'a> {
+    /// The table, columns and values of an `INSERT` or a `REPLACE`.
+    fn visit_insert_body(&mut self, insert: Insert<'a>) -> visitor::Result {
+        if let Some(table) = insert.table {
+            self.write("INTO ")?;
+            self.visit_table(table, true)?;
+        }
+
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } => {
+                if row.values.is_empty() {
+                    self.write(" () VALUES ()")?;
+                } else {
+                    let columns = insert.columns.len();
+
+                    self.write(" (")?;
+                    for (i, c) in insert.columns.into_iter().enumerate() {
+                        self.visit_column(c)?;
+
+                        if i < (columns - 1) {
+                            self.write(",")?;
+                        }
+                    }
+
+                    self.write(")")?;
+                    self.write(" VALUES ")?;
+                    self.visit_row(row)?;
+                }
+            }
+            Expression {
+                kind: ExpressionKind::Values(values),
+                ..
+            } => {
+                let columns = insert.columns.len();
+
+                self.write(" (")?;
+                for (i, c) in insert.columns.into_iter().enumerate() {
+                    self.visit_column(c)?;
+
+                    if i < (columns - 1) {
+                        self.write(",")?;
+                    }
+                }
+                self.write(")")?;
+
+                self.write(" VALUES ")?;
+                let values_len = values.len();
+
+                for (i, row) in values.into_iter().enumerate() {
+                    self.visit_row(row)?;
+
+                    if i < (values_len - 1) {
+                        self.write(", ")?;
+                    }
+                }
+            }
+            expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr))?,
+        }
+
+        Ok(())
+    }
+
     fn visit_regular_equality_comparison(&mut self, left: Expression<'a>, right: Expression<'a>) -> visitor::Result {
         self.visit_expression(left)?;
         self.write(" = ")?;
@@ -36,8 +103,10 @@ impl<'a> Visitor<'a> for Mysql<'a> {
     const C_BACKTICK_OPEN: &'static str = "`";
     const C_BACKTICK_CLOSE: &'static str = "`";
     const C_WILDCARD: &'static str = "%";
+    // The maximum number of placeholders in a MySQL prepared statement.
+    const C_MAX_PARAMETERS: usize = 65535;
 
-    fn build<Q>(query: Q) -> crate::Result<(String, Vec<Value<'a>>)>
+    fn build_with_parameter_limit<Q>(query: Q, limit: usize) -> crate::Result<(String, Vec<Value<'a>>)>
     where
         Q: Into<Query<'a>>,
     {
@@ -48,6 +117,22 @@ impl<'a> Visitor<'a> for Mysql<'a> {
 
         Mysql::visit_query(&mut mysql, query.into())?;
 
+        visitor::check_parameter_count(limit, mysql.parameters.len())?;
+
+        Ok((mysql.query, mysql.parameters))
+    }
+
+    fn build_condition<T>(conditions: T) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        let mut mysql = Mysql {
+            query: String::with_capacity(256),
+            parameters: Vec::with_capacity(16),
+        };
+
+        Mysql::visit_conditions(&mut mysql, conditions.into())?;
+
         Ok((mysql.query, mysql.parameters))
     }
 
@@ -113,79 +198,63 @@ impl<'a> Visitor<'a> for Mysql<'a> {
     }
 
     fn visit_insert(&mut self, insert: Insert<'a>) -> visitor::Result {
+        visitor::check_no_conflict_target(&insert)?;
+
         match insert.on_conflict {
             Some(OnConflict::DoNothing) => self.write("INSERT IGNORE ")?,
             None => self.write("INSERT ")?,
         };
 
-        if let Some(table) = insert.table {
-            self.write("INTO ")?;
-            self.visit_table(table, true)?;
-        }
+        self.visit_insert_body(insert)
+    }
 
-        match insert.values {
-            Expression {
-                kind: ExpressionKind::Row(row),
-                ..
-            } => {
-                if row.values.is_empty() {
-                    self.write(" () VALUES ()")?;
-                } else {
-                    let columns = insert.columns.len();
+    fn visit_optimizer_hints(&mut self, hints: Vec<Cow<'a, str>>) -> visitor::Result {
+        let hints: Vec<_> = hints.iter().map(|hint| visitor::sanitize_comment(hint)).collect();
 
-                    self.write(" (")?;
-                    for (i, c) in insert.columns.into_iter().enumerate() {
-                        self.visit_column(c)?;
+        self.write("/*+ ")?;
+        self.write(hints.join(" "))?;
+        self.write(" */ ")
+    }
 
-                        if i < (columns - 1) {
-                            self.write(",")?;
-                        }
-                    }
+    fn visit_replace(&mut self, replace: Replace<'a>) -> visitor::Result {
+        self.write("REPLACE ")?;
+        self.visit_insert_body(replace.insert)
+    }
 
-                    self.write(")")?;
-                    self.write(" VALUES ")?;
-                    self.visit_row(row)?;
-                }
-            }
-            Expression {
-                kind: ExpressionKind::Values(values),
-                ..
-            } => {
-                let columns = insert.columns.len();
+    fn parameter_substitution(&mut self) -> visitor::Result {
+        self.write("?")
+    }
 
-                self.write(" (")?;
-                for (i, c) in insert.columns.into_iter().enumerate() {
-                    self.visit_column(c)?;
+    fn add_parameter(&mut self, value: Value<'a>) {
+        self.parameters.push(value);
+    }
 
-                    if i < (columns - 1) {
-                        self.write(",")?;
-                    }
-                }
-                self.write(")")?;
+    fn visit_fetch_first(&mut self, _: Option<Value<'a>>, _: Option<Value<'a>>) -> visitor::Result {
+        let msg = "The FETCH FIRST syntax is not supported in MySQL, use LIMIT instead.";
+        let kind = ErrorKind::unsupported_feature(msg);
 
-                self.write(" VALUES ")?;
-                let values_len = values.len();
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
 
-                for (i, row) in values.into_iter().enumerate() {
-                    self.visit_row(row)?;
+        Err(builder.build())
+    }
 
-                    if i < (values_len - 1) {
-                        self.write(", ")?;
-                    }
-                }
+    fn visit_grouping_set(&mut self, grouping_set: GroupingSet<'a>) -> visitor::Result {
+        match grouping_set {
+            GroupingSet::Rollup(values) => {
+                self.visit_grouping(Grouping::new(values))?;
+                self.write(" WITH ROLLUP")
             }
-            expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr))?,
-        }
+            _ => {
+                let msg = "Only ROLLUP is supported from the grouping sets in MySQL.";
+                let kind = ErrorKind::unsupported_feature(msg);
 
-        Ok(())
-    }
-
-    fn parameter_substitution(&mut self) -> visitor::Result {
-        self.write("?")
-    }
+                let mut builder = Error::builder(kind);
+                builder.set_original_message(msg);
 
-    fn add_parameter(&mut self, value: Value<'a>) {
-        self.parameters.push(value);
+                Err(builder.build())
+            }
+        }
     }
 
     fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
@@ -218,6 +287,117 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         self.surround_with("(", ")", |ref mut s| s.visit_expression(value))
     }
 
+    fn visit_index_hints(&mut self, hints: Vec<IndexHint<'a>>) -> visitor::Result {
+        for hint in hints {
+            let (keyword, index) = match hint {
+                IndexHint::Use(index) => (" USE INDEX ", index),
+                IndexHint::Force(index) => (" FORCE INDEX ", index),
+                IndexHint::Ignore(index) => (" IGNORE INDEX ", index),
+            };
+
+            self.write(keyword)?;
+            self.surround_with("(", ")", |ref mut s| s.delimited_identifiers(&[&*index]))?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_count_distinct(&mut self, exprs: Vec<Expression<'a>>) -> visitor::Result {
+        self.write("COUNT")?;
+        self.surround_with("(DISTINCT ", ")", |ref mut s| s.visit_columns(exprs))
+    }
+
+    fn visit_partitions(&mut self, partitions: Vec<Cow<'a, str>>) -> visitor::Result {
+        self.write(" PARTITION ")?;
+
+        self.surround_with("(", ")", |ref mut s| {
+            for (i, partition) in partitions.iter().enumerate() {
+                if i > 0 {
+                    s.write(", ")?;
+                }
+
+                s.delimited_identifiers(&[&*partition])?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn visit_aggregate_filter(&mut self, filter: AggregateFilter<'a>) -> visitor::Result {
+        self.visit_aggregate_filter_as_case(filter)
+    }
+
+    fn visit_values_table(&mut self, _: Values<'a>) -> visitor::Result {
+        let msg = "Using a VALUES list as a table is not supported in MySQL.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_column_type(&mut self, typ: ColumnType, _: bool) -> visitor::Result {
+        match typ {
+            ColumnType::Integer => self.write("INT"),
+            ColumnType::BigInt => self.write("BIGINT"),
+            ColumnType::Text => self.write("TEXT"),
+            ColumnType::Varchar(len) => self.write(format!("VARCHAR({})", len)),
+            ColumnType::Boolean => self.write("BOOLEAN"),
+            ColumnType::Double => self.write("DOUBLE"),
+            ColumnType::DateTime => self.write("DATETIME(3)"),
+            ColumnType::Bytes => self.write("BLOB"),
+        }
+    }
+
+    fn visit_referential_action(&mut self, action: ReferentialAction) -> visitor::Result {
+        match action {
+            // Parsed, but rejected by InnoDB.
+            ReferentialAction::SetDefault => {
+                let msg = "ON DELETE/UPDATE SET DEFAULT is not supported in MySQL.";
+                let kind = ErrorKind::unsupported_feature(msg);
+
+                let mut builder = Error::builder(kind);
+                builder.set_original_message(msg);
+
+                Err(builder.build())
+            }
+            ReferentialAction::Cascade => self.write("CASCADE"),
+            ReferentialAction::SetNull => self.write("SET NULL"),
+            ReferentialAction::Restrict => self.write("RESTRICT"),
+            ReferentialAction::NoAction => self.write("NO ACTION"),
+        }
+    }
+
+    fn visit_drop_index(&mut self, drop: DropIndex<'a>) -> visitor::Result {
+        if drop.if_exists {
+            let msg = "DROP INDEX IF EXISTS is not supported in MySQL.";
+            let kind = ErrorKind::unsupported_feature(msg);
+
+            let mut builder = Error::builder(kind);
+            builder.set_original_message(msg);
+
+            return Err(builder.build());
+        }
+
+        self.write("DROP INDEX ")?;
+        self.delimited_identifiers(&[&*drop.name])?;
+        self.write(" ON ")?;
+        self.visit_table(drop.table, false)
+    }
+
+    fn visit_lock(&mut self, lock: Lock) -> visitor::Result {
+        match (lock.typ, lock.wait) {
+            // `FOR SHARE` is only available from MySQL 8.0 on.
+            (LockType::Share, LockWait::Wait) => self.write(" LOCK IN SHARE MODE"),
+            (LockType::Update, LockWait::Wait) => self.write(" FOR UPDATE"),
+            (LockType::Update, LockWait::NoWait) => self.write(" FOR UPDATE NOWAIT"),
+            (LockType::Update, LockWait::SkipLocked) => self.write(" FOR UPDATE SKIP LOCKED"),
+            (LockType::Share, LockWait::NoWait) => self.write(" FOR SHARE NOWAIT"),
+            (LockType::Share, LockWait::SkipLocked) => self.write(" FOR SHARE SKIP LOCKED"),
+        }
+    }
+
     fn visit_equals(&mut self, left: Expression<'a>, right: Expression<'a>) -> visitor::Result {
         #[cfg(feature = "json")]
         {
@@ -281,7 +461,7 @@ impl<'a> Visitor<'a> for Mysql<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::visitor::*;
+    use crate::{error::ErrorKind, visitor::*};
 
     fn expected_values<'a, T>(sql: &'static str, params: Vec<T>) -> (String, Vec<Value<'a>>)
     where
@@ -561,4 +741,624 @@ mod tests {
             sql
         );
     }
+
+    #[test]
+    fn test_select_for_update_skip_locked() {
+        let query = Select::from_table("jobs")
+            .so_that("done".equals(false))
+            .limit(1)
+            .skip_locked();
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `jobs`.* FROM `jobs` WHERE `done` = ? LIMIT ? FOR UPDATE SKIP LOCKED",
+            sql
+        );
+        assert_eq!(vec![Value::from(false), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_select_for_share() {
+        let (sql, _) = Mysql::build(Select::from_table("jobs").for_share()).unwrap();
+        assert_eq!("SELECT `jobs`.* FROM `jobs` LOCK IN SHARE MODE", sql);
+    }
+
+    #[test]
+    fn test_in_array_zero_chunk_size_uses_the_default() {
+        let query = Select::from_table("users").so_that("id".in_array(vec![1, 2, 3]).chunk_size(0));
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE `id` IN (?,?,?)", sql);
+    }
+
+    #[test]
+    fn test_in_array_variant_with_zero_chunk_size_uses_the_default() {
+        let values = vec![Value::from(1), Value::from(2), Value::from(3)];
+        let cond = Compare::InArray(Box::new(Expression::from(Column::from("id"))), values, 0);
+        let (sql, params) = Mysql::build(Select::from_table("users").so_that(cond)).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE `id` IN (?,?,?)", sql);
+        assert_eq!(vec![Value::from(1), Value::from(2), Value::from(3)], params);
+    }
+
+    #[test]
+    fn test_in_array_is_chunked() {
+        let query = Select::from_table("users").so_that("id".in_array(vec![1, 2, 3, 4, 5]).chunk_size(2));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE (`id` IN (?,?) OR `id` IN (?,?) OR `id` IN (?))",
+            sql
+        );
+        assert_eq!(
+            vec![1, 2, 3, 4, 5].into_iter().map(Value::from).collect::<Vec<_>>(),
+            params
+        );
+    }
+
+    #[test]
+    fn test_in_array_in_one_chunk() {
+        let query = Select::from_table("users").so_that("id".in_array(vec![1, 2]));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE `id` IN (?,?)", sql);
+        assert_eq!(vec![Value::from(1), Value::from(2)], params);
+    }
+
+    #[test]
+    fn test_returning_update_is_not_supported() {
+        let update = Update::table("users").set("name", "Musti").returning(vec!["id"]);
+        let err = Mysql::build(update).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_substring() {
+        let expected = expected_values("SELECT SUBSTRING(`name`, ?, ?) FROM `users`", vec![2, 3]);
+        let query = Select::from_table("users").value(substring(Column::from("name"), 2, 3));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_lpad() {
+        let query = Select::from_table("users").value(lpad(Column::from("id"), 8, "0").alias("code"));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT LPAD(`id`, ?, ?) AS `code` FROM `users`", sql);
+        assert_eq!(vec![Value::from(8), Value::from("0")], params);
+    }
+
+    #[test]
+    fn test_greatest() {
+        let expected = expected_values("SELECT GREATEST(`a`, `b`, ?) FROM `cats`", vec![0]);
+        let query = Select::from_table("cats").value(greatest(vec![
+            Column::from("a").into(),
+            Column::from("b").into(),
+            Expression::from(0),
+        ]));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_default_cell_in_multi_insert() {
+        let insert = Insert::multi_into("cats", vec!["name", "age", "color"])
+            .values(vec![
+                Expression::from("Musti"),
+                default_value(),
+                Expression::from("black"),
+            ])
+            .values(vec![Expression::from("Naukio"), Expression::from(7), default_value()]);
+
+        let expected = expected_values(
+            "INSERT INTO `cats` (`name`,`age`,`color`) VALUES (?,DEFAULT,?), (?,?,DEFAULT)",
+            vec![
+                Value::from("Musti"),
+                Value::from("black"),
+                Value::from("Naukio"),
+                Value::from(7),
+            ],
+        );
+
+        let (sql, params) = Mysql::build(insert).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_order_by_ordinal() {
+        let query = Select::from_table("orders")
+            .column("user_id")
+            .value(count(asterisk()))
+            .group_by("user_id")
+            .order_by(ordinal(2).descend());
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `user_id`, COUNT(*) FROM `orders` GROUP BY `user_id` ORDER BY 2 DESC",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_order_by_alias() {
+        let query = Select::from_table("orders")
+            .column("user_id")
+            .value(sum(Column::from("price")).alias("total"))
+            .group_by("user_id")
+            .order_by("total");
+
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `user_id`, SUM(`price`) AS `total` FROM `orders` GROUP BY `user_id` ORDER BY `total`",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_aggregate_filter_as_case() {
+        let query = Select::from_table("users")
+            .value(count_star().filter("status".equals("active")).alias("active"))
+            .value(sum(Column::from("score")).filter("age".greater_than(18)))
+            .value(count(Column::from("email")).filter("verified".equals(true)));
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT COUNT(CASE WHEN `status` = ? THEN 1 END) AS `active`, \
+             SUM(CASE WHEN `age` > ? THEN `score` END), \
+             COUNT(CASE WHEN `verified` = ? THEN `email` END) FROM `users`",
+            sql
+        );
+        assert_eq!(vec![Value::from("active"), Value::from(18), Value::from(true)], params);
+    }
+
+    #[test]
+    fn test_aggregate_filter_unsupported_function() {
+        let query = Select::from_table("users").value(lower(Column::from("name")).filter("age".greater_than(18)));
+        let err = Mysql::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_collate_in_order_by_and_where() {
+        let query = Select::from_table("users")
+            .so_that(Column::from("name").collate("utf8mb4_unicode_ci").equals("musti"))
+            .order_by(Column::from("name").collate("utf8mb4_unicode_ci").descend());
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `name` COLLATE utf8mb4_unicode_ci = ? \
+             ORDER BY `name` COLLATE utf8mb4_unicode_ci DESC",
+            sql
+        );
+        assert_eq!(vec![Value::from("musti")], params);
+    }
+
+    #[test]
+    fn test_collate_rejects_unsafe_names() {
+        let query = Select::from_table("users").order_by(Column::from("name").collate("x; DROP TABLE users"));
+        let err = Mysql::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[test]
+    fn test_force_index() {
+        let table = Table::from("users").alias("u").force_index("idx_name");
+        let query = Select::from_table(table).so_that(("u", "name").equals("Musti"));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `u`.* FROM `users` AS `u` FORCE INDEX (`idx_name`) WHERE `u`.`name` = ?",
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti")], params);
+    }
+
+    #[test]
+    fn test_partitions_in_insert_update_and_delete() {
+        let table = || Table::from("events").partition(vec!["p0"]);
+
+        let insert = Insert::single_into(table()).value("kind", "login");
+        let (sql, _) = Mysql::build(insert).unwrap();
+        assert_eq!("INSERT INTO `events` PARTITION (`p0`) (`kind`) VALUES (?)", sql);
+
+        let update = Update::table(table()).set("kind", "logout").so_that("id".equals(1));
+        let (sql, _) = Mysql::build(update).unwrap();
+        assert_eq!("UPDATE `events` PARTITION (`p0`) SET `kind` = ? WHERE `id` = ?", sql);
+
+        let delete = Delete::from_table(table()).so_that("id".equals(1));
+        let (sql, _) = Mysql::build(delete).unwrap();
+        assert_eq!("DELETE FROM `events` PARTITION (`p0`) WHERE `id` = ?", sql);
+    }
+
+    #[test]
+    fn test_partitions_with_alias_and_index_hint() {
+        let table = Table::from("events")
+            .partition(vec!["p0", "p1"])
+            .alias("e")
+            .use_index("idx_created");
+
+        let query = Select::from_table(table).so_that(("e", "kind").equals("login"));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `e`.* FROM `events` PARTITION (`p0`, `p1`) AS `e` USE INDEX (`idx_created`) WHERE `e`.`kind` = ?",
+            sql
+        );
+        assert_eq!(vec![Value::from("login")], params);
+    }
+
+    #[test]
+    fn test_multiple_index_hints() {
+        let table = Table::from("users").use_index("idx_a").ignore_index("idx_b");
+        let (sql, _) = Mysql::build(Select::from_table(table)).unwrap();
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` USE INDEX (`idx_a`) IGNORE INDEX (`idx_b`)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_build_condition() {
+        let conditions = "a".equals("foo").and("b".greater_than(2));
+        let (sql, params) = Mysql::build_condition(conditions).unwrap();
+
+        assert_eq!("(`a` = ? AND `b` > ?)", sql);
+        assert_eq!(vec![Value::from("foo"), Value::from(2)], params);
+    }
+
+    #[test]
+    fn test_build_single_condition() {
+        let (sql, params) = Mysql::build_condition("a".equals(1)).unwrap();
+
+        assert_eq!("`a` = ?", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let query = CreateTable::new("users")
+            .column(
+                "id",
+                ColumnType::Integer,
+                vec![ColumnConstraint::PrimaryKey, ColumnConstraint::AutoIncrement],
+            )
+            .column("name", ColumnType::Varchar(255), vec![ColumnConstraint::NotNull])
+            .column("age", ColumnType::Integer, vec![])
+            .column("email", ColumnType::Text, vec![ColumnConstraint::Unique]);
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE `users` (`id` INT PRIMARY KEY AUTO_INCREMENT, `name` VARCHAR(255) NOT NULL, \
+             `age` INT, `email` TEXT UNIQUE)",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_drop_table() {
+        let (sql, _) = Mysql::build(DropTable::table("user")).unwrap();
+        assert_eq!("DROP TABLE `user`", sql);
+
+        let (sql, _) = Mysql::build(DropTable::table("user").if_exists()).unwrap();
+        assert_eq!("DROP TABLE IF EXISTS `user`", sql);
+    }
+
+    #[test]
+    fn test_drop_index() {
+        let (sql, _) = Mysql::build(DropIndex::index("user_email_idx", "user")).unwrap();
+        assert_eq!("DROP INDEX `user_email_idx` ON `user`", sql);
+
+        let err = Mysql::build(DropIndex::index("user_email_idx", "user").if_exists()).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_create_index() {
+        let query = CreateIndex::on("users", vec!["email"]).unique().name("idx_users_email");
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!("CREATE UNIQUE INDEX `idx_users_email` ON `users` (`email`)", sql);
+
+        let (sql, _) = Mysql::build(CreateIndex::on("users", vec!["name", "age"])).unwrap();
+
+        assert_eq!("CREATE INDEX `users_name_age_idx` ON `users` (`name`, `age`)", sql);
+    }
+
+    #[test]
+    fn test_raw_right_hand_side() {
+        let query = Select::from_table("users").so_that("created".equals(raw("CURRENT_DATE")));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE `created` = CURRENT_DATE", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_subquery_right_hand_side() {
+        let subquery = Select::from_table("t").value(max("y"));
+        let query = Select::from_table("users").so_that("x".less_than(subquery));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` WHERE `x` < (SELECT MAX(`y`) FROM `t`)",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_trace_comment() {
+        let query = Select::from_table("cats").so_that("id".equals(1));
+        let (sql, params) = Mysql::build_with_comment(query, "traceparent='00-abc-01'").unwrap();
+
+        assert_eq!(
+            "SELECT `cats`.* FROM `cats` WHERE `id` = ? /* traceparent='00-abc-01' */",
+            sql
+        );
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_trace_comment_cannot_close_the_comment() {
+        let query = Select::from_table("cats");
+        let (sql, _) = Mysql::build_with_comment(query, "x */ DROP TABLE cats; /* *//").unwrap();
+
+        assert_eq!("SELECT `cats`.* FROM `cats` /* x  DROP TABLE cats;  / */", sql);
+    }
+
+    #[test]
+    fn test_select_list_mixing_columns_and_expressions() {
+        let query = Select::from_table("users")
+            .column("name")
+            .values(vec![
+                Expression::from(sum("score")).alias("score"),
+                Expression::from(Column::from("age")) - Expression::from(1),
+            ])
+            .so_that("id".greater_than(10));
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `name`, SUM(`score`) AS `score`, (`age` - ?) FROM `users` WHERE `id` > ?",
+            sql
+        );
+        assert_eq!(vec![Value::from(1), Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_create_table_with_cascading_foreign_key() {
+        let foreign_key = ForeignKey::new(vec!["owner_id"], "users", vec!["id"])
+            .name("cats_owner_fkey")
+            .on_delete(ReferentialAction::Cascade)
+            .on_update(ReferentialAction::Restrict);
+
+        let query = CreateTable::new("cats")
+            .column("id", ColumnType::Integer, vec![ColumnConstraint::PrimaryKey])
+            .column("owner_id", ColumnType::Integer, vec![])
+            .foreign_key(foreign_key);
+
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE `cats` (`id` INT PRIMARY KEY, `owner_id` INT, CONSTRAINT `cats_owner_fkey` \
+             FOREIGN KEY (`owner_id`) REFERENCES `users` (`id`) ON DELETE CASCADE ON UPDATE RESTRICT)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_set_default_is_not_supported() {
+        let foreign_key =
+            ForeignKey::new(vec!["owner_id"], "users", vec!["id"]).on_delete(ReferentialAction::SetDefault);
+
+        let query = CreateTable::new("cats")
+            .column("owner_id", ColumnType::Integer, vec![])
+            .foreign_key(foreign_key);
+
+        let err = Mysql::build(query).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_left_join_lateral() {
+        let sub = Select::from_table("posts")
+            .column("title")
+            .so_that(("posts", "user_id").equals(Column::from(("users", "id"))));
+
+        let join = Table::from(sub).alias("p").on(ConditionTree::NoCondition).lateral();
+        let query = Select::from_table("users").left_join(join);
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `users`.* FROM `users` LEFT JOIN LATERAL (SELECT `title` FROM `posts` \
+             WHERE `posts`.`user_id` = `users`.`id`) AS `p` ON 1=1",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_replace_multiple_rows() {
+        let insert = Insert::multi_into("users", vec!["id", "name"])
+            .values(vec![Value::from(1), Value::from("Musti")])
+            .values(vec![Value::from(2), Value::from("Naukio")]);
+
+        let (sql, params) = Mysql::build(Replace::new(insert)).unwrap();
+
+        assert_eq!("REPLACE INTO `users` (`id`,`name`) VALUES (?,?), (?,?)", sql);
+        assert_eq!(
+            vec![
+                Value::from(1),
+                Value::from("Musti"),
+                Value::from(2),
+                Value::from("Naukio")
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn test_select_with_optimizer_hints() {
+        let query = Select::from_table("users")
+            .column("name")
+            .optimizer_hint("MAX_EXECUTION_TIME(1000)")
+            .optimizer_hint("NO_INDEX_MERGE(users)")
+            .distinct();
+
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT /*+ MAX_EXECUTION_TIME(1000) NO_INDEX_MERGE(users) */ DISTINCT `name` FROM `users`",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_optimizer_hint_cannot_close_the_comment() {
+        let query = Select::from_table("users").optimizer_hint("BKA(users) */ DROP TABLE users; /*");
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT /*+ BKA(users)  DROP TABLE users;  */ `users`.* FROM `users`",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_update_increment_column() {
+        let views = Expression::from(Column::from("views")) + Expression::from(1);
+        let query = Update::table("posts").set("views", views).so_that("id".equals(10));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!("UPDATE `posts` SET `views` = (`views` + ?) WHERE `id` = ?", sql);
+        assert_eq!(vec![Value::from(1), Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_fetch_first_is_not_supported() {
+        let query = Select::from_table("users").limit(10).fetch_first();
+        let err = Mysql::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_create_table_as_select() {
+        let select = Select::from_table("users")
+            .column("id")
+            .so_that("age".greater_than(18))
+            .limit(10);
+
+        let (sql, params) = Mysql::build(select.into_table("snapshot")).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE `snapshot` AS SELECT `id` FROM `users` WHERE `age` > ? LIMIT ?",
+            sql
+        );
+        assert_eq!(vec![Value::from(18), Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_typed_text_is_a_cast() {
+        let query = Select::default().value(typed_text("12:34", "TIME"));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT CAST(? AS TIME)", sql);
+        assert_eq!(vec![Value::from("12:34")], params);
+    }
+
+    #[test]
+    fn test_group_by_with_rollup() {
+        let query = Select::from_table("sales")
+            .column("region")
+            .column("city")
+            .value(sum(Column::from("amount")))
+            .group_by_rollup(vec!["region", "city"]);
+
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT `region`, `city`, SUM(`amount`) FROM `sales` GROUP BY `region`, `city` WITH ROLLUP",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_group_by_cube_is_not_supported() {
+        let query = Select::from_table("sales")
+            .value(sum(Column::from("amount")))
+            .group_by_cube(vec!["region"]);
+
+        let err = Mysql::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_bare_boolean_column_condition() {
+        let query = Select::from_table("users").so_that(Column::from("is_active"));
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE `is_active`", sql);
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let query = Select::from_table("users").value(count_distinct(Column::from("name")));
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT COUNT(DISTINCT `name`) FROM `users`", sql);
+    }
+
+    #[test]
+    fn test_count_distinct_multiple_columns() {
+        let columns = vec![Column::from("a"), Column::from("b")];
+        let query = Select::from_table("users").value(count_distinct(columns).alias("pairs"));
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!("SELECT COUNT(DISTINCT `a`, `b`) AS `pairs` FROM `users`", sql);
+    }
+
+    #[test]
+    fn test_count_distinct_filter_as_case() {
+        let query =
+            Select::from_table("users").value(count_distinct(Column::from("email")).filter("verified".equals(true)));
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT COUNT(DISTINCT CASE WHEN `verified` = ? THEN `email` END) FROM `users`",
+            sql
+        );
+        assert_eq!(vec![Value::from(true)], params);
+    }
+
+    #[test]
+    fn test_conflict_target_is_not_supported() {
+        let target = ConflictTarget::columns(vec!["email"]).so_that("deleted".equals(false));
+        let insert = Insert::from(Insert::single_into("users").value("email", "musti@example.com"))
+            .on_conflict(OnConflict::DoNothing)
+            .conflict_target(target);
+
+        let err = Mysql::build(insert).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
 }
@@ -0,0 +1,238 @@
+use crate::{
+    ast::*,
+    visitor::{self, Visitor},
+};
+use std::{borrow::Cow, fmt};
+
+/// A visitor only collecting the parameters of a query in the order they
+/// appear in the AST, discarding the SQL. Used by
+/// [`Query::parameters`](../ast/enum.Query.html#method.parameters).
+pub(crate) struct ParameterCollector<'a> {
+    parameters: Vec<Value<'a>>,
+}
+
+impl<'a> ParameterCollector<'a> {
+    /// The number of parameters in the row.
+    pub(crate) fn count_in_row(row: Row<'a>) -> crate::Result<usize> {
+        let mut collector = ParameterCollector {
+            parameters: Vec::with_capacity(row.len()),
+        };
+
+        collector.visit_row(row)?;
+
+        Ok(collector.parameters.len())
+    }
+}
+
+impl<'a> Visitor<'a> for ParameterCollector<'a> {
+    const C_BACKTICK_OPEN: &'static str = "\"";
+    const C_BACKTICK_CLOSE: &'static str = "\"";
+    const C_WILDCARD: &'static str = "%";
+    const C_MAX_PARAMETERS: usize = usize::MAX;
+
+    fn build_with_parameter_limit<Q>(query: Q, limit: usize) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        Q: Into<Query<'a>>,
+    {
+        let mut collector = ParameterCollector {
+            parameters: Vec::with_capacity(128),
+        };
+
+        ParameterCollector::visit_query(&mut collector, query.into())?;
+
+        visitor::check_parameter_count(limit, collector.parameters.len())?;
+
+        Ok((String::new(), collector.parameters))
+    }
+
+    fn build_condition<T>(conditions: T) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        let mut collector = ParameterCollector {
+            parameters: Vec::with_capacity(16),
+        };
+
+        ParameterCollector::visit_conditions(&mut collector, conditions.into())?;
+
+        Ok((String::new(), collector.parameters))
+    }
+
+    fn write<D: fmt::Display>(&mut self, _: D) -> visitor::Result {
+        Ok(())
+    }
+
+    fn add_parameter(&mut self, value: Value<'a>) {
+        self.parameters.push(value);
+    }
+
+    fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
+        if let Some(limit) = limit {
+            self.visit_parameterized(limit)?;
+        }
+
+        if let Some(offset) = offset {
+            self.visit_parameterized(offset)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_fetch_first(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
+        if let Some(offset) = offset {
+            self.visit_parameterized(offset)?;
+        }
+
+        if let Some(limit) = limit {
+            self.visit_parameterized(limit)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_insert(&mut self, insert: Insert<'a>) -> visitor::Result {
+        // The conflict target is written only with an `ON CONFLICT` clause,
+        // the other inserts with a target fail to build.
+        let predicate = match insert.on_conflict {
+            Some(_) => insert.conflict_target.and_then(|target| target.predicate),
+            None => None,
+        };
+
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } => self.visit_row(row)?,
+            Expression {
+                kind: ExpressionKind::Values(values),
+                ..
+            } => {
+                for row in values.into_iter() {
+                    self.visit_row(row)?;
+                }
+            }
+            expr => self.visit_expression(expr)?,
+        }
+
+        if let Some(predicate) = predicate {
+            self.visit_conditions(predicate)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_replace(&mut self, replace: Replace<'a>) -> visitor::Result {
+        self.visit_insert(replace.insert)
+    }
+
+    fn visit_merge(&mut self, merge: Merge<'a>) -> visitor::Result {
+        self.visit_query(merge.using.base_query)?;
+        self.visit_conditions(merge.using.on_conditions)?;
+
+        for (_, value) in merge.when_matched {
+            self.visit_expression(value)?;
+        }
+
+        if let Some(query) = merge.when_not_matched {
+            self.visit_query(query)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_update_returning(&mut self, _: Vec<Column<'a>>) -> visitor::Result {
+        Ok(())
+    }
+
+    fn parameter_substitution(&mut self) -> visitor::Result {
+        Ok(())
+    }
+
+    fn visit_aggregate_to_string(&mut self, value: Expression<'a>) -> visitor::Result {
+        self.visit_expression(value)
+    }
+
+    fn visit_raw_value(&mut self, _: Value<'a>) -> visitor::Result {
+        Ok(())
+    }
+
+    fn visit_index_hints(&mut self, _: Vec<IndexHint<'a>>) -> visitor::Result {
+        Ok(())
+    }
+
+    fn visit_partitions(&mut self, _: Vec<Cow<'a, str>>) -> visitor::Result {
+        Ok(())
+    }
+
+    fn visit_count_distinct(&mut self, exprs: Vec<Expression<'a>>) -> visitor::Result {
+        self.visit_columns(exprs)
+    }
+
+    fn visit_collate(&mut self, expr: Expression<'a>, _: Cow<'a, str>) -> visitor::Result {
+        self.visit_expression(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::*;
+
+    #[test]
+    fn select_parameters() {
+        let query: Query = Select::from_table("cats")
+            .so_that("name".equals("Musti").and("age".in_selection(vec![3, 4])))
+            .limit(10)
+            .offset(20)
+            .into();
+
+        assert_eq!(
+            vec![
+                Value::from("Musti"),
+                Value::from(3),
+                Value::from(4),
+                Value::from(10),
+                Value::from(20)
+            ],
+            query.parameters()
+        );
+    }
+
+    #[test]
+    fn insert_parameters() {
+        let query: Query = Insert::multi_into("cats", vec!["name", "age"])
+            .values(vec![Value::from("Musti"), Value::from(5)])
+            .values(vec![Value::from("Naukio"), Value::from(6)])
+            .into();
+
+        assert_eq!(
+            vec![
+                Value::from("Musti"),
+                Value::from(5),
+                Value::from("Naukio"),
+                Value::from(6)
+            ],
+            query.parameters()
+        );
+    }
+
+    #[test]
+    fn update_parameters() {
+        let query: Query = Update::table("cats")
+            .set("name", "Musti")
+            .set("age", 5)
+            .so_that("id".equals(1))
+            .into();
+
+        assert_eq!(
+            vec![Value::from("Musti"), Value::from(5), Value::from(1)],
+            query.parameters()
+        );
+    }
+
+    #[test]
+    fn raw_query_has_no_parameters() {
+        let query = Query::from("SELECT 1");
+
+        assert!(query.parameters().is_empty());
+    }
+}
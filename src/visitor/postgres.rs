@@ -2,7 +2,10 @@ use crate::{
     ast::*,
     visitor::{self, Visitor},
 };
-use std::fmt::{self, Write};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 /// A visitor to generate queries for the PostgreSQL database.
 ///
@@ -18,8 +21,10 @@ impl<'a> Visitor<'a> for Postgres<'a> {
     const C_BACKTICK_OPEN: &'static str = "\"";
     const C_BACKTICK_CLOSE: &'static str = "\"";
     const C_WILDCARD: &'static str = "%";
+    // The maximum number of bind parameters in the PostgreSQL protocol.
+    const C_MAX_PARAMETERS: usize = 65535;
 
-    fn build<Q>(query: Q) -> crate::Result<(String, Vec<Value<'a>>)>
+    fn build_with_parameter_limit<Q>(query: Q, limit: usize) -> crate::Result<(String, Vec<Value<'a>>)>
     where
         Q: Into<Query<'a>>,
     {
@@ -30,6 +35,22 @@ impl<'a> Visitor<'a> for Postgres<'a> {
 
         Postgres::visit_query(&mut postgres, query.into())?;
 
+        visitor::check_parameter_count(limit, postgres.parameters.len())?;
+
+        Ok((postgres.query, postgres.parameters))
+    }
+
+    fn build_condition<T>(conditions: T) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        let mut postgres = Postgres {
+            query: String::with_capacity(256),
+            parameters: Vec::with_capacity(16),
+        };
+
+        Postgres::visit_conditions(&mut postgres, conditions.into())?;
+
         Ok((postgres.query, postgres.parameters))
     }
 
@@ -125,6 +146,10 @@ impl<'a> Visitor<'a> for Postgres<'a> {
     }
 
     fn visit_insert(&mut self, insert: Insert<'a>) -> visitor::Result {
+        if insert.on_conflict.is_none() {
+            visitor::check_no_conflict_target(&insert)?;
+        }
+
         self.write("INSERT ")?;
 
         if let Some(table) = insert.table {
@@ -187,7 +212,29 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         }
 
         if let Some(OnConflict::DoNothing) = insert.on_conflict {
-            self.write(" ON CONFLICT DO NOTHING")?;
+            self.write(" ON CONFLICT")?;
+
+            if let Some(target) = insert.conflict_target {
+                let len = target.columns.len();
+                self.write(" (")?;
+
+                for (i, c) in target.columns.into_iter().enumerate() {
+                    self.visit_column(c.name.into_owned().into())?;
+
+                    if i < (len - 1) {
+                        self.write(", ")?;
+                    }
+                }
+
+                self.write(")")?;
+
+                if let Some(predicate) = target.predicate {
+                    self.write(" WHERE ")?;
+                    self.visit_conditions(predicate)?;
+                }
+            }
+
+            self.write(" DO NOTHING")?;
         };
 
         if let Some(returning) = insert.returning {
@@ -261,6 +308,125 @@ impl<'a> Visitor<'a> for Postgres<'a> {
 
         Ok(())
     }
+
+    fn visit_update_returning(&mut self, columns: Vec<Column<'a>>) -> visitor::Result {
+        if !columns.is_empty() {
+            let values = columns.into_iter().map(|c| c.into()).collect();
+            self.write(" RETURNING ")?;
+            self.visit_columns(values)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_in_array(&mut self, left: Expression<'a>, values: Vec<Value<'a>>, _: usize) -> visitor::Result {
+        self.visit_expression(left)?;
+        self.write(" = ANY(")?;
+        self.visit_parameterized(Value::Array(Some(values)))?;
+        self.write(")")
+    }
+
+    fn visit_typed_text(&mut self, text: Cow<'a, str>, type_name: Cow<'a, str>) -> visitor::Result {
+        // A parameter of the named type would be sent in its binary format,
+        // casting from text makes the database parse the literal instead.
+        self.visit_parameterized(Value::Text(Some(text)))?;
+        self.write("::text::")?;
+        self.write(type_name)
+    }
+
+    fn visit_collate(&mut self, expr: Expression<'a>, collation: Cow<'a, str>) -> visitor::Result {
+        // The names are case-sensitive, and the ICU collations such as
+        // `und-x-icu` have characters needing quotes.
+        visitor::check_collation(&collation, &['-', '.'])?;
+
+        self.visit_expression(expr)?;
+        self.write(" COLLATE ")?;
+        self.delimited_identifiers(&[&*collation])
+    }
+
+    fn visit_column_type(&mut self, typ: ColumnType, auto_increment: bool) -> visitor::Result {
+        match typ {
+            ColumnType::Integer if auto_increment => self.write("SERIAL"),
+            ColumnType::BigInt if auto_increment => self.write("BIGSERIAL"),
+            ColumnType::Integer => self.write("int4"),
+            ColumnType::BigInt => self.write("int8"),
+            ColumnType::Text => self.write("text"),
+            ColumnType::Varchar(len) => self.write(format!("varchar({})", len)),
+            ColumnType::Boolean => self.write("boolean"),
+            ColumnType::Double => self.write("float8"),
+            ColumnType::DateTime => self.write("timestamptz"),
+            ColumnType::Bytes => self.write("bytea"),
+        }
+    }
+
+    fn visit_auto_increment(&mut self) -> visitor::Result {
+        // Handled by the serial types.
+        Ok(())
+    }
+
+    fn visit_values_table(&mut self, values: Values<'a>) -> visitor::Result {
+        self.surround_with("(VALUES ", ")", |ref mut s| {
+            let len = values.len();
+            for (i, row) in values.into_iter().enumerate() {
+                // Untyped parameters in a `VALUES` list resolve to `text`, so
+                // the first row sets the column types with explicit casts.
+                if i == 0 {
+                    s.surround_with("(", ")", |ref mut s| {
+                        let len = row.values.len();
+                        for (j, value) in row.values.into_iter().enumerate() {
+                            let cast = match value.kind() {
+                                ExpressionKind::Parameterized(val) => parameter_cast(val),
+                                _ => None,
+                            };
+
+                            s.visit_expression(value)?;
+
+                            if let Some(cast) = cast {
+                                s.write(cast)?;
+                            }
+
+                            if j < (len - 1) {
+                                s.write(",")?;
+                            }
+                        }
+
+                        Ok(())
+                    })?;
+                } else {
+                    s.visit_row(row)?;
+                }
+
+                if i < (len - 1) {
+                    s.write(",")?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+fn parameter_cast(value: &Value<'_>) -> Option<&'static str> {
+    let cast = match value {
+        Value::Integer(_) => "::int8",
+        Value::Float(_) => "::float4",
+        Value::Double(_) => "::float8",
+        Value::Text(_) => "::text",
+        Value::Boolean(_) => "::bool",
+        Value::Bytes(_) => "::bytea",
+        #[cfg(feature = "bigdecimal")]
+        Value::Numeric(_) => "::numeric",
+        #[cfg(feature = "uuid")]
+        Value::Uuid(_) => "::uuid",
+        #[cfg(feature = "chrono")]
+        Value::DateTime(_) => "::timestamptz",
+        #[cfg(feature = "chrono")]
+        Value::Date(_) => "::date",
+        #[cfg(feature = "chrono")]
+        Value::Time(_) => "::time",
+        _ => return None,
+    };
+
+    Some(cast)
 }
 
 #[cfg(test)]
@@ -631,4 +797,729 @@ mod tests {
 
         assert_eq!("SELECT \"User\".*, \"Toto\".* FROM \"User\" LEFT JOIN \"Post\" AS \"p\" ON \"p\".\"userId\" = \"User\".\"id\", \"Toto\"", sql);
     }
+
+    #[test]
+    fn test_select_for_update_skip_locked() {
+        let query = Select::from_table("jobs")
+            .so_that("done".equals(false))
+            .limit(1)
+            .skip_locked();
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"jobs\".* FROM \"jobs\" WHERE \"done\" = $1 LIMIT $2 FOR UPDATE SKIP LOCKED",
+            sql
+        );
+        assert_eq!(vec![Value::from(false), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_select_for_share_nowait() {
+        let query = Select::from_table("jobs").for_share().nowait();
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT \"jobs\".* FROM \"jobs\" FOR SHARE NOWAIT", sql);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_build_with_types() {
+        let dt = chrono::Utc::now();
+
+        let conditions = "id"
+            .equals(1)
+            .and("name".equals("Musti"))
+            .and("created_at".less_than(dt));
+
+        let (sql, params) = Postgres::build_with_types(Select::from_table("cats").so_that(conditions)).unwrap();
+
+        assert_eq!(
+            "SELECT \"cats\".* FROM \"cats\" WHERE (\"id\" = $1 AND \"name\" = $2 AND \"created_at\" < $3)",
+            sql
+        );
+
+        assert_eq!(
+            vec![
+                (Value::integer(1), ValueKind::Integer),
+                (Value::text("Musti"), ValueKind::Text),
+                (Value::datetime(dt), ValueKind::DateTime),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn test_in_array() {
+        let query = Select::from_table("users").so_that("id".in_array(vec![1, 2, 3]).chunk_size(2));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"id\" = ANY($1)", sql);
+        assert_eq!(vec![Value::array(vec![1, 2, 3])], params);
+    }
+
+    #[test]
+    fn test_returning_update() {
+        let update = Update::table("users")
+            .set("name", "Musti")
+            .so_that("id".equals(1))
+            .returning(vec!["id", "name"]);
+
+        let (sql, params) = Postgres::build(update).unwrap();
+
+        assert_eq!(
+            "UPDATE \"users\" SET \"name\" = $1 WHERE \"id\" = $2 RETURNING \"id\", \"name\"",
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti"), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_substring() {
+        let expected = expected_values(r#"SELECT SUBSTRING("name", $1, $2) FROM "users""#, vec![2, 3]);
+        let query = Select::from_table("users").value(substring(Column::from("name"), 2, 3));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_greatest_and_least() {
+        let expected = expected_values(r#"SELECT GREATEST("a", $1), LEAST("b", $2) FROM "cats""#, vec![0, 10]);
+
+        let query = Select::from_table("cats")
+            .value(greatest(vec![Column::from("a").into(), Expression::from(0)]))
+            .value(least(vec![Column::from("b").into(), Expression::from(10)]));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_in_values_join() {
+        let join = Column::from(("users", "name")).in_values_join(vec!["Musti", "Naukio"]);
+        let query = Select::from_table("users").inner_join(join);
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".* FROM \"users\" \
+             INNER JOIN (VALUES ($1::text),($2)) AS \"name_values\" \
+             ON \"users\".\"name\" = \"name_values\".\"column1\"",
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti"), Value::from("Naukio")], params);
+    }
+
+    #[test]
+    fn test_order_by_ordinal() {
+        let query = Select::from_table("orders")
+            .column("user_id")
+            .value(count(asterisk()))
+            .group_by("user_id")
+            .order_by(ordinal(2));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"user_id\", COUNT(*) FROM \"orders\" GROUP BY \"user_id\" ORDER BY 2",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_order_by_alias() {
+        let query = Select::from_table("orders")
+            .column("user_id")
+            .value(sum(Column::from("price")).alias("total"))
+            .group_by("user_id")
+            .order_by("total".descend());
+
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"user_id\", SUM(\"price\") AS \"total\" FROM \"orders\" GROUP BY \"user_id\" ORDER BY \"total\" DESC",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_aggregate_filter() {
+        let query = Select::from_table("users")
+            .value(count_star().filter("status".equals("active")).alias("active"))
+            .value(sum(Column::from("score")).filter("age".greater_than(18)))
+            .so_that("deleted".equals(false));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT COUNT(*) FILTER (WHERE \"status\" = $1) AS \"active\", \
+             SUM(\"score\") FILTER (WHERE \"age\" > $2) FROM \"users\" WHERE \"deleted\" = $3",
+            sql
+        );
+        assert_eq!(vec![Value::from("active"), Value::from(18), Value::from(false)], params);
+    }
+
+    #[test]
+    fn test_collate_in_order_by_and_where() {
+        let query = Select::from_table("users")
+            .so_that(Column::from("name").collate("und-x-icu").equals("musti"))
+            .order_by(Column::from("name").collate("C"));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".* FROM \"users\" WHERE \"name\" COLLATE \"und-x-icu\" = $1 \
+             ORDER BY \"name\" COLLATE \"C\"",
+            sql
+        );
+        assert_eq!(vec![Value::from("musti")], params);
+    }
+
+    #[test]
+    fn test_collate_rejects_quotes() {
+        let query = Select::from_table("users").order_by(Column::from("name").collate("C\" --"));
+        let err = Postgres::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::ConversionError(_)));
+    }
+
+    #[test]
+    fn test_index_hints_are_not_supported() {
+        let query = Select::from_table(Table::from("users").force_index("idx_name"));
+        let err = Postgres::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_partitions_are_not_supported() {
+        let query = Select::from_table(Table::from("events").partition(vec!["p0"]));
+        let err = Postgres::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+
+        let update = Update::table(Table::from("events").partition(vec!["p0"])).set("kind", "login");
+        let err = Postgres::build(update).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_build_condition() {
+        let conditions = "a".equals("foo").and("b".greater_than(2).or("c".is_null()));
+        let (sql, params) = Postgres::build_condition(conditions).unwrap();
+
+        assert_eq!(r#"("a" = $1 AND ("b" > $2 OR "c" IS NULL))"#, sql);
+        assert_eq!(vec![Value::from("foo"), Value::from(2)], params);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let query = CreateTable::new("users")
+            .column(
+                "id",
+                ColumnType::Integer,
+                vec![ColumnConstraint::PrimaryKey, ColumnConstraint::AutoIncrement],
+            )
+            .column("name", ColumnType::Varchar(255), vec![ColumnConstraint::NotNull])
+            .column("age", ColumnType::Integer, vec![])
+            .column("email", ColumnType::Text, vec![ColumnConstraint::Unique]);
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE \"users\" (\"id\" SERIAL PRIMARY KEY, \"name\" varchar(255) NOT NULL, \
+             \"age\" int4, \"email\" text UNIQUE)",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_drop_table() {
+        let (sql, _) = Postgres::build(DropTable::table(("public", "user"))).unwrap();
+        assert_eq!("DROP TABLE \"public\".\"user\"", sql);
+
+        let (sql, _) = Postgres::build(DropTable::table("user").if_exists()).unwrap();
+        assert_eq!("DROP TABLE IF EXISTS \"user\"", sql);
+    }
+
+    #[test]
+    fn test_drop_index() {
+        let (sql, _) = Postgres::build(DropIndex::index("user_email_idx", "user")).unwrap();
+        assert_eq!("DROP INDEX \"user_email_idx\"", sql);
+
+        let (sql, _) = Postgres::build(DropIndex::index("user_email_idx", ("public", "user")).if_exists()).unwrap();
+        assert_eq!("DROP INDEX IF EXISTS \"public\".\"user_email_idx\"", sql);
+    }
+
+    #[test]
+    fn test_create_index() {
+        let query = CreateIndex::on("users", vec!["email"]).unique().name("idx_users_email");
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!("CREATE UNIQUE INDEX \"idx_users_email\" ON \"users\" (\"email\")", sql);
+
+        let (sql, _) = Postgres::build(CreateIndex::on("users", vec!["name", "age"])).unwrap();
+
+        assert_eq!(
+            "CREATE INDEX \"users_name_age_idx\" ON \"users\" (\"name\", \"age\")",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_raw_right_hand_side() {
+        let query = Select::from_table("users").so_that("created".greater_than(raw("now() - interval '1 day'")));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".* FROM \"users\" WHERE \"created\" > now() - interval '1 day'",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_merge_is_not_supported() {
+        let using = Select::default()
+            .value(Expression::from(1).alias("id"))
+            .into_using("src", vec![Column::from("id")])
+            .on(Column::from(("cats", "id")).equals(Column::from(("src", "id"))));
+
+        let err = Postgres::build(Merge::new("cats", using)).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_trace_comment() {
+        let query = Select::from_table("cats");
+        let (sql, _) = Postgres::build_with_comment(query, "traceparent='00-abc-01'").unwrap();
+
+        assert_eq!("SELECT \"cats\".* FROM \"cats\" /* traceparent='00-abc-01' */", sql);
+    }
+
+    #[test]
+    fn test_select_list_mixing_columns_and_expressions() {
+        let query = Select::from_table("users")
+            .column("name")
+            .value(count(asterisk()).alias("total"))
+            .value(Expression::from(Column::from("age")) * Expression::from(2))
+            .so_that("active".equals(true))
+            .group_by("name")
+            .group_by("age");
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"name\", COUNT(*) AS \"total\", (\"age\" * $1) FROM \"users\" \
+             WHERE \"active\" = $2 GROUP BY \"name\", \"age\"",
+            sql
+        );
+        assert_eq!(vec![Value::from(2), Value::from(true)], params);
+    }
+
+    #[test]
+    fn test_create_table_with_cascading_foreign_key() {
+        let foreign_key = ForeignKey::new(vec!["owner_id"], "users", vec!["id"])
+            .on_delete(ReferentialAction::Cascade)
+            .on_update(ReferentialAction::SetNull);
+
+        let query = CreateTable::new("cats")
+            .column("owner_id", ColumnType::Integer, vec![])
+            .foreign_key(foreign_key);
+
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE \"cats\" (\"owner_id\" int4, FOREIGN KEY (\"owner_id\") REFERENCES \"users\" (\"id\") \
+             ON DELETE CASCADE ON UPDATE SET NULL)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_left_join_lateral() {
+        let top_posts = Select::from_table("posts")
+            .column("title")
+            .so_that(("posts", "user_id").equals(Column::from(("users", "id"))))
+            .and_where("published".equals(true))
+            .order_by("created_at".descend())
+            .limit(3);
+
+        let join = JoinData::all_from(Table::from(top_posts).alias("p")).lateral();
+
+        let query = Select::from_table("users")
+            .column(("users", "name"))
+            .column(("p", "title"))
+            .left_join(join)
+            .so_that(("users", "active").equals(true));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".\"name\", \"p\".\"title\" FROM \"users\" LEFT JOIN LATERAL (SELECT \"title\" \
+             FROM \"posts\" WHERE (\"posts\".\"user_id\" = \"users\".\"id\" AND \"published\" = $1) \
+             ORDER BY \"created_at\" DESC LIMIT $2) AS \"p\" ON 1=1 WHERE \"users\".\"active\" = $3",
+            sql
+        );
+
+        assert_eq!(vec![Value::from(true), Value::from(3_i64), Value::from(true)], params);
+    }
+
+    #[test]
+    fn test_replace_is_not_supported() {
+        let insert = Insert::single_into("users").value("id", 1);
+        let err = Postgres::build(Replace::new(insert)).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_greater_than_all_subquery() {
+        let sub = Select::from_table("products")
+            .column("price")
+            .so_that("category".equals("toys"));
+
+        let query = Select::from_table("products")
+            .so_that("stock".greater_than(10))
+            .and_where("price".gt_all(sub))
+            .and_where("active".equals(true));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"products\".* FROM \"products\" WHERE (\"stock\" > $1 AND \"price\" > ALL \
+             (SELECT \"price\" FROM \"products\" WHERE \"category\" = $2) AND \"active\" = $3)",
+            sql
+        );
+
+        assert_eq!(vec![Value::from(10), Value::from("toys"), Value::from(true)], params);
+    }
+
+    #[test]
+    fn test_equals_any_subquery() {
+        let sub = Select::from_table("admins")
+            .column("user_id")
+            .so_that("level".equals(2));
+        let query = Select::from_table("users").so_that("id".eq_any(sub).and("name".equals("Musti")));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".* FROM \"users\" WHERE (\"id\" = ANY (SELECT \"user_id\" FROM \"admins\" \
+             WHERE \"level\" = $1) AND \"name\" = $2)",
+            sql
+        );
+
+        assert_eq!(vec![Value::from(2), Value::from("Musti")], params);
+    }
+
+    #[test]
+    fn test_bulk_update_without_values_is_unchanged() {
+        let values: Vec<(i64, &str)> = Vec::new();
+        let query = Update::table("cats").set("age", 1).bulk_set("name", "id", values);
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("UPDATE \"cats\" SET \"age\" = $1", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_bulk_update_with_case() {
+        let values = vec![(1, "Musti"), (2, "Naukio"), (3, "Belka")];
+
+        let query = Update::table("cats")
+            .so_that("deleted".equals(false))
+            .bulk_set("name", "id", values);
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "UPDATE \"cats\" SET \"name\" = CASE \"id\" WHEN $1 THEN $2 WHEN $3 THEN $4 WHEN $5 THEN $6 END \
+             WHERE (\"deleted\" = $7 AND \"id\" IN ($8,$9,$10))",
+            sql
+        );
+
+        assert_eq!(
+            vec![
+                Value::from(1),
+                Value::from("Musti"),
+                Value::from(2),
+                Value::from("Naukio"),
+                Value::from(3),
+                Value::from("Belka"),
+                Value::from(false),
+                Value::from(1),
+                Value::from(2),
+                Value::from(3),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn test_optimizer_hints_are_ignored() {
+        let query = Select::from_table("users").optimizer_hint("MAX_EXECUTION_TIME(1000)");
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT \"users\".* FROM \"users\"", sql);
+    }
+
+    #[test]
+    fn test_fetch_first_with_limit_and_offset() {
+        let query = Select::from_table("users")
+            .so_that("age".greater_than(18))
+            .order_by("id")
+            .limit(10)
+            .offset(20)
+            .fetch_first();
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".* FROM \"users\" WHERE \"age\" > $1 ORDER BY \"id\" OFFSET $2 ROWS FETCH NEXT $3 ROWS ONLY",
+            sql
+        );
+        assert_eq!(vec![Value::from(18), Value::from(20), Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_fetch_first_with_limit_only() {
+        let query = Select::from_table("users").limit(10).fetch_first();
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT \"users\".* FROM \"users\" FETCH NEXT $1 ROWS ONLY", sql);
+        assert_eq!(vec![Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_select_into_count() {
+        let join = "posts".on(("users", "id").equals(Column::from(("posts", "user_id"))));
+
+        let query = Select::from_table("users")
+            .inner_join(join)
+            .so_that(("posts", "published").equals(true))
+            .and_where(("users", "age").greater_than(18))
+            .order_by(("users", "name").ascend())
+            .limit(10)
+            .offset(20);
+
+        let (sql, params) = Postgres::build(query.into_count()).unwrap();
+
+        let expected_sql = "SELECT COUNT(*) AS \"count\" FROM (SELECT \"users\".* FROM \"users\" \
+            INNER JOIN \"posts\" ON \"users\".\"id\" = \"posts\".\"user_id\" \
+            WHERE (\"posts\".\"published\" = $1 AND \"users\".\"age\" > $2)) AS \"count_query\"";
+
+        assert_eq!(expected_sql, sql);
+        assert_eq!(vec![Value::from(true), Value::from(18)], params);
+    }
+
+    #[test]
+    fn test_returning_insert_with_alias() {
+        let insert = Insert::single_into("users").value("name", "Musti");
+        let query = Insert::from(insert).returning(vec![Column::from("id").alias("new_id")]);
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "INSERT INTO \"users\" (\"name\") VALUES ($1) RETURNING \"id\" AS \"new_id\"",
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti")], params);
+    }
+
+    #[test]
+    fn test_moving_sum_with_rows_frame() {
+        let over = Over::default()
+            .partition_by("account")
+            .order_by("t")
+            .rows_between(FrameBound::Preceding(2), FrameBound::CurrentRow);
+
+        let query = Select::from_table("transactions")
+            .column("t")
+            .value(sum(Column::from("x")).over(over).alias("moving_sum"));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"t\", SUM(\"x\") OVER (PARTITION BY \"account\" ORDER BY \"t\" ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS \"moving_sum\" FROM \"transactions\"",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_window_with_range_frame_only() {
+        let over = Over::default().range_between(FrameBound::UnboundedPreceding, FrameBound::UnboundedFollowing);
+        let query = Select::from_table("transactions").value(sum(Column::from("x")).over(over));
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT SUM(\"x\") OVER (RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) FROM \"transactions\"",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_row_in_tuples() {
+        let cond = Row::from((Column::from("a"), Column::from("b"))).in_tuples(vec![(1, 2), (3, 4)]);
+        let (sql, params) = Postgres::build(Select::from_table("foo").so_that(cond)).unwrap();
+
+        assert_eq!(
+            "SELECT \"foo\".* FROM \"foo\" WHERE (\"a\",\"b\") IN (($1,$2),($3,$4))",
+            sql
+        );
+
+        assert_eq!(
+            vec![Value::from(1), Value::from(2), Value::from(3), Value::from(4)],
+            params
+        );
+    }
+
+    #[test]
+    fn test_create_table_as_select() {
+        let select = Select::from_table("users").column("id").so_that("age".greater_than(18));
+        let (sql, params) = Postgres::build(select.into_table(("public", "snapshot"))).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE \"public\".\"snapshot\" AS SELECT \"id\" FROM \"users\" WHERE \"age\" > $1",
+            sql
+        );
+        assert_eq!(vec![Value::from(18)], params);
+    }
+
+    #[test]
+    fn test_on_conflict_with_a_partial_index_predicate() {
+        let target = ConflictTarget::columns(vec!["email", "tenant_id"])
+            .so_that("deleted".equals(false).and("tenant_id".greater_than(10)));
+
+        let insert = Insert::single_into("users")
+            .value("email", "musti@example.com")
+            .value("tenant_id", 11);
+        let insert = Insert::from(insert)
+            .on_conflict(OnConflict::DoNothing)
+            .conflict_target(target);
+
+        let query = Query::from(insert);
+        let expected_params = vec![
+            Value::from("musti@example.com"),
+            Value::from(11),
+            Value::from(false),
+            Value::from(10),
+        ];
+
+        assert_eq!(expected_params, query.parameters());
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "INSERT INTO \"users\" (\"email\",\"tenant_id\") VALUES ($1,$2) \
+             ON CONFLICT (\"email\", \"tenant_id\") WHERE (\"deleted\" = $3 AND \"tenant_id\" > $4) DO NOTHING",
+            sql
+        );
+
+        assert_eq!(expected_params, params);
+    }
+
+    #[test]
+    fn test_typed_text_is_cast_from_text() {
+        let query = Select::default()
+            .value(typed_text("[1,5)", "int4range"))
+            .so_that(Column::from("tags").equals(typed_text(r#""a"=>"1""#, "hstore")));
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT $1::text::int4range WHERE \"tags\" = $2::text::hstore", sql);
+
+        assert_eq!(vec![Value::from("[1,5)"), Value::from(r#""a"=>"1""#)], params);
+    }
+
+    #[test]
+    fn test_group_by_rollup() {
+        let query = Select::from_table("sales")
+            .column("year")
+            .column("region")
+            .column("city")
+            .value(sum(Column::from("amount")))
+            .so_that("amount".greater_than(10))
+            .group_by("year")
+            .group_by_rollup(vec!["region", "city"]);
+
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT \"year\", \"region\", \"city\", SUM(\"amount\") FROM \"sales\" WHERE \"amount\" > $1 \
+             GROUP BY \"year\", ROLLUP (\"region\", \"city\")",
+            sql
+        );
+
+        assert_eq!(vec![Value::from(10)], params);
+    }
+
+    #[test]
+    fn test_group_by_cube() {
+        let query = Select::from_table("sales")
+            .value(sum(Column::from("amount")))
+            .group_by_cube(vec!["region", "city"]);
+
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT SUM(\"amount\") FROM \"sales\" GROUP BY CUBE (\"region\", \"city\")",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_bare_boolean_column_condition() {
+        let query = Select::from_table("users").so_that(Column::from("is_active"));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT \"users\".* FROM \"users\" WHERE \"is_active\"", sql);
+        assert!(params.is_empty());
+
+        let conditions = ConditionTree::from(Column::from("is_active")).and("age".greater_than(3));
+        let (sql, _) = Postgres::build(Select::from_table("users").so_that(conditions)).unwrap();
+
+        assert_eq!(
+            "SELECT \"users\".* FROM \"users\" WHERE (\"is_active\" AND \"age\" > $1)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let query = Select::from_table("users").value(count_distinct(Column::from("name")));
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!("SELECT COUNT(DISTINCT \"name\") FROM \"users\"", sql);
+    }
+
+    #[test]
+    fn test_count_distinct_multiple_columns_is_not_supported() {
+        let columns = vec![Column::from("a"), Column::from("b")];
+        let query = Select::from_table("users").value(count_distinct(columns));
+        let err = Postgres::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_conflict_target_without_on_conflict_is_not_supported() {
+        let target = ConflictTarget::columns(vec!["email"]).so_that("deleted".equals(false));
+        let insert =
+            Insert::from(Insert::single_into("users").value("email", "musti@example.com")).conflict_target(target);
+
+        let query = Query::from(insert);
+        assert_eq!(vec![Value::from("musti@example.com")], query.parameters());
+
+        let err = Postgres::build(query).unwrap_err();
+        assert!(matches!(err.kind(), crate::error::ErrorKind::UnsupportedFeature(_)));
+    }
 }
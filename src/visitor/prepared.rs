@@ -0,0 +1,68 @@
+use crate::{
+    ast::Value,
+    error::{Error, ErrorKind},
+};
+
+/// A built query that can be executed many times with different parameters,
+/// without visiting the AST again.
+///
+/// The database connections cache the prepared statements by the SQL string,
+/// so executing the same `PreparedQuery` again skips preparing the statement.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let update = Update::table("cats").set("age", 0).so_that("id".equals(0));
+/// let prepared = Postgres::prepare(update)?;
+///
+/// assert_eq!("UPDATE \"cats\" SET \"age\" = $1 WHERE \"id\" = $2", prepared.sql());
+/// assert_eq!(2, prepared.parameter_count());
+///
+/// assert!(prepared.validate(&[Value::from(7), Value::from(1)]).is_ok());
+/// assert!(prepared.validate(&[Value::from(7)]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedQuery<'a> {
+    sql: String,
+    parameters: Vec<Value<'a>>,
+}
+
+impl<'a> PreparedQuery<'a> {
+    pub(crate) fn new(sql: String, parameters: Vec<Value<'a>>) -> Self {
+        Self { sql, parameters }
+    }
+
+    /// The SQL string of the query.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The parameters the query was built with, in the order of the
+    /// placeholders.
+    pub fn parameters(&self) -> &[Value<'a>] {
+        &self.parameters
+    }
+
+    /// The number of parameters every execution must bind.
+    pub fn parameter_count(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Checks a new set of parameters has a value for every placeholder.
+    /// The values are bound in the order of the placeholders.
+    pub fn validate(&self, params: &[Value<'_>]) -> crate::Result<()> {
+        if params.len() != self.parameter_count() {
+            let msg = format!(
+                "The query needs {} parameters, {} given.",
+                self.parameter_count(),
+                params.len()
+            );
+
+            return Err(Error::builder(ErrorKind::conversion(msg)).build());
+        }
+
+        Ok(())
+    }
+}
@@ -16,12 +16,80 @@ pub struct Sqlite<'a> {
     parameters: Vec<Value<'a>>,
 }
 
+impl<'a> Sqlite<'a> {
+    /// The table, columns and values of an `INSERT` or a `REPLACE`.
+    fn visit_insert_body(&mut self, insert: Insert<'a>) -> visitor::Result {
+        if let Some(table) = insert.table {
+            self.write(" INTO ")?;
+            self.visit_table(table, true)?;
+        }
+
+        match insert.values {
+            Expression {
+                kind: ExpressionKind::Row(row),
+                ..
+            } => {
+                if row.values.is_empty() {
+                    self.write(" DEFAULT VALUES")?;
+                } else {
+                    let columns = insert.columns.len();
+
+                    self.write(" (")?;
+                    for (i, c) in insert.columns.into_iter().enumerate() {
+                        self.visit_column(c.name.into_owned().into())?;
+
+                        if i < (columns - 1) {
+                            self.write(", ")?;
+                        }
+                    }
+
+                    self.write(")")?;
+                    self.write(" VALUES ")?;
+                    self.visit_row(row)?;
+                }
+            }
+            Expression {
+                kind: ExpressionKind::Values(values),
+                ..
+            } => {
+                let columns = insert.columns.len();
+
+                self.write(" (")?;
+                for (i, c) in insert.columns.into_iter().enumerate() {
+                    self.visit_column(c.name.into_owned().into())?;
+
+                    if i < (columns - 1) {
+                        self.write(", ")?;
+                    }
+                }
+                self.write(")")?;
+
+                self.write(" VALUES ")?;
+                let values_len = values.len();
+
+                for (i, row) in values.into_iter().enumerate() {
+                    self.visit_row(row)?;
+
+                    if i < (values_len - 1) {
+                        self.write(", ")?;
+                    }
+                }
+            }
+            expr => self.visit_expression(expr)?,
+        }
+
+        Ok(())
+    }
+}
+
 impl<'a> Visitor<'a> for Sqlite<'a> {
     const C_BACKTICK_OPEN: &'static str = "`";
     const C_BACKTICK_CLOSE: &'static str = "`";
     const C_WILDCARD: &'static str = "%";
+    // The `SQLITE_MAX_VARIABLE_NUMBER` of the bundled SQLite library.
+    const C_MAX_PARAMETERS: usize = 250000;
 
-    fn build<Q>(query: Q) -> crate::Result<(String, Vec<Value<'a>>)>
+    fn build_with_parameter_limit<Q>(query: Q, limit: usize) -> crate::Result<(String, Vec<Value<'a>>)>
     where
         Q: Into<Query<'a>>,
     {
@@ -32,6 +100,22 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
 
         Sqlite::visit_query(&mut sqlite, query.into())?;
 
+        visitor::check_parameter_count(limit, sqlite.parameters.len())?;
+
+        Ok((sqlite.query, sqlite.parameters))
+    }
+
+    fn build_condition<T>(conditions: T) -> crate::Result<(String, Vec<Value<'a>>)>
+    where
+        T: Into<ConditionTree<'a>>,
+    {
+        let mut sqlite = Sqlite {
+            query: String::with_capacity(256),
+            parameters: Vec::with_capacity(16),
+        };
+
+        Sqlite::visit_conditions(&mut sqlite, conditions.into())?;
+
         Ok((sqlite.query, sqlite.parameters))
     }
 
@@ -97,71 +181,19 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
     }
 
     fn visit_insert(&mut self, insert: Insert<'a>) -> visitor::Result {
+        visitor::check_no_conflict_target(&insert)?;
+
         match insert.on_conflict {
             Some(OnConflict::DoNothing) => self.write("INSERT OR IGNORE")?,
             None => self.write("INSERT")?,
         };
 
-        if let Some(table) = insert.table {
-            self.write(" INTO ")?;
-            self.visit_table(table, true)?;
-        }
-
-        match insert.values {
-            Expression {
-                kind: ExpressionKind::Row(row),
-                ..
-            } => {
-                if row.values.is_empty() {
-                    self.write(" DEFAULT VALUES")?;
-                } else {
-                    let columns = insert.columns.len();
-
-                    self.write(" (")?;
-                    for (i, c) in insert.columns.into_iter().enumerate() {
-                        self.visit_column(c.name.into_owned().into())?;
-
-                        if i < (columns - 1) {
-                            self.write(", ")?;
-                        }
-                    }
-
-                    self.write(")")?;
-                    self.write(" VALUES ")?;
-                    self.visit_row(row)?;
-                }
-            }
-            Expression {
-                kind: ExpressionKind::Values(values),
-                ..
-            } => {
-                let columns = insert.columns.len();
-
-                self.write(" (")?;
-                for (i, c) in insert.columns.into_iter().enumerate() {
-                    self.visit_column(c.name.into_owned().into())?;
-
-                    if i < (columns - 1) {
-                        self.write(", ")?;
-                    }
-                }
-                self.write(")")?;
-
-                self.write(" VALUES ")?;
-                let values_len = values.len();
-
-                for (i, row) in values.into_iter().enumerate() {
-                    self.visit_row(row)?;
-
-                    if i < (values_len - 1) {
-                        self.write(", ")?;
-                    }
-                }
-            }
-            expr => self.visit_expression(expr)?,
-        }
+        self.visit_insert_body(insert)
+    }
 
-        Ok(())
+    fn visit_replace(&mut self, replace: Replace<'a>) -> visitor::Result {
+        self.write("REPLACE")?;
+        self.visit_insert_body(replace.insert)
     }
 
     fn parameter_substitution(&mut self) -> visitor::Result {
@@ -172,6 +204,26 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         self.parameters.push(value);
     }
 
+    fn visit_fetch_first(&mut self, _: Option<Value<'a>>, _: Option<Value<'a>>) -> visitor::Result {
+        let msg = "The FETCH FIRST syntax is not supported in SQLite, use LIMIT instead.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_grouping_set(&mut self, _: GroupingSet<'a>) -> visitor::Result {
+        let msg = "ROLLUP, CUBE and GROUPING SETS are not supported in SQLite.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
     fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
         match (limit, offset) {
             (Some(limit), Some(offset)) => {
@@ -201,6 +253,87 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         self.surround_with("(", ")", |ref mut s| s.visit_expression(value))
     }
 
+    fn visit_substring(&mut self, substring: Substring<'a>) -> visitor::Result {
+        self.write("SUBSTR")?;
+
+        self.surround_with("(", ")", |ref mut s| {
+            s.visit_expression(*substring.expression)?;
+            s.write(", ")?;
+            s.visit_expression(*substring.start)?;
+            s.write(", ")?;
+            s.visit_expression(*substring.length)
+        })
+    }
+
+    // The scalar `MAX` and `MIN` with more than one argument work as
+    // `GREATEST` and `LEAST`. With one argument they would be aggregates, so
+    // the argument is written alone.
+    fn visit_greatest(&mut self, mut greatest: Greatest<'a>) -> visitor::Result {
+        if greatest.exprs.len() == 1 {
+            return self.visit_expression(greatest.exprs.remove(0));
+        }
+
+        self.write("MAX")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_expressions(greatest.exprs))
+    }
+
+    fn visit_least(&mut self, mut least: Least<'a>) -> visitor::Result {
+        if least.exprs.len() == 1 {
+            return self.visit_expression(least.exprs.remove(0));
+        }
+
+        self.write("MIN")?;
+        self.surround_with("(", ")", |ref mut s| s.visit_expressions(least.exprs))
+    }
+
+    fn visit_pad(&mut self, _: Pad<'a>) -> visitor::Result {
+        let msg = "LPAD and RPAD functions are not supported in SQLite.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_lock(&mut self, _: Lock) -> visitor::Result {
+        let msg = "Row-level locking is not supported in SQLite.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_quantified_comparison(
+        &mut self,
+        _: Expression<'a>,
+        _: &'static str,
+        _: Quantifier,
+        _: Expression<'a>,
+    ) -> visitor::Result {
+        let msg = "ANY and ALL comparisons are not supported in SQLite.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    fn visit_lateral(&mut self) -> visitor::Result {
+        let msg = "LATERAL joins are not supported in SQLite.";
+        let kind = ErrorKind::unsupported_feature(msg);
+
+        let mut builder = Error::builder(kind);
+        builder.set_original_message(msg);
+
+        Err(builder.build())
+    }
+
+    // Comparing a row against the list needs row value support from SQLite
+    // 3.15.
     fn visit_values(&mut self, values: Values<'a>) -> visitor::Result {
         self.surround_with("(VALUES ", ")", |ref mut s| {
             let len = values.len();
@@ -214,11 +347,55 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
             Ok(())
         })
     }
+
+    fn visit_column_type(&mut self, typ: ColumnType, _: bool) -> visitor::Result {
+        // An auto-incrementing column must be exactly `INTEGER PRIMARY KEY`,
+        // which is also 64 bits wide.
+        match typ {
+            ColumnType::Integer | ColumnType::BigInt => self.write("INTEGER"),
+            ColumnType::Text | ColumnType::Varchar(_) => self.write("TEXT"),
+            ColumnType::Boolean => self.write("BOOLEAN"),
+            ColumnType::Double => self.write("REAL"),
+            ColumnType::DateTime => self.write("DATETIME"),
+            ColumnType::Bytes => self.write("BLOB"),
+        }
+    }
+
+    fn visit_auto_increment(&mut self) -> visitor::Result {
+        self.write(" AUTOINCREMENT")
+    }
+
+    fn visit_create_index(&mut self, create: CreateIndex<'a>) -> visitor::Result {
+        // The database qualifies the index name, the table must be in the
+        // same database.
+        let name = create.index_name();
+
+        if create.unique {
+            self.write("CREATE UNIQUE INDEX ")?;
+        } else {
+            self.write("CREATE INDEX ")?;
+        }
+
+        match create.table.database {
+            Some(ref database) => self.delimited_identifiers(&[database.as_ref(), &*name])?,
+            None => self.delimited_identifiers(&[&*name])?,
+        }
+
+        self.write(" ON ")?;
+
+        match create.table.typ {
+            TableType::Table(ref table) => self.delimited_identifiers(&[table.as_ref()])?,
+            _ => self.visit_table(create.table.clone(), false)?,
+        }
+
+        self.write(" ")?;
+        self.visit_index_columns(create.columns)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{val, visitor::*};
+    use crate::{error::ErrorKind, val, visitor::*};
 
     fn expected_values<'a, T>(sql: &'static str, params: Vec<T>) -> (String, Vec<Value<'a>>)
     where
@@ -451,6 +628,24 @@ mod tests {
         assert_eq!(default_params(expected.1), params);
     }
 
+    #[test]
+    fn test_select_equals_null() {
+        let query = Select::from_table("naukio").so_that("word".equals(Value::Text(None)));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `naukio`.* FROM `naukio` WHERE `word` IS NULL", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_select_not_equals_null() {
+        let query = Select::from_table("naukio").so_that("age".not_equals(Option::<i64>::None));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `naukio`.* FROM `naukio` WHERE `age` IS NOT NULL", sql);
+        assert!(params.is_empty());
+    }
+
     #[test]
     fn test_select_and() {
         let expected_sql = "SELECT `naukio`.* FROM `naukio` WHERE (`word` = ? AND `age` < ? AND `paw` = ?)";
@@ -467,6 +662,29 @@ mod tests {
         assert_eq!(default_params(expected_params), params);
     }
 
+    #[test]
+    fn test_select_where_equals_pairs() {
+        let expected_sql = "SELECT `naukio`.* FROM `naukio` WHERE (`deleted` = ? AND (`word` = ? AND `age` = ?))";
+        let expected_params = vec![Value::boolean(false), Value::text("meow"), Value::integer(10)];
+
+        let query = Select::from_table("naukio")
+            .so_that("deleted".equals(false))
+            .where_equals(vec![("word", Value::text("meow")), ("age", Value::integer(10))]);
+
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+        assert_eq!(default_params(expected_params), params);
+    }
+
+    #[test]
+    fn test_select_where_equals_without_pairs() {
+        let pairs: Vec<(&str, Value)> = Vec::new();
+        let (sql, _) = Sqlite::build(Select::from_table("naukio").where_equals(pairs)).unwrap();
+
+        assert_eq!("SELECT `naukio`.* FROM `naukio`", sql);
+    }
+
     #[test]
     fn test_select_and_different_execution_order() {
         let expected_sql = "SELECT `naukio`.* FROM `naukio` WHERE (`word` = ? AND (`age` < ? AND `paw` = ?))";
@@ -798,4 +1016,573 @@ mod tests {
             sql
         );
     }
+
+    #[test]
+    fn test_select_for_update_is_not_supported() {
+        let query = Select::from_table("jobs").limit(1).skip_locked();
+        let err = Sqlite::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_lateral_join_is_not_supported() {
+        let sub = Select::from_table("posts")
+            .column("title")
+            .so_that(("posts", "user_id").equals(Column::from(("users", "id"))));
+
+        let join = Table::from(sub).alias("p").on(ConditionTree::NoCondition).lateral();
+        let query = Select::from_table("users").left_join(join);
+        let err = Sqlite::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_returning_update_is_not_supported() {
+        let update = Update::table("users").set("name", "Musti").returning(vec!["id"]);
+        let err = Sqlite::build(update).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_insert_over_the_parameter_limit() {
+        let insert = (0..4).fold(Insert::multi_into("cats", vec!["name", "age"]), |insert, i| {
+            insert.values(vec![val!("Musti"), val!(i)])
+        });
+
+        let err = Sqlite::build_with_parameter_limit(insert.clone(), 7).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TooManyParameters { limit: 7, got: 8 }));
+
+        let (_, params) = Sqlite::build_with_parameter_limit(insert, 8).unwrap();
+        assert_eq!(8, params.len());
+    }
+
+    #[test]
+    fn test_substring() {
+        let expected = expected_values("SELECT SUBSTR(`name`, ?, ?) FROM `users`", vec![2, 3]);
+        let query = Select::from_table("users").value(substring(Column::from("name"), 2, 3));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_pad_is_not_supported() {
+        let query = Select::from_table("users").value(lpad(Column::from("id"), 8, "0"));
+        let err = Sqlite::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_greatest_and_least_are_max_and_min() {
+        let clamped = least(vec![
+            Expression::from(greatest(vec![Column::from("age").into(), Expression::from(0)])),
+            Expression::from(100),
+        ]);
+
+        let expected = expected_values("SELECT MIN(MAX(`age`, ?), ?) FROM `cats`", vec![0, 100]);
+        let (sql, params) = Sqlite::build(Select::from_table("cats").value(clamped)).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_greatest_and_least_with_one_argument_are_not_aggregates() {
+        let query = Select::from_table("cats")
+            .value(greatest(vec![Column::from("age")]))
+            .value(least(vec![Column::from("weight")]));
+
+        let (sql, _) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `age`, `weight` FROM `cats`", sql);
+    }
+
+    #[test]
+    fn test_and_with_no_condition_is_dropped() {
+        let conditions = ConditionTree::NoCondition.and("name".equals("Musti"));
+        let expected = expected_values("SELECT `cats`.* FROM `cats` WHERE `name` = ?", vec!["Musti"]);
+        let (sql, params) = Sqlite::build(Select::from_table("cats").so_that(conditions)).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_or_with_negative_condition_is_dropped() {
+        let conditions = ConditionTree::NegativeCondition
+            .or("name".equals("Musti"))
+            .or(ConditionTree::NegativeCondition);
+
+        let expected = expected_values("SELECT `cats`.* FROM `cats` WHERE `name` = ?", vec!["Musti"]);
+        let (sql, params) = Sqlite::build(Select::from_table("cats").so_that(conditions)).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_and_with_negative_condition_is_negative() {
+        let conditions = ConditionTree::single("name".equals("Musti")).and(ConditionTree::NegativeCondition);
+        let (sql, params) = Sqlite::build(Select::from_table("cats").so_that(conditions)).unwrap();
+
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE 1=0", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_filter() {
+        let query = Select::from_table("users").value(count_star().filter("status".equals("active")).alias("active"));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(
+            "SELECT COUNT(*) FILTER (WHERE `status` = ?) AS `active` FROM `users`",
+            sql
+        );
+        assert_eq!(vec![Value::from("active")], params);
+    }
+
+    #[test]
+    fn test_update_set_opt_none_sets_null() {
+        let query = Update::table("users")
+            .set_opt("name", Some("Musti"))
+            .set_opt("age", None::<i64>)
+            .so_that("id".equals(1));
+
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!("UPDATE `users` SET `name` = ?, `age` = ? WHERE `id` = ?", sql);
+        assert_eq!(vec![Value::from("Musti"), Value::Integer(None), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_update_set_opt_skips_none() {
+        let query = Update::table("users")
+            .null_mode(UpdateNullMode::SkipNone)
+            .set_opt("name", Some("Musti"))
+            .set_opt("age", None::<i64>)
+            .so_that("id".equals(1));
+
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!("UPDATE `users` SET `name` = ? WHERE `id` = ?", sql);
+        assert_eq!(vec![Value::from("Musti"), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let query = CreateTable::new("users")
+            .column(
+                "id",
+                ColumnType::Integer,
+                vec![ColumnConstraint::PrimaryKey, ColumnConstraint::AutoIncrement],
+            )
+            .column("name", ColumnType::Varchar(255), vec![ColumnConstraint::NotNull])
+            .column("age", ColumnType::Integer, vec![])
+            .column("email", ColumnType::Text, vec![ColumnConstraint::Unique]);
+
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE `users` (`id` INTEGER PRIMARY KEY AUTOINCREMENT, `name` TEXT NOT NULL, \
+             `age` INTEGER, `email` TEXT UNIQUE)",
+            sql
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_drop_table() {
+        let (sql, _) = Sqlite::build(DropTable::table("user")).unwrap();
+        assert_eq!("DROP TABLE `user`", sql);
+
+        let (sql, _) = Sqlite::build(DropTable::table("user").if_exists()).unwrap();
+        assert_eq!("DROP TABLE IF EXISTS `user`", sql);
+    }
+
+    #[test]
+    fn test_drop_index() {
+        let (sql, _) = Sqlite::build(DropIndex::index("user_email_idx", "user")).unwrap();
+        assert_eq!("DROP INDEX `user_email_idx`", sql);
+
+        let (sql, _) = Sqlite::build(DropIndex::index("user_email_idx", "user").if_exists()).unwrap();
+        assert_eq!("DROP INDEX IF EXISTS `user_email_idx`", sql);
+    }
+
+    #[test]
+    fn test_create_index() {
+        let query = CreateIndex::on("users", vec!["email"]).unique().name("idx_users_email");
+        let (sql, _) = Sqlite::build(query).unwrap();
+
+        assert_eq!("CREATE UNIQUE INDEX `idx_users_email` ON `users` (`email`)", sql);
+
+        let (sql, _) = Sqlite::build(CreateIndex::on("users", vec!["name", "age"])).unwrap();
+
+        assert_eq!("CREATE INDEX `users_name_age_idx` ON `users` (`name`, `age`)", sql);
+    }
+
+    #[test]
+    fn test_create_index_in_attached_database() {
+        let (sql, _) = Sqlite::build(CreateIndex::on(("other", "users"), vec!["email"]).unique()).unwrap();
+
+        assert_eq!(
+            "CREATE UNIQUE INDEX `other`.`users_email_idx` ON `users` (`email`)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_replace() {
+        let insert = Insert::single_into("users").value("id", 1).value("name", "Musti");
+        let (sql, params) = Sqlite::build(Replace::new(insert)).unwrap();
+
+        assert_eq!("REPLACE INTO `users` (`id`, `name`) VALUES (?,?)", sql);
+        assert_eq!(vec![Value::from(1), Value::from("Musti")], params);
+    }
+
+    #[test]
+    fn test_quantified_comparisons_are_not_supported() {
+        let sub = Select::from_table("admins").column("user_id");
+        let query = Select::from_table("users").so_that("id".eq_any(sub));
+
+        let err = Sqlite::build(query).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_all_of_conditions() {
+        let empty: Vec<Compare> = Vec::new();
+        let (sql, _) = Sqlite::build(Select::from_table("cats").so_that(ConditionTree::all_of(empty))).unwrap();
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE 1=1", sql);
+
+        let single = vec!["name".equals("Musti")];
+        let (sql, _) = Sqlite::build(Select::from_table("cats").so_that(ConditionTree::all_of(single))).unwrap();
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE `name` = ?", sql);
+
+        let many = vec!["name".equals("Musti"), "age".greater_than(3), "indoor".equals(true)];
+        let (sql, params) = Sqlite::build(Select::from_table("cats").so_that(ConditionTree::all_of(many))).unwrap();
+        assert_eq!(
+            "SELECT `cats`.* FROM `cats` WHERE (`name` = ? AND `age` > ? AND `indoor` = ?)",
+            sql
+        );
+        assert_eq!(vec![Value::from("Musti"), Value::from(3), Value::from(true)], params);
+    }
+
+    #[test]
+    fn test_any_of_conditions() {
+        let empty: Vec<Compare> = Vec::new();
+        let (sql, _) = Sqlite::build(Select::from_table("cats").so_that(ConditionTree::any_of(empty))).unwrap();
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE 1=0", sql);
+
+        let single = vec!["name".equals("Musti")];
+        let (sql, _) = Sqlite::build(Select::from_table("cats").so_that(ConditionTree::any_of(single))).unwrap();
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE `name` = ?", sql);
+
+        let many = vec!["name".equals("Musti"), "name".equals("Naukio")];
+        let (sql, params) = Sqlite::build(Select::from_table("cats").so_that(ConditionTree::any_of(many))).unwrap();
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE (`name` = ? OR `name` = ?)", sql);
+        assert_eq!(vec![Value::from("Musti"), Value::from("Naukio")], params);
+    }
+
+    #[test]
+    fn test_insert_from_columnar_data() {
+        let columns = vec![
+            ("a", vec![1, 2, 3, 4]),
+            ("b", vec![10, 20, 30, 40]),
+            ("c", vec![100, 200, 300, 400]),
+        ];
+
+        let query = Insert::columnar_into("foo", columns).unwrap();
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(
+            "INSERT INTO `foo` (`a`, `b`, `c`) VALUES (?,?,?), (?,?,?), (?,?,?), (?,?,?)",
+            sql
+        );
+
+        let expected: Vec<Value> = vec![1, 10, 100, 2, 20, 200, 3, 30, 300, 4, 40, 400]
+            .into_iter()
+            .map(Value::from)
+            .collect();
+
+        assert_eq!(expected, params);
+    }
+
+    #[test]
+    fn test_insert_from_columnar_data_of_different_lengths() {
+        let columns = vec![("a", vec![1, 2]), ("b", vec![10])];
+        let err = Insert::columnar_into("foo", columns).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::ConversionError(_)));
+    }
+
+    #[test]
+    fn test_row_not_in_tuples() {
+        let cond = Row::from((Column::from("a"), Column::from("b"))).not_in_tuples(vec![(1, 2), (3, 4)]);
+        let (sql, params) = Sqlite::build(Select::from_table("foo").so_that(cond)).unwrap();
+
+        assert_eq!(
+            "SELECT `foo`.* FROM `foo` WHERE (`a`,`b`) NOT IN (VALUES (?,?),(?,?))",
+            sql
+        );
+
+        assert_eq!(
+            vec![Value::from(1), Value::from(2), Value::from(3), Value::from(4)],
+            params
+        );
+    }
+
+    #[test]
+    fn test_create_table_as_select() {
+        let select = Select::from_table("users").column("id").so_that("age".greater_than(18));
+        let (sql, params) = Sqlite::build(select.into_table("snapshot")).unwrap();
+
+        assert_eq!(
+            "CREATE TABLE `snapshot` AS SELECT `id` FROM `users` WHERE `age` > ?",
+            sql
+        );
+        assert_eq!(vec![Value::from(18)], params);
+    }
+
+    #[test]
+    fn test_select_from_runtime_table_name() {
+        let tenant = "acme";
+        let table = Table::dynamic(format!("{}_orders", tenant)).unwrap();
+        let query = Select::from_table(table).so_that("id".equals(1));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `acme_orders`.* FROM `acme_orders` WHERE `id` = ?", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_runtime_table_name_with_quotes_is_rejected() {
+        let tenant = "acme` WHERE 1=1; --";
+        let err = Table::dynamic(format!("{}_orders", tenant)).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::InvalidIdentifier { .. }));
+    }
+
+    #[test]
+    fn test_grouping_sets_are_not_supported() {
+        let query = Select::from_table("sales")
+            .value(sum(Column::from("amount")))
+            .group_by_rollup(vec!["region"]);
+
+        let err = Sqlite::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_bare_boolean_column_condition() {
+        let query = Select::from_table("users").so_that(ConditionTree::not(Column::from("is_active")));
+        let (sql, _) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `users`.* FROM `users` WHERE (NOT `is_active`)", sql);
+    }
+
+    #[test]
+    fn test_estimate_a_large_insert() {
+        let mut insert = Insert::multi_into("naukio", vec!["a", "b"]);
+
+        for i in 0..200000 {
+            insert = insert.values(vec![i, i]);
+        }
+
+        let stats = Sqlite::estimate(insert.clone()).unwrap();
+        let (sql, _) = Sqlite::build_with_parameter_limit(insert, usize::MAX).unwrap();
+
+        assert_eq!(400000, stats.param_count);
+        assert_eq!(sql.len(), stats.byte_len);
+        assert!(stats.param_count > Sqlite::C_MAX_PARAMETERS);
+    }
+
+    #[test]
+    fn keywords_can_be_written_in_lowercase() {
+        let query = || {
+            Select::from_table("Cats")
+                .column("Name")
+                .value(count(asterisk()).alias("Total"))
+                .so_that(
+                    "Name"
+                        .like("%Musti%")
+                        .and(Column::from("Age").equals(raw("'AGE' OR NULL"))),
+                )
+                .group_by("Name")
+                .order_by("Name".descend())
+                .limit(10)
+        };
+
+        let (upper, upper_params) = Sqlite::build_with_keyword_case(query(), KeywordCase::Upper).unwrap();
+        let (lower, lower_params) = Sqlite::build_with_keyword_case(query(), KeywordCase::Lower).unwrap();
+
+        assert_eq!(Sqlite::build(query()).unwrap().0, upper);
+        assert_eq!(
+            "SELECT `Name`, COUNT(*) AS `Total` FROM `Cats` WHERE (`Name` LIKE ? AND `Age` = 'AGE' OR NULL) GROUP BY `Name` ORDER BY `Name` DESC LIMIT ?",
+            upper
+        );
+        assert_eq!(
+            "select `Name`, count(*) as `Total` from `Cats` where (`Name` like ? and `Age` = 'AGE' or null) group by `Name` order by `Name` desc limit ?",
+            lower
+        );
+        assert_eq!(upper_params, lower_params);
+    }
+
+    #[test]
+    fn update_set_all_sorts_the_columns_by_name() {
+        let mut changes = std::collections::HashMap::new();
+        changes.insert(String::from("name"), Value::from("Musti"));
+        changes.insert(String::from("age"), Value::from(6));
+        changes.insert(String::from("color"), Value::from("black"));
+
+        let query = Update::table("cats").set_all(changes).so_that("id".equals(1));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(
+            "UPDATE `cats` SET `age` = ?, `color` = ?, `name` = ? WHERE `id` = ?",
+            sql
+        );
+        assert_eq!(
+            vec![
+                Value::from(6),
+                Value::from("black"),
+                Value::from("Musti"),
+                Value::from(1)
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn recursive_cte_walks_the_children() {
+        let anchor = Select::from_table("nodes")
+            .column("id")
+            .column("parent_id")
+            .so_that("id".equals(1));
+
+        let recursive = Select::from_table("nodes")
+            .column(("nodes", "id"))
+            .column(("nodes", "parent_id"))
+            .inner_join("tree".on(("nodes", "parent_id").equals(Column::from(("tree", "id")))))
+            .so_that(Column::from(("nodes", "id")).not_equals(99));
+
+        let query =
+            Select::from_table("tree")
+                .column("id")
+                .with_recursive("tree", vec!["id", "parent_id"], anchor, recursive);
+
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!(
+            "WITH RECURSIVE `tree` (`id`, `parent_id`) AS (\
+             SELECT `id`, `parent_id` FROM `nodes` WHERE `id` = ? \
+             UNION ALL \
+             SELECT `nodes`.`id`, `nodes`.`parent_id` FROM `nodes` \
+             INNER JOIN `tree` ON `nodes`.`parent_id` = `tree`.`id` WHERE `nodes`.`id` <> ?) \
+             SELECT `id` FROM `tree`",
+            sql
+        );
+        assert_eq!(vec![Value::from(1), Value::from(99)], params);
+    }
+
+    #[test]
+    fn null_literal_is_written_as_a_keyword() {
+        let query = Select::default().value(null_value().alias("x"));
+        let (sql, params) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT NULL AS `x`", sql);
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn null_literal_aligns_the_columns_of_a_union() {
+        let cats = Select::from_table("cats")
+            .column("name")
+            .column("lives")
+            .so_that("lives".greater_than(1));
+
+        let dogs = Select::from_table("dogs")
+            .column("name")
+            .value(null_value().alias("lives"));
+
+        let (sql, params) = Sqlite::build(Union::new(cats).all(dogs)).unwrap();
+
+        assert_eq!(
+            "SELECT `name`, `lives` FROM `cats` WHERE `lives` > ? UNION ALL SELECT `name`, NULL AS `lives` FROM `dogs`",
+            sql
+        );
+        assert_eq!(vec![Value::from(1)], params);
+    }
+
+    #[test]
+    fn comparing_to_a_null_literal_uses_is_null() {
+        let query = Select::from_table("cats").so_that("owner".equals(null_value()));
+        let (sql, _) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT `cats`.* FROM `cats` WHERE `owner` IS NULL", sql);
+    }
+
+    #[test]
+    fn large_inserts_split_into_batches_under_the_parameter_limit() {
+        let limit = 32767;
+
+        let insert = (0..50_000).fold(Insert::multi_into("cats", vec!["id", "name"]), |insert, i| {
+            insert.values(vec![Value::from(i), Value::from("Musti")])
+        });
+
+        let batches = insert.into_batches(limit).unwrap();
+
+        // 16383 rows fit in a batch.
+        assert_eq!(4, batches.len());
+
+        let mut total_params = 0;
+
+        for batch in batches {
+            let (_, params) = Sqlite::build_with_parameter_limit(batch, limit).unwrap();
+            total_params += params.len();
+        }
+
+        assert_eq!(100_000, total_params);
+    }
+
+    #[test]
+    fn insert_batches_fail_if_a_row_is_over_the_limit() {
+        let insert = Insert::multi_into("cats", vec!["id", "name"]).values(vec![Value::from(1), Value::from("Musti")]);
+        let err = insert.into_batches(1).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::TooManyParameters { limit: 1, got: 2 }));
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let query = Select::from_table("users").value(count_distinct(Column::from("name")));
+        let (sql, _) = Sqlite::build(query).unwrap();
+
+        assert_eq!("SELECT COUNT(DISTINCT `name`) FROM `users`", sql);
+    }
+
+    #[test]
+    fn test_count_distinct_multiple_columns_is_not_supported() {
+        let columns = vec![Column::from("a"), Column::from("b")];
+        let query = Select::from_table("users").value(count_distinct(columns));
+        let err = Sqlite::build(query).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
+
+    #[test]
+    fn test_conflict_target_is_not_supported() {
+        let target = ConflictTarget::columns(vec!["email"]).so_that("deleted".equals(false));
+        let insert = Insert::from(Insert::single_into("users").value("email", "musti@example.com"))
+            .on_conflict(OnConflict::DoNothing)
+            .conflict_target(target);
+
+        let err = Sqlite::build(insert).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::UnsupportedFeature(_)));
+    }
 }